@@ -1,87 +1,210 @@
-use tabled::Table;
+use std::collections::BTreeSet;
+
+use chrono::Utc;
 
 use crate::{
     error, info,
-    management::{ArtistReleaseManager, TokenManager},
+    management::{ArtistArchiveManager, ArtistReleaseManager, ArtistRulesManager, TokenManager},
+    output::{self, ArtistRecord},
     spotify, success,
-    types::{Artist, ArtistReleases, ArtistTableRow},
-    utils, warning,
+    types::{
+        Artist, ArtistRule, ArtistRuleMode, ArtistRuleTableRow, ArtistSyncSummary, OutputFormat,
+    },
+    utils::{self, ReleaseKind, ReleaseKinds},
+    warning,
 };
 
-/// Updates the local artist cache with followed artists from Spotify.
+/// Synchronizes the local artist cache with the followed artists on Spotify.
 ///
-/// Compares the number of cached artists with the current number of followed
-/// artists on Spotify and fetches any new artists. The function intelligently
-/// determines how many artists need to be updated and provides progress feedback
-/// during the operation.
+/// Pages through all followed artists and compares them with the cache by
+/// artist ID. Newly followed artists are added, unfollowed artists are moved
+/// to the artist archive (or dropped entirely when `prune` is set) and a
+/// summary of all changes is printed.
 ///
 /// # Arguments
 ///
-/// * `force` - If true, forces a complete refresh of all artists, ignoring cache
-///
-/// # Behavior
+/// * `force` - If true, discards the cached releases of all still-followed artists
+/// * `prune` - If true, unfollowed artists and their cached releases are removed
+///   instead of archived
 ///
-/// - **Incremental Updates**: Only fetches new artists unless force is enabled
-/// - **Cache Comparison**: Compares local cache count with remote count
-/// - **Force Mode**: When enabled, completely rebuilds the artist cache
-/// - **Progress Tracking**: Shows real-time progress during the update process
+/// # Sync Logic
 ///
-/// # Update Logic
+/// 1. Fetch every followed artist from Spotify (50 per request, cursor based)
+/// 2. Diff the fetched list against the cache by artist ID
+/// 3. With `force`, clear the cached releases of still-followed artists
+/// 4. Add new artists, restoring archived releases for re-followed artists
+/// 5. Archive or remove artists that are no longer followed
+/// 6. Persist the cache and print a summary
 ///
-/// 1. Load current cached artist count
-/// 2. Get total artist count from Spotify
-/// 3. Calculate how many new artists to fetch
-/// 4. If no new artists and not forcing, skip update
-/// 5. Otherwise, fetch and cache the new/all artists
+/// The cache is only modified once the complete list of followed artists
+/// has been fetched, so an interrupted fetch never archives artists by accident.
 ///
 /// # Error Handling
 ///
-/// The function handles various error conditions gracefully:
-/// - Missing or corrupt artist cache (treats as empty)
-/// - Spotify API failures (reports error and exits)
-/// - Authentication issues (directs user to re-authenticate)
+/// - Missing or corrupt artist cache is treated as empty
+/// - Spotify API failures abort the sync without touching the cache
+/// - Missing authentication directs the user to `sporlcli auth`
 ///
 /// # Example
 ///
 /// ```
-/// // Incremental update - only fetch new artists
-/// update_artists(false).await;
+/// // Sync and archive unfollowed artists
+/// update_artists(false, false).await;
 ///
-/// // Force complete refresh
-/// update_artists(true).await;
+/// // Sync and drop unfollowed artists with their releases
+/// update_artists(false, true).await;
 /// ```
-///
-/// # Performance Notes
-///
-/// - Incremental updates are much faster for users with stable follow lists
-/// - Force updates may take significant time for users following many artists
-/// - Progress indicators help users understand operation status
-pub async fn update_artists(force: bool) {
-    let artist_cache_count = match ArtistReleaseManager::load().await {
-        Ok(arm) => {
-            if !force {
-                arm.count_artists()
-            } else {
-                0
-            }
-        }
-        Err(_) => 0,
+pub async fn update_artists(force: bool, prune: bool) {
+    spotify::ensure_online("Updating the artist cache");
+    let mut arm: ArtistReleaseManager = ArtistReleaseManager::load()
+        .await
+        .unwrap_or_else(|_| ArtistReleaseManager::new(None));
+
+    let followed = match fetch_followed_artists().await {
+        Ok(artists) => artists,
+        Err(e) => error!("Cannot update artists. Err: {}", e),
     };
 
-    let artist_remote_count = match spotify::artists::get_total_artist_count().await {
-        Ok(c) => c,
-        Err(_) => 0,
+    let mut archive = ArtistArchiveManager::load()
+        .await
+        .unwrap_or_else(|_| ArtistArchiveManager::new());
+
+    let summary = arm.sync_artists(followed);
+    if force {
+        arm.clear_releases();
+    }
+
+    let mut restored = 0;
+    for artist in &summary.added {
+        if let Some(archived) = archive.restore(&artist.id) {
+            arm.add_releases_to_artist(&artist.id, archived.releases);
+            restored += 1;
+        }
+    }
+
+    // write the archive first, so unfollowed artists never vanish from both caches
+    if !prune && !summary.removed.is_empty() {
+        archive.archive(summary.removed.clone());
+    }
+
+    if (restored > 0 || (!prune && !summary.removed.is_empty()))
+        && let Err(e) = archive.persist().await
+    {
+        error!("Failed to update artist archive. Err: {}", e);
+    }
+
+    if let Err(e) = arm.persist().await {
+        error!("Failed to cache artists. Err: {}", e);
+    }
+
+    print_sync_summary(&summary, restored, prune);
+    success!("Cached {} artists.", arm.count_artists());
+}
+
+/// Refreshes the metadata of all cached artists from Spotify.
+///
+/// Looks up every cached artist through the several-artists endpoint in
+/// batches of 50 IDs and replaces name, genres, popularity, follower count,
+/// images and external URLs with the current data. Cached releases are kept
+/// as they are, and the followed-artist list itself is not changed; use
+/// `sporlcli artists update` for that.
+///
+/// # Behavior
+///
+/// - Artists that Spotify no longer knows are reported by name and ID and
+///   left untouched
+/// - A failing batch is reported and skipped, the remaining batches continue
+/// - The cache is persisted once after all batches have been processed
+///
+/// # Example
+///
+/// ```bash
+/// sporlcli artists refresh
+/// ```
+pub async fn refresh_artists() {
+    spotify::ensure_online("Refreshing artist metadata");
+    let mut arm = match ArtistReleaseManager::load().await {
+        Ok(arm) => arm,
+        Err(e) => error!(
+            "Failed to load artists. Please run sporlcli artists update\n Error: {}",
+            e
+        ),
     };
 
-    let max_new: u64 = if artist_remote_count > artist_cache_count as u64 {
-        artist_remote_count - artist_cache_count as u64
-    } else {
-        0
+    let mut token_mgr = match TokenManager::load().await {
+        Ok(t) => t,
+        Err(e) => {
+            error!(
+                "Failed to load token. Please run sporlcli auth\n Error: {}",
+                e
+            );
+        }
     };
 
-    if let Err(e) = load_remote_artists(max_new, force).await {
-        error!("Cannot update artists. Err: {}", e)
+    let cached_artists = arm.get_all_artists().unwrap_or_default();
+    let artist_ids: Vec<String> = cached_artists.iter().map(|a| a.id.clone()).collect();
+    let artists_total = artist_ids.len();
+
+    let pb = utils::create_progress_bar("Refreshing artist metadata...");
+
+    let mut refreshed = 0;
+    let mut failed = 0;
+    for chunk in artist_ids.chunks(50) {
+        let token = token_mgr.get_valid_token().await;
+        match spotify::artists::get_several_artists(&token, chunk).await {
+            Ok(artists) => {
+                let missing: Vec<String> = chunk
+                    .iter()
+                    .filter(|id| !artists.iter().any(|a| &a.id == *id))
+                    .map(|id| match cached_artists.iter().find(|a| &a.id == id) {
+                        Some(a) => format!("{} ({})", a.name, a.id),
+                        None => id.clone(),
+                    })
+                    .collect();
+                if !missing.is_empty() {
+                    failed += missing.len();
+                    pb.suspend(|| {
+                        warning!(
+                            "Spotify no longer knows {} artists, keeping cached data: {}",
+                            missing.len(),
+                            missing.join(", ")
+                        )
+                    });
+                }
+
+                let refreshed_at = Utc::now().timestamp() as u64;
+                let artists = artists
+                    .into_iter()
+                    .map(|mut artist| {
+                        artist.refreshed_at = Some(refreshed_at);
+                        artist
+                    })
+                    .collect();
+                refreshed += arm.update_artist_metadata(artists);
+            }
+            Err(e) => {
+                failed += chunk.len();
+                pb.suspend(|| warning!("Failed to refresh {} artists. Err: {}", chunk.len(), e));
+            }
+        }
+
+        pb.set_message(format!("Refreshed {refreshed}/{artists_total} artists ..."));
+    }
+
+    pb.finish_and_clear();
+
+    if let Err(e) = arm.persist().await {
+        error!("Failed to cache artists. Err: {}", e);
+    }
+
+    if failed > 0 {
+        warning!("{} artists could not be refreshed.", failed);
     }
+    success!(
+        "Refreshed metadata for {}/{} artists.",
+        refreshed,
+        artists_total
+    );
 }
 
 /// Lists cached artists with optional search filtering and tabular display.
@@ -93,12 +216,16 @@ pub async fn update_artists(force: bool) {
 /// # Arguments
 ///
 /// * `search` - Optional search term to filter artists by name (case-insensitive)
+/// * `format` - Output format (`table`, `json`, `ndjson`, `csv` or `markdown`)
 ///
 /// # Display Format
 ///
-/// The output table includes:
+/// Machine-readable formats contain the artist ID, all genres, popularity,
+/// followers and Spotify URL (see the `output` module). The output table includes:
 /// - **Name**: Artist's display name
 /// - **Genres**: Up to 3 genres associated with the artist (comma-separated)
+/// - **Popularity**: Spotify popularity (0-100), empty until refreshed
+/// - **Followers**: Follower count, empty until refreshed
 ///
 /// # Search Functionality
 ///
@@ -123,27 +250,26 @@ pub async fn update_artists(force: bool) {
 ///
 /// ```
 /// // List all artists
-/// list_artists(None).await;
+/// list_artists(None, OutputFormat::Table).await;
 ///
 /// // Search for artists containing "rock"
-/// list_artists(Some("rock".to_string())).await;
+/// list_artists(Some("rock".to_string()), OutputFormat::Table).await;
 ///
-/// // Search for specific artist
-/// list_artists(Some("Arctic Monkeys".to_string())).await;
+/// // Export all artists as JSON
+/// list_artists(None, OutputFormat::Json).await;
 /// ```
 ///
 /// # Output Example
 ///
 /// ```text
-/// ┌─────────────────────┬──────────────────────────────┐
-/// │ name                │ genres                       │
-/// ├─────────────────────┼──────────────────────────────┤
-/// │ Arctic Monkeys      │ garage rock,indie rock,rock  │
-/// │ Radiohead          │ alternative rock,art rock    │
-/// │ The Beatles        │ rock,pop,psychedelic rock    │
-/// └─────────────────────┴──────────────────────────────┘
+/// ┌────────────────┬─────────────────────────────┬────────────┬───────────┐
+/// │ name           │ genres                      │ popularity │ followers │
+/// ├────────────────┼─────────────────────────────┼────────────┼───────────┤
+/// │ Arctic Monkeys │ garage rock,indie rock,rock │ 82         │ 23017445  │
+/// │ Radiohead      │ alternative rock,art rock   │ 80         │ 12440391  │
+/// └────────────────┴─────────────────────────────┴────────────┴───────────┘
 /// ```
-pub async fn list_artists(search: Option<String>) {
+pub async fn list_artists(search: Option<String>, format: OutputFormat) {
     match load_cached_artists().await {
         Ok(artists) => {
             // sort artists by name
@@ -155,28 +281,185 @@ pub async fn list_artists(search: Option<String>) {
                 sorted_artists.retain(|a| a.name.to_lowercase().contains(&search_term));
             }
 
-            // convert artists to table rows
-            let table_rows: Vec<ArtistTableRow> = sorted_artists
-                .into_iter()
-                .map(|a| ArtistTableRow {
-                    name: a.name,
-                    genres: a
-                        .genres
-                        .iter()
-                        .take(3)
-                        .cloned()
-                        .collect::<Vec<_>>()
-                        .join(","),
-                })
-                .collect();
-
-            let table = Table::new(table_rows);
-            println!("{}", table);
+            let records: Vec<ArtistRecord> =
+                sorted_artists.iter().map(ArtistRecord::from).collect();
+            match output::render(&records, format) {
+                Ok(rendered) => println!("{}", rendered),
+                Err(e) => error!("Cannot render artists. Err: {}", e),
+            }
         }
         Err(e) => warning!("Failed to load arists. Err: {}", e),
     }
 }
 
+/// Mutes a followed artist, or some of its release types.
+///
+/// Muted releases are hidden from `releases` listings and playlists, but the
+/// artist stays followed and its releases are still fetched and cached.
+/// Setting a rule replaces any earlier rule of the artist.
+///
+/// # Arguments
+///
+/// * `artist` - Spotify artist ID or (part of) the artist name
+/// * `types` - Release types to hide; empty hides all releases of the artist
+///
+/// # Example
+///
+/// ```bash
+/// sporlcli artists mute "Some Artist"
+/// sporlcli artists mute 4Z8W4fKeB5YxbusRsdQVPb --type compilation --type appears_on
+/// ```
+pub async fn mute_artist(artist: &str, types: &[ReleaseKinds]) {
+    set_artist_rule(artist, ArtistRuleMode::Mute, types).await;
+}
+
+/// Only shows the given release types of a followed artist.
+///
+/// Works like [`mute_artist`], but hides every release that is not one of
+/// the given types, e.g. "only albums from this artist".
+///
+/// # Arguments
+///
+/// * `artist` - Spotify artist ID or (part of) the artist name
+/// * `types` - Release types to keep showing
+///
+/// # Example
+///
+/// ```bash
+/// sporlcli artists allow "Some Artist" --type album
+/// ```
+pub async fn allow_artist(artist: &str, types: &[ReleaseKinds]) {
+    set_artist_rule(artist, ArtistRuleMode::Allow, types).await;
+}
+
+/// Removes the mute or allow rule of an artist.
+///
+/// The artist is looked up among the rules first, so artists that are no
+/// longer followed can still be unmuted.
+///
+/// # Arguments
+///
+/// * `artist` - Spotify artist ID or (part of) the artist name
+///
+/// # Example
+///
+/// ```bash
+/// sporlcli artists unmute "Some Artist"
+/// ```
+pub async fn unmute_artist(artist: &str) {
+    let mut rules = match ArtistRulesManager::load().await {
+        Ok(rules) => rules,
+        Err(e) => error!("Failed to load artist rules. Err: {}", e),
+    };
+
+    let needle = artist.trim().to_lowercase();
+    let ruled_id = rules
+        .rules()
+        .iter()
+        .find(|r| r.artist_id == artist.trim() || r.artist_name.to_lowercase() == needle)
+        .map(|r| r.artist_id.clone());
+
+    let artist_id = match ruled_id {
+        Some(id) => id,
+        None => match ArtistReleaseManager::load().await {
+            Ok(arm) => match arm.find_artist(artist) {
+                Ok(a) => a.id,
+                Err(e) => error!("{}", e),
+            },
+            Err(e) => error!(
+                "Failed to load artists. Please run sporlcli artists update\n Error: {}",
+                e
+            ),
+        },
+    };
+
+    let Some(removed) = rules.remove(&artist_id) else {
+        info!("No rule set for {}.", artist);
+        return;
+    };
+
+    if let Err(e) = rules.persist().await {
+        error!("Failed to store artist rules. Err: {}", e);
+    }
+    success!(
+        "Removed rule '{}' of {}.",
+        removed.describe(),
+        removed.artist_name
+    );
+}
+
+/// Lists the mute and allow rules of all artists.
+///
+/// # Example
+///
+/// ```bash
+/// sporlcli artists rules
+/// ```
+///
+/// ```text
+/// ┌─────────────┬────────────────────────┬───────────────┐
+/// │ artist      │ id                     │ rule          │
+/// ├─────────────┼────────────────────────┼───────────────┤
+/// │ Some Artist │ 4Z8W4fKeB5YxbusRsdQVPb │ only album    │
+/// └─────────────┴────────────────────────┴───────────────┘
+/// ```
+pub async fn list_artist_rules() {
+    let rules = match ArtistRulesManager::load().await {
+        Ok(rules) => rules,
+        Err(e) => error!("Failed to load artist rules. Err: {}", e),
+    };
+
+    if rules.is_empty() {
+        info!("No artist rules set. Use sporlcli artists mute or allow to add one.");
+        return;
+    }
+
+    let rows = rules.rules().iter().map(|r| ArtistRuleTableRow {
+        artist: r.artist_name.clone(),
+        id: r.artist_id.clone(),
+        rule: r.describe(),
+    });
+    println!("{}", output::render_table(rows, OutputFormat::Table));
+}
+
+/// Resolves a cached artist and stores a rule for it.
+///
+/// # Arguments
+///
+/// * `artist` - Spotify artist ID or (part of) the artist name
+/// * `mode` - Whether the types are hidden or the only ones shown
+/// * `types` - Release types of the rule
+async fn set_artist_rule(artist: &str, mode: ArtistRuleMode, types: &[ReleaseKinds]) {
+    let arm = match ArtistReleaseManager::load().await {
+        Ok(arm) => arm,
+        Err(e) => error!(
+            "Failed to load artists. Please run sporlcli artists update\n Error: {}",
+            e
+        ),
+    };
+    let artist = match arm.find_artist(artist) {
+        Ok(artist) => artist,
+        Err(e) => error!("{}", e),
+    };
+
+    let types: BTreeSet<ReleaseKind> = types.iter().flat_map(ReleaseKinds::iter).collect();
+    let rule = ArtistRule {
+        artist_id: artist.id,
+        artist_name: artist.name,
+        mode,
+        types: types.into_iter().collect(),
+    };
+
+    let mut rules = match ArtistRulesManager::load().await {
+        Ok(rules) => rules,
+        Err(e) => error!("Failed to load artist rules. Err: {}", e),
+    };
+    if let Err(e) = rules.set(rule.clone()).persist().await {
+        error!("Failed to store artist rules. Err: {}", e);
+    }
+    success!("{} is now {}.", rule.artist_name, rule.describe());
+}
+
 /// Loads artists from the local cache.
 ///
 /// Internal helper function that retrieves all cached artists from the
@@ -218,118 +501,30 @@ async fn load_cached_artists() -> Result<Vec<Artist>, String> {
     }
 }
 
-/// Fetches artists from Spotify and updates the local cache.
-///
-/// Core function that handles the actual fetching of artist data from Spotify's
-/// API with intelligent pagination, progress tracking, and cache management.
-/// Supports both incremental updates and complete refreshes.
+/// Fetches the complete list of followed artists from Spotify.
 ///
-/// # Arguments
-///
-/// * `max_new` - Maximum number of new artists to fetch (0 = no update needed)
-/// * `force` - If true, starts with an empty cache regardless of existing data
+/// Walks Spotify's cursor-based pagination with the maximum page size of 50
+/// until no further cursor is returned, showing a spinner with the running
+/// count while doing so.
 ///
 /// # Returns
 ///
 /// Returns a `Result` containing:
-/// - `Ok(Vec<ArtistReleases>)` - All artist data after the update
-/// - `Err(reqwest::Error)` - HTTP/API error during the fetch process
-///
-/// # Fetching Strategy
-///
-/// The function uses intelligent pagination:
-/// - Fetches up to 50 artists per API request (Spotify's maximum)
-/// - Uses cursor-based pagination for efficient traversal
-/// - Adjusts final request size to match exact requirements
-/// - Stops early if no more artists are available
-///
-/// # Progress Tracking
-///
-/// Provides real-time progress feedback:
-/// - Animated spinner during the operation
-/// - Running count of fetched artists
-/// - Clear success/failure messaging
-/// - Progress indicators that clean up properly
-///
-/// # Cache Management
-///
-/// The function handles cache operations:
-/// - Loads existing cache unless force mode is enabled
-/// - Adds newly fetched artists to the cache
-/// - Persists updated cache to disk
-/// - Reports final cache statistics
+/// - `Ok(Vec<Artist>)` - Every artist the user currently follows
+/// - `Err(reqwest::Error)` - HTTP/API error during any page request
 ///
 /// # Authentication
 ///
-/// Manages API authentication automatically:
-/// - Loads stored authentication tokens
-/// - Refreshes tokens if they're expired
-/// - Handles authentication failures with clear error messages
+/// Loads the stored token and refreshes it per page if required. Missing
+/// tokens terminate the program with a hint to run `sporlcli auth`.
 ///
-/// # Error Handling
-///
-/// Comprehensive error handling for:
-/// - Authentication failures (directs user to re-authenticate)
-/// - Network connectivity issues
-/// - API rate limiting and service errors
-/// - Cache persistence failures
-/// - Invalid API responses
-///
-/// # Early Exit Conditions
-///
-/// The function exits early in several scenarios:
-/// - `max_new` is 0 (no update needed)
-/// - API returns empty artist list
-/// - Required number of artists have been fetched
-/// - No more artists available (pagination exhausted)
-///
-/// # Example Usage
+/// # Example
 ///
 /// ```
-/// // Fetch up to 100 new artists
-/// let artists = load_remote_artists(100, false).await?;
-///
-/// // Force complete refresh
-/// let artists = load_remote_artists(0, true).await?;
+/// let followed = fetch_followed_artists().await?;
+/// println!("Following {} artists", followed.len());
 /// ```
-///
-/// # Performance Considerations
-///
-/// - Uses efficient cursor-based pagination
-/// - Respects API rate limits through built-in delays
-/// - Minimizes memory usage by processing artists in batches
-/// - Persists cache after completion to avoid data loss
-///
-/// # Network Resilience
-///
-/// The function includes resilience features:
-/// - Automatic token refresh for expired credentials
-/// - Graceful handling of temporary API failures
-/// - Progress preservation across network interruptions
-/// - Clear error reporting for permanent failures
-async fn load_remote_artists(
-    max_new: u64,
-    force: bool,
-) -> Result<Vec<ArtistReleases>, reqwest::Error> {
-    let mut arm: ArtistReleaseManager = match ArtistReleaseManager::load().await {
-        Ok(arm) => {
-            if force {
-                ArtistReleaseManager::new(None)
-            } else {
-                arm
-            }
-        }
-        Err(_) => ArtistReleaseManager::new(None),
-    };
-
-    if max_new == 0 {
-        success!("Nothing to update here.");
-        return Ok(arm.all().unwrap_or(Vec::new()));
-    } else {
-        info!("Update {} artists in cache ...", max_new);
-    }
-
-    // load tokeb manager for retrieve valid auth token
+async fn fetch_followed_artists() -> Result<Vec<Artist>, reqwest::Error> {
     let mut token_mgr = match TokenManager::load().await {
         Ok(t) => t,
         Err(e) => {
@@ -340,63 +535,86 @@ async fn load_remote_artists(
         }
     };
 
-    // start progress wheel
     let pb = utils::create_progress_bar("Fetching followed artists...");
 
+    let mut artists: Vec<Artist> = Vec::new();
     let mut after: Option<String> = None;
-    let mut new_once = max_new;
-
-    let mut total_fetched = 0u64;
 
     loop {
-        let limit = if new_once < 50 { new_once } else { 50 };
-        if limit <= 0 {
-            break;
-        }
-
         let token = token_mgr.get_valid_token().await;
-        let result = spotify::artists::get_artist(&token, limit, after.clone()).await;
-
-        match result {
-            Ok((artists, next_after)) => {
-                if artists.is_empty() {
-                    break;
-                }
-
-                total_fetched += artists.len() as u64;
-                new_once -= artists.len() as u64;
-                pb.set_message(format!(
-                    "Fetched {}/{} artists from remote ...",
-                    total_fetched, max_new
-                ));
-
-                arm.add_artists(artists);
-                after = next_after;
-
-                if after.is_none() {
-                    break;
-                }
-            }
+        let (page, next_after) = match spotify::artists::get_artist(&token, 50, after.clone()).await
+        {
+            Ok(result) => result,
             Err(e) => {
                 pb.finish_and_clear();
-                error!("Failed to fetch artists: {}", e);
+                return Err(e);
             }
+        };
+
+        if page.is_empty() {
+            break;
+        }
+
+        let refreshed_at = Utc::now().timestamp() as u64;
+        artists.extend(page.into_iter().map(|mut artist| {
+            artist.refreshed_at = Some(refreshed_at);
+            artist
+        }));
+        pb.set_message(format!(
+            "Fetched {} followed artists from remote ...",
+            artists.len()
+        ));
+
+        after = next_after;
+        if after.is_none() {
+            break;
         }
     }
 
     pb.finish_and_clear();
-    success!(
-        "Fetched {}/{} artists!",
-        max_new,
-        arm.count_artists().clone(),
-    );
+    Ok(artists)
+}
 
-    // let artists_mgr = ArtistReleaseManager::new()
-    if let Err(e) = arm.persist().await {
-        error!("Failed to cache artists. Err: {}", e);
+/// Prints the changes applied by an artist sync.
+///
+/// Lists every added and removed artist by name followed by a one-line
+/// summary. Removed artists are reported as archived or pruned depending
+/// on how the sync was run.
+///
+/// # Arguments
+///
+/// * `summary` - The result of `ArtistReleaseManager::sync_artists`
+/// * `restored` - Number of added artists whose releases came from the archive
+/// * `prune` - Whether removed artists were dropped instead of archived
+fn print_sync_summary(summary: &ArtistSyncSummary, restored: usize, prune: bool) {
+    if summary.is_empty() {
+        success!(
+            "Nothing to update here. {} artists are up to date.",
+            summary.unchanged
+        );
+        return;
     }
 
-    success!("Cached {} artists.", arm.count_artists().clone());
+    let removed_action = if prune { "Pruned" } else { "Archived" };
 
-    Ok(arm.all().unwrap_or(Vec::new()))
+    for artist in &summary.added {
+        info!("Added {}", artist.name);
+    }
+    for ar in &summary.removed {
+        info!(
+            "{} {} ({} cached releases)",
+            removed_action,
+            ar.artist.name,
+            ar.releases.len()
+        );
+    }
+
+    success!(
+        "{} added ({} restored from archive), {} {}, {} unchanged.",
+        summary.added.len(),
+        restored,
+        summary.removed.len(),
+        removed_action.to_lowercase(),
+        summary.unchanged
+    );
 }
@@ -97,5 +97,6 @@ use crate::{spotify, types::PkceToken};
 /// The CLI layer focuses on user interaction while the spotify module handles
 /// the technical OAuth implementation details.
 pub async fn auth(shared_state: Arc<Mutex<Option<PkceToken>>>) {
+    spotify::ensure_online("Authorization");
     spotify::auth::auth(shared_state).await;
 }
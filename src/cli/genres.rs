@@ -0,0 +1,63 @@
+use crate::{
+    error,
+    management::ArtistReleaseManager,
+    output::{self, GenreRecord},
+    types::OutputFormat,
+    warning,
+};
+
+/// Lists every genre of the cached artists with artist and release counts.
+///
+/// Genres come from the artist cache (`sporlcli artists update` or
+/// `sporlcli artists refresh`), so this command works offline. The list is
+/// sorted by the number of artists and helps to find values for the
+/// `--genre` and `--exclude-genre` filters of `releases` and `playlist`.
+///
+/// # Arguments
+///
+/// * `format` - Output format (`table`, `json`, `ndjson`, `csv` or `markdown`)
+///
+/// # Example Usage
+///
+/// ```bash
+/// sporlcli genres
+/// sporlcli genres --format csv > genres.csv
+/// ```
+///
+/// # Output Example
+///
+/// ```text
+/// ┌────────────────┬─────────┬──────────┐
+/// │ genre          │ artists │ releases │
+/// ├────────────────┼─────────┼──────────┤
+/// │ indie rock     │ 14      │ 212      │
+/// │ minimal techno │ 9       │ 87       │
+/// └────────────────┴─────────┴──────────┘
+/// ```
+pub async fn list_genres(format: OutputFormat) {
+    let manager = match ArtistReleaseManager::load().await {
+        Ok(manager) => manager,
+        Err(e) => {
+            warning!(
+                "Failed to load artists. Run sporlcli artists update. Err: {}",
+                e
+            );
+            return;
+        }
+    };
+
+    let records: Vec<GenreRecord> = manager
+        .genre_counts()
+        .into_iter()
+        .map(|(genre, artists, releases)| GenreRecord {
+            genre,
+            artists,
+            releases,
+        })
+        .collect();
+
+    match output::render(&records, format) {
+        Ok(rendered) => println!("{}", rendered),
+        Err(e) => error!("Cannot render genres. Err: {}", e),
+    }
+}
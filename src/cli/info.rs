@@ -1,6 +1,24 @@
-use chrono::Utc;
+use chrono::{SecondsFormat, Utc};
 
-use crate::{error, info, management::ArtistReleaseManager, spotify, success, utils, warning};
+use crate::{
+    clock, config, error,
+    logging::{self, Kind},
+    management::ArtistReleaseManager,
+    output::{self, ArtistCountRecord, OutputRecord, WeekRecord},
+    spotify,
+    types::{OutputFormat, WeekSelection},
+    utils,
+};
+
+/// Prints a result line of the `table` format to stdout.
+///
+/// The lines look like status messages but are the command's output, so
+/// they are not subject to `-q` and can be redirected.
+macro_rules! print_line {
+    ($kind:expr, $($arg:tt)*) => {
+        println!("{}", logging::format_line($kind, format_args!($($arg)*)))
+    };
+}
 
 /// Internal structure for holding release week information.
 ///
@@ -10,6 +28,8 @@ use crate::{error, info, management::ArtistReleaseManager, spotify, success, uti
 struct ReleaseWeekInfo {
     /// Week number within the year (1-52/53)
     week: u32,
+    /// Release year the week belongs to
+    year: i32,
     /// Formatted date range string (e.g., "2023-10-14 - 2023-10-20")
     dates: String,
 }
@@ -25,15 +45,18 @@ struct ReleaseWeekInfo {
 ///
 /// * `release_week` - Display current release week information
 /// * `artists` - Display artist count statistics (cache vs remote)
-/// * `previous_weeks` - Number of previous weeks to display information for
-/// * `release_date` - Specific date to lookup release week information
+/// * `weeks` - Week selection (`--week`, `--from`/`--to`, `--previous-weeks`, `--release-date`)
+/// * `format` - Output format; `table` prints the messages shown below, all
+///   other formats print week records (`week`, `year`, `label`, `scheme`,
+///   `start`, `end`) or artist counts (`cache`, `remote`)
 ///
 /// # Information Types
 ///
 /// ## Release Week (`--release-week`)
 /// Shows information about the current release week:
-/// - Week number within the current year
-/// - Date range covered by the week (Saturday to Friday)
+/// - Week number and the release year it belongs to, labelled per the
+///   configured week scheme (`WEEK_SCHEME`)
+/// - Date range covered by the week (Saturday to Friday by default)
 ///
 /// ## Artist Statistics (`--artists`)
 /// Compares local cache with remote Spotify data:
@@ -41,8 +64,8 @@ struct ReleaseWeekInfo {
 /// - Number of artists currently followed on Spotify
 /// - Warning if cache is outdated
 ///
-/// ## Previous Weeks (`--previous-weeks N`)
-/// Displays week information for the last N weeks:
+/// ## Week Selection (`--previous-weeks N`, `--week`, `--from`/`--to`)
+/// Displays week information for the selected weeks:
 /// - Week numbers and date ranges
 /// - Useful for understanding recent release periods
 ///
@@ -55,8 +78,8 @@ struct ReleaseWeekInfo {
 /// The function executes in priority order and returns after the first match:
 /// 1. Release week information (if `release_week` is true)
 /// 2. Artist statistics (if `artists` is true)
-/// 3. Previous weeks information (if `previous_weeks` is provided)
-/// 4. Date lookup (if `release_date` is provided)
+/// 3. Date lookup (if only `--release-date` is provided)
+/// 4. Week information (if any other week selection is provided)
 ///
 /// # Error Handling
 ///
@@ -64,7 +87,7 @@ struct ReleaseWeekInfo {
 /// - **Release week errors**: Terminate with error message
 /// - **Artist cache failures**: Default to 0 count with warning
 /// - **API failures**: Default to 0 count, may show warning
-/// - **Date parsing errors**: Use current date as fallback
+/// - **Date parsing errors**: Terminate with the invalid date in the message
 ///
 /// # Example Usage
 ///
@@ -78,6 +101,9 @@ struct ReleaseWeekInfo {
 /// # Show last 4 weeks
 /// sporlcli info --previous-weeks 4
 ///
+/// # Show the dates of week 32 of 2025
+/// sporlcli info --week 2025-W32
+///
 /// # Look up specific date
 /// sporlcli info --release-date 2023-12-25
 /// ```
@@ -86,7 +112,7 @@ struct ReleaseWeekInfo {
 ///
 /// **Release Week:**
 /// ```
-/// [o] Current release week: 42
+/// [o] Current release week: 42/2023
 /// [o] Current release week dates: 2023-10-14 - 2023-10-20
 /// ```
 ///
@@ -99,15 +125,15 @@ struct ReleaseWeekInfo {
 ///
 /// **Previous Weeks:**
 /// ```
-/// [o] Release week: 40
+/// [o] Release week: 40/2023
 /// [o] Release week dates: 2023-09-30 - 2023-10-06
-/// [o] Release week: 41
+/// [o] Release week: 41/2023
 /// [o] Release week dates: 2023-10-07 - 2023-10-13
 /// ```
 ///
 /// **Date Lookup:**
 /// ```
-/// [o] 2023-12-25 is in release week 52.
+/// [o] 2023-12-25 is in release week 52/2023.
 /// ```
 ///
 /// # Use Cases
@@ -117,20 +143,27 @@ struct ReleaseWeekInfo {
 /// - **Historical Analysis**: Reviewing past release weeks
 /// - **Date Conversion**: Converting calendar dates to release week numbers
 /// - **Debugging**: Verifying week calculations and date ranges
-pub async fn info(
-    release_week: bool,
-    artists: bool,
-    previous_weeks: Option<u32>,
-    release_date: Option<String>,
-) {
+pub async fn info(release_week: bool, artists: bool, weeks: &WeekSelection, format: OutputFormat) {
+    let scheme = config::week_scheme();
+
     if release_week {
+        if format != OutputFormat::Table {
+            let current = utils::build_week(clock::today());
+            print_records(&[WeekRecord::new(&current, scheme)], format);
+            return;
+        }
+
         let info: ReleaseWeekInfo = match current_release_week().await {
             Ok(info) => info,
             Err(err) => error!("Error fetching release week info: {}", err),
         };
 
-        info!("Current release week: {}", info.week);
-        info!("Current release week dates: {}", info.dates);
+        print_line!(
+            Kind::Info,
+            "Current release week: {}",
+            scheme.label(info.week, info.year)
+        );
+        print_line!(Kind::Info, "Current release week dates: {}", info.dates);
         return;
     }
 
@@ -139,47 +172,124 @@ pub async fn info(
             Ok(arm) => arm.count_artists().clone() as u64,
             Err(_) => 0,
         };
+        let cache_updated_at = ArtistReleaseManager::cache_updated_at().await;
 
-        let artist_remote_count = match spotify::artists::get_total_artist_count().await {
-            Ok(c) => c,
-            Err(_) => 0,
+        // Offline, only the cache can be asked; show how old it is instead.
+        let artist_remote_count = if spotify::is_offline() {
+            None
+        } else {
+            Some(
+                spotify::artists::get_total_artist_count()
+                    .await
+                    .unwrap_or(0),
+            )
         };
 
-        success!("Artist count remote: {}", artist_remote_count);
+        if format != OutputFormat::Table {
+            let counts = ArtistCountRecord {
+                cache: artist_cache_count,
+                remote: artist_remote_count,
+                cache_updated_at: cache_updated_at
+                    .map(|at| at.to_rfc3339_opts(SecondsFormat::Secs, true)),
+            };
+            print_records(&[counts], format);
+            return;
+        }
+
+        let Some(artist_remote_count) = artist_remote_count else {
+            print_line!(Kind::Info, "Artist count cache: {}", artist_cache_count);
+            match cache_updated_at {
+                Some(at) => print_line!(
+                    Kind::Info,
+                    "Artist cache updated: {} ({})",
+                    at.format("%Y-%m-%d %H:%M UTC"),
+                    utils::format_age((Utc::now() - at).num_seconds().max(0) as u64)
+                ),
+                None => print_line!(Kind::Warning, "Artist cache updated: never"),
+            }
+            print_line!(
+                Kind::Warning,
+                "Offline mode: the remote artist count was not checked."
+            );
+            return;
+        };
+
+        print_line!(
+            Kind::Success,
+            "Artist count remote: {}",
+            artist_remote_count
+        );
         if artist_cache_count < artist_remote_count {
-            warning!("Artist count cache: {}", artist_cache_count);
+            print_line!(Kind::Warning, "Artist count cache: {}", artist_cache_count);
         } else {
-            success!("Artist count cache: {}", artist_cache_count);
+            print_line!(Kind::Success, "Artist count cache: {}", artist_cache_count);
+        }
+
+        return;
+    }
+
+    if let (Some(release_date), false) = (&weeks.release_date, weeks.previous_weeks.is_some()) {
+        let release_date = match utils::get_date_from_string(Some(release_date.clone())) {
+            Ok(date) => date,
+            Err(e) => error!("{}", e),
+        };
+        let release_week = utils::build_week(release_date);
+        if format != OutputFormat::Table {
+            print_records(&[WeekRecord::new(&release_week, scheme)], format);
+            return;
         }
 
+        print_line!(
+            Kind::Info,
+            "{} is in release week {}.",
+            release_date,
+            scheme.label(release_week.week, release_week.year)
+        );
         return;
     }
 
-    if let Some(previous_weeks) = previous_weeks {
-        let curr_date = Utc::now().date_naive();
-        let release_weeks = utils::get_custom_week_range(curr_date, previous_weeks);
+    if weeks.is_set() {
+        let release_weeks = match utils::resolve_release_weeks(weeks) {
+            Ok(release_weeks) => release_weeks,
+            Err(e) => error!("{}", e),
+        };
+
+        if format != OutputFormat::Table {
+            let records: Vec<WeekRecord> = release_weeks
+                .iter()
+                .map(|week| WeekRecord::new(week, scheme))
+                .collect();
+            print_records(&records, format);
+            return;
+        }
 
+        let today = clock::today();
         for release_week in release_weeks {
-            info!("Release week: {}", release_week.week);
-            info!(
+            print_line!(
+                Kind::Info,
+                "Release week: {}",
+                scheme.label(release_week.week, release_week.year)
+            );
+            print_line!(
+                Kind::Info,
                 "Release week dates: {} - {}",
-                release_week
-                    .dates
-                    .first()
-                    .unwrap_or(&Utc::now().date_naive()),
-                release_week
-                    .dates
-                    .last()
-                    .unwrap_or(&Utc::now().date_naive())
+                release_week.dates.first().unwrap_or(&today),
+                release_week.dates.last().unwrap_or(&today)
             );
         }
-        return;
     }
+}
 
-    if let Some(release_date_str) = release_date {
-        let release_date = utils::get_date_from_string(Some(release_date_str));
-        let release_week = utils::get_release_week_number(release_date);
-        info!("{} is in release week {}.", release_date, release_week);
+/// Prints info records in a non-table output format.
+///
+/// # Arguments
+///
+/// * `records` - The records to print
+/// * `format` - The output format
+fn print_records<R: OutputRecord>(records: &[R], format: OutputFormat) {
+    match output::render(records, format) {
+        Ok(rendered) => println!("{}", rendered),
+        Err(e) => error!("Cannot render info. Err: {}", e),
     }
 }
 
@@ -197,15 +307,16 @@ pub async fn info(
 ///
 /// # Week Calculation
 ///
-/// The function uses the application's standard week calculation:
+/// The function uses the configured week scheme (`WEEK_SCHEME`). With the
+/// default Saturday-to-Friday scheme:
 /// - Weeks start on Saturday and end on Friday
-/// - Week 1 begins on the Saturday before or on January 1st
+/// - Week 1 begins on the first Saturday on or after January 1st
 /// - Week numbers are 1-based and typically range from 1-52 (sometimes 53)
 ///
 /// # Date Range Formatting
 ///
 /// The date range is formatted as "YYYY-MM-DD - YYYY-MM-DD" showing the
-/// full week span from Saturday through Friday. This provides clear
+/// full week span, e.g. from Saturday through Friday. This provides clear
 /// visibility into which dates fall within the current release week.
 ///
 /// # Error Conditions
@@ -232,25 +343,21 @@ pub async fn info(
 ///
 /// # Date Handling
 ///
-/// Uses UTC time for consistency and to avoid timezone-related issues
-/// when determining the current week. The week calculation is based on
-/// the current UTC date, ensuring consistent behavior regardless of
-/// the user's local timezone.
+/// Uses [`clock::today`], i.e. the current date in the configured timezone
+/// (`TIMEZONE`, the system timezone by default) or the date given with
+/// `--today`/`SPORLCLI_TODAY`. A release week starting on Friday morning in
+/// Sydney is therefore already current there, although it is still Thursday
+/// in UTC.
 async fn current_release_week() -> Result<ReleaseWeekInfo, String> {
-    let curr_date = Utc::now().date_naive();
-    let release_week = utils::build_week(curr_date);
+    let today = clock::today();
+    let release_week = utils::build_week(today);
     Ok(ReleaseWeekInfo {
-        week: release_week.week.clone(),
+        week: release_week.week,
+        year: release_week.year,
         dates: format!(
             "{} - {}",
-            release_week
-                .dates
-                .first()
-                .unwrap_or(&Utc::now().date_naive()),
-            release_week
-                .dates
-                .last()
-                .unwrap_or(&Utc::now().date_naive())
+            release_week.dates.first().unwrap_or(&today),
+            release_week.dates.last().unwrap_or(&today)
         ),
     })
 }
@@ -25,12 +25,23 @@
 //! ### Artist Operations
 //!
 //! - [`update_artists`] - Synchronizes local artist cache with followed artists from Spotify
+//! - [`refresh_artists`] - Refreshes genres, popularity, followers and images of cached artists
 //! - [`list_artists`] - Displays cached followed artists with optional search filtering
+//! - [`mute_artist`] - Hides all or some release types of an artist from listings and playlists
+//! - [`allow_artist`] - Only shows the given release types of an artist
+//! - [`unmute_artist`] - Removes the mute or allow rule of an artist
+//! - [`list_artist_rules`] - Lists the mute and allow rules of all artists
+//! - [`list_genres`] - Lists the genres of cached artists with artist and release counts
 //!
 //! ### Release Operations
 //!
 //! - [`update_releases`] - Fetches and caches new releases from all followed artists
+//! - [`retry_failed_releases`] - Fetches releases of artists that failed in earlier updates
 //! - [`list_releases`] - Shows releases organized by week with time-range filtering
+//! - [`new_releases`] - Shows releases discovered since the previous update
+//! - [`undated_releases`] - Shows releases that only have a month or year as release date
+//! - [`reindex_releases`] - Rebuilds the weekly release cache for the configured week scheme
+//! - [`show_release`] - Shows the details and tracklist of a single release
 //!
 //! ### Playlist Operations
 //!
@@ -153,14 +164,26 @@
 
 mod artists;
 mod auth;
+mod genres;
 mod info;
 mod playlist;
 mod releases;
 
+pub use artists::allow_artist;
+pub use artists::list_artist_rules;
 pub use artists::list_artists;
+pub use artists::mute_artist;
+pub use artists::refresh_artists;
+pub use artists::unmute_artist;
 pub use artists::update_artists;
 pub use auth::auth;
+pub use genres::list_genres;
 pub use info::info;
 pub use playlist::playlist;
 pub use releases::list_releases;
+pub use releases::new_releases;
+pub use releases::reindex_releases;
+pub use releases::retry_failed_releases;
+pub use releases::show_release;
+pub use releases::undated_releases;
 pub use releases::update_releases;
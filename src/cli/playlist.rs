@@ -1,8 +1,13 @@
-use chrono::Datelike;
+use std::collections::{HashMap, HashSet};
 
 use crate::{
-    info, spotify, success,
-    types::{Album, GetSeveralAlbumsResponse, Track},
+    config, error, info,
+    management::{ArtistReleaseManager, ArtistRulesManager, EditionIndexManager},
+    spotify, success,
+    types::{
+        Album, ArtistFilter, GenreFilter, GetAlbumResponse, GetSeveralAlbumsResponse,
+        ReleaseTypeFilter, Track, WeekSelection,
+    },
     utils, warning,
 };
 
@@ -15,16 +20,22 @@ use crate::{
 ///
 /// # Arguments
 ///
-/// * `previous_weeks` - Number of weeks before the target date to include (None = current week only)
-/// * `release_date` - Specific date to target (None = current date)
+/// * `weeks` - Week selection (`--week`, `--from`/`--to`, `--previous-weeks`, `--release-date`);
+///   defaults to the most recent complete release week
+/// * `types` - Release type filter (`--type`, `--exclude-type`); all types by default
+/// * `genres` - Artist genre filter (`--genre`, `--exclude-genre`); all genres by default
+/// * `artists` - Credited artist filter (`--artist`); all artists by default
+/// * `show_hidden` - Report releases and tracks left out by title patterns or artist rules
 ///
 /// # Playlist Naming
 ///
-/// Playlists are named using the format: "Weekly Picks {week}/{year}"
+/// Playlists are named "Weekly Picks {label}", where the label follows the
+/// configured week scheme (`WEEK_SCHEME`).
 ///
 /// Examples:
-/// - "Weekly Picks 42/2023"
-/// - "Weekly Picks 1/2024"
+/// - "Weekly Picks 42/2023" (default Saturday-to-Friday weeks)
+/// - "Weekly Picks 1/2024 (fri-thu)" (Friday-to-Thursday weeks)
+/// - "Weekly Picks 2024-W01" (ISO-8601 weeks)
 ///
 /// # Workflow Overview
 ///
@@ -88,6 +99,9 @@ use crate::{
 ///
 /// # Create playlists for 2 weeks before specific date
 /// sporlcli playlist --release-date 2023-12-25 --previous-weeks 2
+///
+/// # Create playlist for week 32 of 2025
+/// sporlcli playlist --week 2025-W32
 /// ```
 ///
 /// # Playlist Content
@@ -96,6 +110,12 @@ use crate::{
 /// - First track from each album released during the week
 /// - Tracks from all release types (albums, singles, EPs, compilations)
 /// - Automatic deduplication of albums from the same artist
+/// - One track per release: of several editions (explicit and clean versions,
+///   deluxe editions, regional duplicates) only the preferred one is used,
+///   explicit over clean (see `GROUP_EDITIONS`)
+/// - No releases or tracks hidden by `TITLE_INCLUDE`/`TITLE_EXCLUDE` or
+///   artist rules; an album whose first track is hidden contributes its first
+///   shown track instead (`--show-hidden` reports what was left out)
 /// - Chronological ordering based on release data processing
 ///
 /// # API Limitations and Handling
@@ -139,17 +159,35 @@ use crate::{
 /// - Support for collaborative playlists
 /// - Playlist artwork customization
 /// - Integration with user's existing playlist folders
-pub async fn playlist(previous_weeks: Option<u32>, release_date: Option<String>) {
-    let curr_date = utils::get_date_from_string(release_date);
-    let curr_year = curr_date.year();
-    let release_weeks = utils::get_custom_week_range(curr_date, previous_weeks.unwrap_or(0));
+pub async fn playlist(
+    weeks: &WeekSelection,
+    types: &ReleaseTypeFilter,
+    genres: &GenreFilter,
+    artists: &ArtistFilter,
+    show_hidden: bool,
+) {
+    spotify::ensure_online("Creating playlists");
+    let release_weeks = match utils::resolve_release_weeks(weeks) {
+        Ok(release_weeks) => release_weeks,
+        Err(e) => error!("{}", e),
+    };
+
+    let scheme = config::week_scheme();
+    let ep_rule = config::ep_rule();
+    let genres_by_artist = match utils::load_genres_for(genres).await {
+        Ok(genres_by_artist) => genres_by_artist,
+        Err(e) => error!("{}", e),
+    };
+    let titles = config::title_filter();
+    let artist_release_mgr = ArtistReleaseManager::load()
+        .await
+        .unwrap_or_else(|_| ArtistReleaseManager::new(None));
+    let followed = artist_release_mgr.followed_artist_ids();
+    let rules = ArtistRulesManager::load_for(&artist_release_mgr).await;
 
     for release_week in release_weeks {
-        let playlist_name = format!(
-            "Weekly Picks {}/{}",
-            release_week.week.clone(),
-            curr_year.clone()
-        );
+        let label = scheme.label(release_week.week, release_week.year);
+        let playlist_name = format!("Weekly Picks {}", label);
 
         let playlist_exists = match spotify::playlist::exists(&playlist_name).await {
             Ok(exists) => exists,
@@ -164,24 +202,47 @@ pub async fn playlist(previous_weeks: Option<u32>, release_date: Option<String>)
             continue;
         }
 
-        info!(
-            "Gather album information for release week {}/{}",
-            release_week.week.clone(),
-            curr_year.clone()
-        );
+        info!("Gather album information for release week {}", label);
 
         let mut all_albums: Vec<GetSeveralAlbumsResponse> = Vec::new();
 
-        let releases: Vec<Album> =
-            match utils::get_weekly_releases(release_week.week, curr_year).await {
-                Ok(releases) => releases,
-                Err(e) => {
-                    warning!("{}", e);
-                    Vec::new()
-                }
-            };
+        let (mut releases, mut hidden) = match utils::get_weekly_releases(
+            release_week.week,
+            release_week.year,
+            &titles,
+            &followed,
+        )
+        .await
+        {
+            Ok(releases) => releases,
+            Err(e) => {
+                warning!("{}", e);
+                (Vec::new(), Vec::new())
+            }
+        };
+        let selected = |album: &Album| {
+            genres.matches(album, &genres_by_artist)
+                && types.matches(album, &ep_rule)
+                && artists.matches(album)
+        };
+        releases.retain(selected);
+        hidden.retain(|h| selected(&h.album));
+        hidden.extend(utils::split_hidden_releases(&mut releases, |album| {
+            rules.hidden_reason(album, &ep_rule)
+        }));
+        if show_hidden {
+            for h in &hidden {
+                info!("Left out {}: {}", h.album.name, h.reason);
+            }
+        }
 
         if releases.is_empty() {
+            if types.is_set() || genres.is_set() || artists.is_set() || !hidden.is_empty() {
+                info!(
+                    "No releases left after filtering by type, genre and artist rules in release week {}",
+                    label
+                );
+            }
             continue;
         }
 
@@ -209,25 +270,40 @@ pub async fn playlist(previous_weeks: Option<u32>, release_date: Option<String>)
             }
         }
 
-        success!(
-            "Album information gathered for release week {}/{}",
-            release_week.week.clone(),
-            curr_year.clone()
-        );
+        success!("Album information gathered for release week {}", label);
 
-        info!(
-            "Create playlist for release week {}/{}",
-            release_week.week.clone(),
-            curr_year.clone()
-        );
+        // full albums carry the durations the EP rule may need
+        let durations: HashMap<String, u64> = all_albums
+            .iter()
+            .flat_map(|response| response.albums.iter())
+            .filter_map(|album| album.duration_ms().map(|ms| (album.id.clone(), ms)))
+            .collect();
+        store_release_durations(&durations).await;
+        let editions = store_editions(&all_albums, &artist_release_mgr.release_ids()).await;
+        for release in &mut releases {
+            release.duration_ms = durations.get(&release.id).copied().or(release.duration_ms);
+        }
+        let details: HashMap<&str, &GetAlbumResponse> = all_albums
+            .iter()
+            .flat_map(|response| response.albums.iter())
+            .map(|album| (album.id.as_str(), album))
+            .collect();
+        releases.retain(|album| {
+            details.contains_key(album.id.as_str())
+                && types.matches(album, &ep_rule)
+                && rules.permits(album, &ep_rule)
+        });
+        let groups = if config::group_editions_enabled() {
+            utils::group_editions(releases, &editions.upcs(), &editions.explicit_album_ids())
+        } else {
+            releases.into_iter().map(|release| vec![release]).collect()
+        };
+
+        info!("Create playlist for release week {}", label);
 
         let playlist_id: Option<String> = match spotify::playlist::create(playlist_name).await {
             Ok(resp) => {
-                success!(
-                    "Playlist for release week {}/{} created.",
-                    release_week.week.clone(),
-                    curr_year.clone()
-                );
+                success!("Playlist for release week {} created.", label);
                 Some(resp.id.clone())
             }
             Err(e) => {
@@ -237,32 +313,107 @@ pub async fn playlist(previous_weeks: Option<u32>, release_date: Option<String>)
         };
 
         if let Some(playlist_id) = playlist_id {
-            info!(
-                "Add tracks to playlist for release week {}/{}",
-                release_week.week.clone(),
-                curr_year.clone()
-            );
-            let tracks: Vec<Track> = all_albums
-                .iter()
-                .flat_map(|ar| {
-                    ar.albums
-                        .iter()
-                        .flat_map(|album| album.tracks.items.first())
-                })
-                .cloned()
-                .collect();
+            info!("Add tracks to playlist for release week {}", label);
+            let mut tracks: Vec<Track> = Vec::new();
+            for editions in &groups {
+                // first track whose title is not hidden, from the preferred edition on
+                let mut picked: Option<(&GetAlbumResponse, &Track)> = None;
+                for album in editions.iter().map(|edition| details[edition.id.as_str()]) {
+                    let track = album.tracks.items.iter().find(|track| {
+                        match titles.hidden_reason(&track.name) {
+                            Some(reason) => {
+                                if show_hidden {
+                                    info!(
+                                        "Left out track {} of {}: {}",
+                                        track.name, album.name, reason
+                                    );
+                                }
+                                false
+                            }
+                            None => true,
+                        }
+                    });
+                    match track {
+                        Some(track) => {
+                            picked = Some((album, track));
+                            break;
+                        }
+                        None if show_hidden => {
+                            info!("Left out {}: all tracks are hidden", album.name)
+                        }
+                        None => {}
+                    }
+                }
+
+                if let Some((album, track)) = picked {
+                    tracks.push(track.clone());
+                    if show_hidden {
+                        for edition in editions.iter().filter(|edition| edition.id != album.id) {
+                            info!(
+                                "Left out {} ({}): edition of {} ({})",
+                                edition.name, edition.id, album.name, album.id
+                            );
+                        }
+                    }
+                }
+            }
 
             let tracks_chunks = tracks.chunks(100);
             for chunk in tracks_chunks {
                 match spotify::playlist::add_tracks(playlist_id.clone(), chunk.to_vec()).await {
-                    Ok(_) => success!(
-                        "Tracks added to playlist for release week {}/{}",
-                        release_week.week.clone(),
-                        curr_year.clone()
-                    ),
+                    Ok(_) => success!("Tracks added to playlist for release week {}", label),
                     Err(e) => warning!("Failed to add tracks to playlist: {}", e),
                 };
             }
         }
     }
 }
+
+/// Saves the edition facts of the albums fetched for a playlist to the edition index.
+///
+/// The indexed UPCs and explicit flags let release listings group editions
+/// the same way the playlist does. Entries of releases that are no longer in
+/// the artist cache are pruned. Failures only produce a warning.
+///
+/// # Arguments
+///
+/// * `responses` - Full album details fetched for the playlist
+/// * `release_ids` - IDs of the releases in the artist cache
+///
+/// # Returns
+///
+/// The edition index including the new entries.
+async fn store_editions(
+    responses: &[GetSeveralAlbumsResponse],
+    release_ids: &HashSet<String>,
+) -> EditionIndexManager {
+    let mut editions = EditionIndexManager::load()
+        .await
+        .unwrap_or_else(|_| EditionIndexManager::new(HashMap::new()));
+    for album in responses.iter().flat_map(|response| response.albums.iter()) {
+        editions.insert(album);
+    }
+    editions.retain_albums(release_ids);
+    if let Err(e) = editions.persist().await {
+        warning!("Failed to save album editions: {}", e);
+    }
+    editions
+}
+
+/// Saves release durations learned from full album details to the artist cache.
+///
+/// Failures only produce a warning; the durations are fetched again the next
+/// time a playlist needs them.
+async fn store_release_durations(durations: &HashMap<String, u64>) {
+    if durations.is_empty() {
+        return;
+    }
+    let Ok(mut manager) = ArtistReleaseManager::load().await else {
+        return;
+    };
+    if manager.set_release_durations(durations) > 0
+        && let Err(e) = manager.persist().await
+    {
+        warning!("Failed to save release durations: {}", e);
+    }
+}
@@ -1,19 +1,43 @@
-use std::time::Duration;
+use std::{
+    collections::{HashMap, HashSet},
+    time::{Duration, Instant},
+};
 
-use chrono::{Datelike, NaiveDate};
-use tabled::Table;
-use tokio::time::sleep;
+use chrono::{DateTime, Utc};
 
 use crate::{
-    error,
+    config, error, info,
     management::{
-        ArtistReleaseManager, ReleaseWeekManager, STATE_TYPE_RELEASES, StateManager, TokenManager,
+        AlbumDetailsManager, ArtistReleaseManager, ArtistRulesManager, EditionIndexManager,
+        ReleaseWeekManager, RetryQueueManager, STATE_TYPE_RELEASES, StateManager, TokenManager,
+        UpdateHistoryManager,
+    },
+    output::{self, AlbumRecord, ReleaseRecord},
+    progress::{self, ArtistStatus, Event, Progress, WaitReason},
+    shutdown,
+    spotify::{self, ErrorClass},
+    success,
+    types::{
+        Album, AlbumResponse, ArtistFilter, ArtistReleases, Checkpoint, GenreFilter,
+        HiddenReleaseTableRow, OutputFormat, ReleaseTypeFilter, ReleaseWeek, RunParams, UpdateRun,
+        UpdateSummary, WeekOfTheYear, WeekSelection,
     },
-    spotify, success,
-    types::{Album, ArtistReleases, ReleaseTableRow, ReleaseWeek},
-    utils, warning,
+    utils::{self, EpRule},
+    warning,
 };
 
+/// Number of artists fetched before pausing to stay below the rate limit.
+const ARTIST_CHUNK_SIZE: usize = 100;
+
+/// Pause after each chunk of fetched artists.
+const CHUNK_PAUSE: Duration = Duration::from_secs(30);
+
+/// Number of retries of an artist after a transient error.
+const TRANSIENT_RETRIES: u32 = 3;
+
+/// Backoff before the first retry of a transient error, doubled for each further retry.
+const TRANSIENT_BACKOFF: Duration = Duration::from_secs(10);
+
 /// Updates the local release cache with latest releases from followed artists.
 ///
 /// Performs a comprehensive update of release data by fetching releases for all
@@ -54,7 +78,9 @@ use crate::{
 /// # Error Handling and Resilience
 ///
 /// Comprehensive error handling for production use:
-/// - **Individual Failures**: Artist-level failures don't stop entire process
+/// - **Individual Failures**: Artist-level failures don't stop entire process;
+///   transient errors are retried with backoff, permanent ones (e.g. 404) are
+///   skipped, and failed artists are queued for `--retry-failed`
 /// - **Network Issues**: Graceful handling of connectivity problems
 /// - **API Errors**: Proper response to Spotify service issues
 /// - **Cache Failures**: Continues operation even with cache write failures
@@ -63,16 +89,20 @@ use crate::{
 /// # Progress Feedback
 ///
 /// Provides detailed real-time progress information:
-/// - Current artist being processed
-/// - Number of releases found per artist
-/// - Overall progress counters (current/total)
+/// - A progress bar over all artists, then over all week files
+/// - Elapsed time and an ETA that includes the planned 30-second pauses
+/// - Current artist being processed and number of releases found
 /// - Success/failure status for each operation
-/// - Final summary statistics
+/// - A final summary table of fetched, skipped (checkpoint) and failed
+///   artists, new releases and written weeks
 ///
 /// # Data Organization
 ///
 /// Organizes fetched data for efficient access:
 /// - **Artist-Release Mapping**: Associates releases with their artists
+/// - **Merging**: Merges fetched releases by album ID, so fetching one kind
+///   never drops cached releases of another kind; a release is only removed
+///   when a complete fetch of its kind no longer returns it
 /// - **Weekly Grouping**: Groups releases by their release week
 /// - **Year Organization**: Separates data by year for scalability
 /// - **Duplicate Removal**: Handles duplicate releases across artists
@@ -84,6 +114,26 @@ use crate::{
 /// - **Weekly Cache**: Organizes releases by week/year combinations
 /// - **State Cache**: Tracks processing progress for resume capability
 ///
+/// # Arguments
+///
+/// * `resume` - Continue an interrupted run even if it used different parameters
+/// * `restart` - Discard an interrupted run and start a new one
+///
+/// # Checkpoints
+///
+/// An interrupted run leaves a checkpoint with its run ID, parameters, start
+/// time, completed artists and failed artists. Without flags a run with the
+/// same parameters is resumed automatically, while a run with different
+/// parameters is refused. `--resume` continues it with its original
+/// parameters and `--restart` (or `--force`) discards it.
+///
+/// # Interruption
+///
+/// The first Ctrl-C lets the current request finish, stops fetching further
+/// artists, saves the checkpoint, the artist cache and the week files built
+/// so far and exits with `shutdown::EXIT_INTERRUPTED` (130). A second Ctrl-C
+/// quits immediately.
+///
 /// # Example Usage
 ///
 /// ```bash
@@ -93,6 +143,9 @@ use crate::{
 /// # Force complete refresh
 /// sporlcli releases update --force
 ///
+/// # Continue an interrupted run with its original parameters
+/// sporlcli releases update --resume
+///
 /// # Update specific release types
 /// sporlcli releases update --type album,single
 ///
@@ -113,16 +166,22 @@ use crate::{
 /// - Followed artists cache (run `sporlcli artists update` first)
 /// - Network connectivity for API requests
 /// - Sufficient disk space for cache files
-pub async fn update_releases(force: bool, release_types: &utils::ReleaseKinds) {
-    let pb = utils::create_progress_bar("Fetching releases for followed artists...");
-
-    let mut state = match StateManager::new(STATE_TYPE_RELEASES.to_string())
-        .load()
-        .await
-    {
-        Ok(state) => state,
-        Err(_) => StateManager::new(STATE_TYPE_RELEASES.to_string()),
+pub async fn update_releases(
+    force: bool,
+    release_types: &utils::ReleaseKinds,
+    resume: bool,
+    restart: bool,
+) {
+    spotify::ensure_online("Updating the release cache");
+    let params = RunParams {
+        release_types: release_types.to_string(),
+        force,
     };
+    let (mut state, release_types) = load_checkpoint(params, release_types, resume, restart).await;
+    let release_types = &release_types;
+
+    let run_started = Instant::now();
+    shutdown::install();
 
     let mut artist_release_mgr = ArtistReleaseManager::load()
         .await
@@ -138,129 +197,568 @@ pub async fn update_releases(force: bool, release_types: &utils::ReleaseKinds) {
         }
     };
 
-    let mut remote_releases: Vec<Album> = Vec::new();
+    let mut retry_queue = RetryQueueManager::load()
+        .await
+        .unwrap_or_else(|_| RetryQueueManager::new(Vec::new()));
+
     let artist_releases: Vec<ArtistReleases> = if let Some(ar) = artist_release_mgr.all() {
         ar
     } else {
         Vec::new()
     };
 
-    let artist_chunks = artist_releases.chunks(100);
-    let artists_total = artist_releases.len().clone();
+    let artist_chunks = artist_releases.chunks(ARTIST_CHUNK_SIZE);
+    let chunks_total = artist_chunks.len();
+    let artists_total = artist_releases.len();
     let mut artists_count = 0;
     let mut artist_cached = false;
+    let mut summary = UpdateSummary::default();
+
+    let pb = Progress::with_total(
+        "Fetching releases for followed artists...",
+        artists_total as u64,
+    );
+
+    'chunk: for (chunk_index, artist_chunk) in artist_chunks.enumerate() {
+        // every chunk of fetched artists is followed by a throttle pause
+        pb.set_planned_waits(CHUNK_PAUSE * (chunks_total - chunk_index) as u32);
 
-    'chunk: for artist_chunk in artist_chunks {
         for artist in artist_chunk {
-            let token = token_mgr.get_valid_token().await;
+            if shutdown::requested() {
+                break 'chunk;
+            }
 
-            if state.has(artist.artist.id.clone()) && !force {
+            if state.has(artist.artist.id.clone()) {
+                progress::emit(Event::ArtistFinished {
+                    artist_id: artist.artist.id.clone(),
+                    artist_name: artist.artist.name.clone(),
+                    position: artists_count + 1,
+                    total: artists_total,
+                    status: ArtistStatus::Skipped,
+                    error: None,
+                });
                 pb.set_message(format!(
-                    "Releases for artist {artist_name} already cached. ({artists_count}/{artists_total})",
+                    "Releases for artist {artist_name} already cached.",
                     artist_name = artist.artist.name.clone(),
-                    artists_count = artists_count,
-                    artists_total = artists_total
                 ));
                 artist_cached = true;
                 artists_count += 1;
-
-                remote_releases.extend(artist.releases.clone());
+                summary.skipped += 1;
+                pb.inc(1);
                 continue;
             }
 
             artist_cached = false;
+            progress::emit(Event::ArtistStarted {
+                artist_id: artist.artist.id.clone(),
+                artist_name: artist.artist.name.clone(),
+                position: artists_count + 1,
+                total: artists_total,
+            });
 
-            match spotify::releases::get_release_for_artist(
-                artist.artist.id.clone(),
-                &token,
-                50,
-                release_types,
-            )
-            .await
-            {
-                Ok(releases) => {
+            match fetch_artist_releases(&artist.artist.id, &mut token_mgr, release_types).await {
+                Ok(response) => {
+                    progress::emit(Event::ReleasesFound {
+                        artist_id: artist.artist.id.clone(),
+                        artist_name: artist.artist.name.clone(),
+                        count: response.items.len(),
+                    });
                     pb.set_message(format!(
-                        "Fetched {releases} releases from artist {artist_name} ({artists_count}/{artists_total}).",
-                        releases = releases.len().clone(),
+                        "Fetched {releases} releases from artist {artist_name}.",
+                        releases = response.items.len(),
                         artist_name = artist.artist.name.clone(),
-                        artists_count = artists_count,
-                        artists_total = artists_total
                     ));
-                    remote_releases.extend(releases.clone());
                     state.add(artist.artist.id.clone());
                     artists_count += 1;
+                    summary.fetched += 1;
 
-                    // cache release for artist
-                    if releases.len() > 0 {
-                        match artist_release_mgr
-                            .add_releases_to_artist(&artist.artist.id, releases)
-                            .persist()
-                            .await
-                        {
-                            Ok(_) => {
-                                pb.set_message(format!(
-                                    "Releases for artist {artist_name} cached. ({artists_count}/{artists_total})",
-                                    artist_name = artist.artist.name,
-                                    artists_count = artists_count,
-                                    artists_total = artists_total
-                                ));
-                            }
-                            Err(e) => {
-                                pb.set_message(format!(
-                                    "Cannot cache releases for artist {artist_name} ({artists_count}/{artists_total}): {e}",
-                                    artist_name = artist.artist.name,
-                                    artists_count = artists_count,
-                                    artists_total = artists_total
-                                ));
-                            }
-                        }
+                    if let Err(e) = state.persist().await {
+                        pb.set_message(format!("Failed to persist state: {e:?}"));
                     }
-                }
-                Err(e) => {
-                    pb.set_message(format!(
-                        "Failed to load releases for artist {artist_name}: {error} ({artists_count}/{artists_total})",
-                        artist_name = artist.artist.name.clone(),
-                        error = e,
-                        artists_count = artists_count,
-                        artists_total = artists_total
-                    ));
 
-                    match state.persist().await {
-                        Ok(_) => pb.set_message(format!(
-                            "Successfully persisted state. ({artists_count}/{artists_total})",
-                            artists_count = artists_count,
-                            artists_total = artists_total
-                        )),
+                    match store_artist_releases(
+                        &mut artist_release_mgr,
+                        &artist.artist.id,
+                        response,
+                        release_types,
+                    )
+                    .await
+                    {
+                        Ok(new_releases) => {
+                            summary.new_releases += new_releases;
+                            pb.set_message(format!(
+                                "Releases for artist {artist_name} cached.",
+                                artist_name = artist.artist.name,
+                            ));
+                        }
                         Err(e) => {
-                            pb.set_message(format!("Failed to persist state: {error:?} ({artists_count}/{artists_total})",
-                                error = e,
-                                artists_count = artists_count,
-                                artists_total = artists_total
+                            pb.set_message(format!(
+                                "Cannot cache releases for artist {artist_name}: {e}",
+                                artist_name = artist.artist.name,
                             ));
                         }
                     }
 
-                    break 'chunk;
+                    if retry_queue.remove(&artist.artist.id)
+                        && let Err(e) = retry_queue.persist().await
+                    {
+                        pb.suspend(|| warning!("Cannot save retry queue. Err: {}", e));
+                    }
+
+                    progress::emit(Event::ArtistFinished {
+                        artist_id: artist.artist.id.clone(),
+                        artist_name: artist.artist.name.clone(),
+                        position: artists_count,
+                        total: artists_total,
+                        status: ArtistStatus::Fetched,
+                        error: None,
+                    });
+                    pb.inc(1);
+                }
+                Err((e, class)) => {
+                    artists_count += 1;
+                    summary.failed += 1;
+                    progress::emit(Event::ArtistFinished {
+                        artist_id: artist.artist.id.clone(),
+                        artist_name: artist.artist.name.clone(),
+                        position: artists_count,
+                        total: artists_total,
+                        status: ArtistStatus::Failed,
+                        error: Some(e.to_string()),
+                    });
+                    pb.suspend(|| {
+                        warning!(
+                            "Failed to load releases for artist {}: {} ({})",
+                            artist.artist.name,
+                            e,
+                            describe_failure(class)
+                        )
+                    });
+
+                    // keep going: the artist is retried by the next run or --retry-failed
+                    state.fail(artist.artist.id.clone(), e.to_string());
+                    if let Err(e) = state.persist().await {
+                        pb.set_message(format!("Failed to persist state: {e:?}"));
+                    }
+                    if let Err(e) = retry_queue
+                        .record(
+                            &artist.artist.id,
+                            &artist.artist.name,
+                            &e.to_string(),
+                            class == ErrorClass::Permanent,
+                        )
+                        .persist()
+                        .await
+                    {
+                        pb.suspend(|| warning!("Cannot save retry queue. Err: {}", e));
+                    }
+                    pb.inc(1);
                 }
             }
         }
 
         if !artist_cached {
-            sleep(Duration::from_secs(30)).await;
+            progress::wait(CHUNK_PAUSE, WaitReason::Throttle).await;
         }
     }
 
-    pb.finish();
+    let interrupted = shutdown::requested() && artists_count < artists_total;
+    if interrupted {
+        pb.set_message("Saving progress...");
+        if let Err(e) = state.persist().await {
+            pb.suspend(|| warning!("Cannot save checkpoint. Err: {:?}", e));
+        }
+        if let Err(e) = artist_release_mgr.persist().await {
+            pb.suspend(|| warning!("Cannot save release cache. Err: {}", e));
+        }
+    }
 
     // @todo implement cleanup of stste
     if artists_count == artists_total {
+        let started_at = state.get_state().started_at;
         match state.clear().await {
             Ok(_) => success!("State cache cleaned."),
             Err(e) => warning!("Cannot cleanup state cache. Err: {:?}", e),
         }
+
+        let mut history = UpdateHistoryManager::load()
+            .await
+            .unwrap_or_else(|_| UpdateHistoryManager::new(Vec::new()));
+        let run = UpdateRun {
+            started_at,
+            finished_at: Utc::now().timestamp() as u64,
+            release_types: release_types.to_string(),
+        };
+        if let Err(e) = history.record(run).persist().await {
+            warning!("Cannot record update run. Err: {}", e);
+        }
+    }
+
+    // rebuild the weekly cache from all cached releases so no kind or artist is lost
+    summary.weeks = rebuild_week_caches(&artist_release_mgr, &pb).await;
+
+    pb.finish_and_clear();
+
+    let elapsed = run_started.elapsed();
+    progress::emit(Event::UpdateFinished {
+        summary: summary.clone(),
+        elapsed_seconds: elapsed.as_secs(),
+        interrupted,
+    });
+    println!("{}", output::render_table([summary], OutputFormat::Table));
+    if interrupted {
+        warning!(
+            "Update interrupted after {}/{} artists. Run sporlcli releases update to resume.",
+            artists_count,
+            artists_total
+        );
+        std::process::exit(shutdown::EXIT_INTERRUPTED);
+    }
+
+    success!(
+        "Release cache updated in {}.",
+        progress::format_duration(elapsed)
+    );
+}
+
+/// Fetches the releases of one artist, backing off on transient errors.
+///
+/// Transient errors (rate limits, server and network errors) are retried up
+/// to three times after 10, 20 and 40 seconds; permanent errors (e.g. a 404
+/// for a deleted artist) are returned right away.
+///
+/// # Returns
+///
+/// The first page of releases, or the last error together with its class.
+async fn fetch_artist_releases(
+    artist_id: &str,
+    token_mgr: &mut TokenManager,
+    release_types: &utils::ReleaseKinds,
+) -> Result<AlbumResponse, (reqwest::Error, ErrorClass)> {
+    let mut retries = 0;
+    loop {
+        let token = token_mgr.get_valid_token().await;
+        match spotify::releases::get_release_for_artist(
+            artist_id.to_string(),
+            &token,
+            50,
+            release_types,
+        )
+        .await
+        {
+            Ok(response) => return Ok(response),
+            Err(e) => {
+                let class = ErrorClass::of(&e);
+                if class == ErrorClass::Permanent
+                    || retries >= TRANSIENT_RETRIES
+                    || shutdown::requested()
+                {
+                    return Err((e, class));
+                }
+                progress::wait(TRANSIENT_BACKOFF * 2u32.pow(retries), WaitReason::Backoff).await;
+                retries += 1;
+            }
+        }
     }
+}
+
+/// Merges fetched releases into the artist cache and persists it.
+///
+/// Releases of the fetched kinds that a complete fetch no longer returns are
+/// removed from the cache.
+///
+/// # Returns
+///
+/// The number of releases that were not cached before, or an error message
+/// if the cache cannot be written.
+async fn store_artist_releases(
+    artist_release_mgr: &mut ArtistReleaseManager,
+    artist_id: &str,
+    response: AlbumResponse,
+    release_types: &utils::ReleaseKinds,
+) -> Result<usize, String> {
+    let new_releases = artist_release_mgr.count_unknown_releases(artist_id, &response.items);
 
-    let releases_per_week = match prepare_remote_releases(remote_releases).await {
+    // only a complete fetch proves that a release of these kinds is gone
+    if response.is_complete() {
+        let fetched_ids: HashSet<String> = response.items.iter().map(|a| a.id.clone()).collect();
+        artist_release_mgr.prune_releases_for_artist(
+            artist_id,
+            &release_types.fetch_groups(),
+            &fetched_ids,
+        );
+    }
+
+    artist_release_mgr
+        .add_releases_to_artist(artist_id, response.items)
+        .persist()
+        .await?;
+    Ok(new_releases)
+}
+
+/// Describes what happens to an artist after a failure of the given class.
+fn describe_failure(class: ErrorClass) -> &'static str {
+    match class {
+        ErrorClass::Permanent => "permanent error, skipped and queued",
+        ErrorClass::Transient => "still failing after retries, queued for retry",
+    }
+}
+
+/// Fetches the releases of the artists in the retry queue only.
+///
+/// Failed artists of earlier `releases update` runs are kept in the retry
+/// queue together with their last error. This retries exactly those artists
+/// without touching the checkpoint of a regular run: recovered artists are
+/// merged into the cache and removed from the queue, artists that still fail
+/// stay queued with an increased attempt counter. Queued artists that are no
+/// longer followed are dropped. Afterwards the weekly cache is rebuilt and a
+/// summary table is printed.
+///
+/// # Arguments
+///
+/// * `release_types` - Release kinds to fetch
+///
+/// # Example Usage
+///
+/// ```bash
+/// sporlcli releases update --retry-failed
+/// ```
+pub async fn retry_failed_releases(release_types: &utils::ReleaseKinds) {
+    spotify::ensure_online("Retrying failed artists");
+    shutdown::install();
+    let run_started = Instant::now();
+
+    let mut retry_queue = RetryQueueManager::load()
+        .await
+        .unwrap_or_else(|_| RetryQueueManager::new(Vec::new()));
+    if retry_queue.is_empty() {
+        success!("No failed artists to retry.");
+        return;
+    }
+
+    let mut artist_release_mgr = match ArtistReleaseManager::load().await {
+        Ok(manager) => manager,
+        Err(e) => error!(
+            "Failed to load release cache: {}\nRun sporlcli releases update.",
+            e
+        ),
+    };
+
+    let mut token_mgr = match TokenManager::load().await {
+        Ok(manager) => manager,
+        Err(e) => error!(
+            "Failed to load token. Please run sporlcli auth\n Error: {}",
+            e
+        ),
+    };
+
+    let queued = retry_queue.entries().to_vec();
+    let total = queued.len();
+    let mut summary = UpdateSummary::default();
+    let mut processed = 0;
+
+    let pb = Progress::with_total("Retrying failed artists...", total as u64);
+
+    for (index, entry) in queued.iter().enumerate() {
+        if shutdown::requested() {
+            break;
+        }
+        if index > 0 && index % ARTIST_CHUNK_SIZE == 0 {
+            progress::wait(CHUNK_PAUSE, WaitReason::Throttle).await;
+        }
+
+        processed += 1;
+        if artist_release_mgr
+            .get_releases_for_artist(&entry.artist_id)
+            .is_none()
+        {
+            pb.suspend(|| {
+                info!(
+                    "{} is no longer followed, removed from the retry queue.",
+                    entry.artist_name
+                )
+            });
+            retry_queue.remove(&entry.artist_id);
+            pb.inc(1);
+            continue;
+        }
+
+        progress::emit(Event::ArtistStarted {
+            artist_id: entry.artist_id.clone(),
+            artist_name: entry.artist_name.clone(),
+            position: processed,
+            total,
+        });
+
+        match fetch_artist_releases(&entry.artist_id, &mut token_mgr, release_types).await {
+            Ok(response) => {
+                progress::emit(Event::ReleasesFound {
+                    artist_id: entry.artist_id.clone(),
+                    artist_name: entry.artist_name.clone(),
+                    count: response.items.len(),
+                });
+                summary.fetched += 1;
+                match store_artist_releases(
+                    &mut artist_release_mgr,
+                    &entry.artist_id,
+                    response,
+                    release_types,
+                )
+                .await
+                {
+                    Ok(new_releases) => {
+                        summary.new_releases += new_releases;
+                        retry_queue.remove(&entry.artist_id);
+                        pb.set_message(format!(
+                            "Releases for artist {} cached.",
+                            entry.artist_name
+                        ));
+                    }
+                    Err(e) => pb.suspend(|| {
+                        warning!(
+                            "Cannot cache releases for artist {}: {}",
+                            entry.artist_name,
+                            e
+                        )
+                    }),
+                }
+                progress::emit(Event::ArtistFinished {
+                    artist_id: entry.artist_id.clone(),
+                    artist_name: entry.artist_name.clone(),
+                    position: processed,
+                    total,
+                    status: ArtistStatus::Fetched,
+                    error: None,
+                });
+            }
+            Err((e, class)) => {
+                summary.failed += 1;
+                progress::emit(Event::ArtistFinished {
+                    artist_id: entry.artist_id.clone(),
+                    artist_name: entry.artist_name.clone(),
+                    position: processed,
+                    total,
+                    status: ArtistStatus::Failed,
+                    error: Some(e.to_string()),
+                });
+                pb.suspend(|| {
+                    warning!(
+                        "Failed to load releases for artist {}: {} ({})",
+                        entry.artist_name,
+                        e,
+                        describe_failure(class)
+                    )
+                });
+                retry_queue.record(
+                    &entry.artist_id,
+                    &entry.artist_name,
+                    &e.to_string(),
+                    class == ErrorClass::Permanent,
+                );
+            }
+        }
+
+        if let Err(e) = retry_queue.persist().await {
+            pb.suspend(|| warning!("Cannot save retry queue. Err: {}", e));
+        }
+        pb.inc(1);
+    }
+
+    let interrupted = processed < total;
+    if let Err(e) = retry_queue.persist().await {
+        pb.suspend(|| warning!("Cannot save retry queue. Err: {}", e));
+    }
+
+    summary.weeks = rebuild_week_caches(&artist_release_mgr, &pb).await;
+    pb.finish_and_clear();
+
+    let elapsed = run_started.elapsed();
+    progress::emit(Event::UpdateFinished {
+        summary: summary.clone(),
+        elapsed_seconds: elapsed.as_secs(),
+        interrupted,
+    });
+    println!(
+        "{}",
+        output::render_table([summary.clone()], OutputFormat::Table)
+    );
+
+    if interrupted {
+        warning!(
+            "Retry interrupted after {}/{} artists. Run sporlcli releases update --retry-failed to continue.",
+            processed,
+            total
+        );
+        std::process::exit(shutdown::EXIT_INTERRUPTED);
+    }
+
+    if retry_queue.is_empty() {
+        success!("All failed artists recovered.");
+    } else {
+        warning!(
+            "{} artists are still failing and stay in the retry queue.",
+            retry_queue.entries().len()
+        );
+    }
+}
+
+/// Rebuilds the weekly release cache from the artist release cache.
+///
+/// Week files are derived data: every cached artist release is placed into
+/// its release week again using the configured week scheme (`WEEK_SCHEME`).
+/// Run this after switching week schemes to populate the new scheme's week
+/// directories without fetching anything from Spotify. Week files of other
+/// schemes are kept in their own directories and are left untouched.
+///
+/// # Example Usage
+///
+/// ```bash
+/// WEEK_SCHEME=iso sporlcli releases reindex
+/// ```
+pub async fn reindex_releases() {
+    let artist_release_mgr = match ArtistReleaseManager::load().await {
+        Ok(manager) => manager,
+        Err(e) => error!(
+            "Failed to load release cache: {}\nRun sporlcli releases update.",
+            e
+        ),
+    };
+
+    let pb = Progress::with_total("Rebuilding weekly release cache...", 0);
+    let weeks = rebuild_week_caches(&artist_release_mgr, &pb).await;
+    pb.finish_and_clear();
+
+    success!(
+        "Weekly release cache rebuilt: {} weeks using the {} week scheme.",
+        weeks,
+        config::week_scheme()
+    );
+}
+
+/// Writes one week file per release week from all cached artist releases.
+///
+/// # Arguments
+///
+/// * `artist_release_mgr` - The artist release cache to derive the weeks from
+/// * `pb` - Progress bar receiving a message per cached week
+///
+/// # Returns
+///
+/// The number of week files written.
+async fn rebuild_week_caches(artist_release_mgr: &ArtistReleaseManager, pb: &Progress) -> usize {
+    let cached_releases: Vec<Album> = artist_release_mgr
+        .all()
+        .unwrap_or_default()
+        .into_iter()
+        .flat_map(|ar| ar.releases)
+        .collect();
+
+    let releases_per_week = match prepare_remote_releases(
+        cached_releases,
+        config::place_approximate_releases(),
+    )
+    .await
+    {
         Ok(releases) => releases,
         Err(e) => {
             warning!("Failed to prepare remote releases: {}", e);
@@ -268,30 +766,196 @@ pub async fn update_releases(force: bool, release_types: &utils::ReleaseKinds) {
         }
     };
 
-    for release_per_week in releases_per_week.clone() {
-        match ReleaseWeekManager::new(
-            release_per_week.week.week.clone(),
-            release_per_week.year.clone(),
-            Some(release_per_week.releases.clone()),
-        )
-        .save_to_cache()
-        .await
+    pb.start_phase(
+        "Writing weekly release cache...",
+        releases_per_week.len() as u64,
+    );
+
+    let mut written = 0;
+    for release_per_week in releases_per_week {
+        let week = release_per_week.week.week;
+        let year = release_per_week.week.year;
+        match ReleaseWeekManager::new(week, year, Some(release_per_week.releases))
+            .save_to_cache()
+            .await
         {
-            Ok(_) => pb.set_message(format!(
-                "Releases for week {week} in year {year} cached.",
-                week = release_per_week.week.week.clone(),
-                year = release_per_week.year.clone()
-            )),
-            Err(_) => warning!(
-                "Cannot cache releases for week {week} in year {year}.",
-                week = release_per_week.week.week.clone(),
-                year = release_per_week.year.clone()
-            ),
+            Ok(_) => {
+                written += 1;
+                pb.set_message(format!("Releases for week {week} in year {year} cached."))
+            }
+            Err(_) => {
+                pb.suspend(|| warning!("Cannot cache releases for week {week} in year {year}."))
+            }
         }
+        pb.inc(1);
     }
 
-    pb.finish_and_clear();
-    success!("Release cache updated.");
+    written
+}
+
+/// Lists cached releases that have no exact release date.
+///
+/// Spotify dates some releases (often reissues or small-label releases) only
+/// by month (`2024-10`) or year (`2024`). Those releases cannot be assigned to
+/// a release week reliably, so they are kept in the artist cache and listed
+/// here instead. Set `PLACE_APPROXIMATE_RELEASES=true` to additionally place
+/// them into the week containing the first day of their month or year.
+///
+/// # Arguments
+///
+/// * `format` - Output format (`table`, `json`, `ndjson`, `csv` or `markdown`)
+///
+/// # Example Usage
+///
+/// ```bash
+/// sporlcli releases undated
+/// sporlcli releases undated --format csv
+/// ```
+///
+/// # Output Example
+///
+/// ```text
+/// Releases without an exact release date
+/// ┌───────────────────┬───────┬─────────────────────┬─────────────┐
+/// │ date              │ type  │ name                │ artists     │
+/// ├───────────────────┼───────┼─────────────────────┼─────────────┤
+/// │ 2024-10 (approx.) │ album │ Remastered Classics │ Artist Name │
+/// │ 2019 (approx.)    │ album │ Early Recordings    │ Band Name   │
+/// └───────────────────┴───────┴─────────────────────┴─────────────┘
+/// ```
+pub async fn undated_releases(format: OutputFormat) {
+    let artist_release_mgr = match ArtistReleaseManager::load().await {
+        Ok(manager) => manager,
+        Err(e) => error!(
+            "Failed to load release cache: {}\nRun sporlcli releases update.",
+            e
+        ),
+    };
+
+    let mut undated: Vec<Album> = artist_release_mgr
+        .all()
+        .unwrap_or_default()
+        .into_iter()
+        .flat_map(|ar| ar.releases)
+        .filter(|album| !album.has_exact_date())
+        .collect();
+    let ep_rule = config::ep_rule();
+    let rules = ArtistRulesManager::load_for(&artist_release_mgr).await;
+    let titles = config::title_filter();
+    undated.retain(|album| {
+        titles.hidden_reason(&album.name).is_none() && rules.permits(album, &ep_rule)
+    });
+    utils::remove_duplicate_albums(&mut undated);
+
+    if undated.is_empty() && !format.is_machine_readable() {
+        info!("All cached releases have an exact release date.");
+        return;
+    }
+
+    let followed = artist_release_mgr.followed_artist_ids();
+    utils::sort_albums_by_date_and_artist(&mut undated, &followed);
+    let editions = EditionIndexManager::load()
+        .await
+        .unwrap_or_else(|_| EditionIndexManager::new(HashMap::new()));
+    let records = edition_records(undated, &editions, release_week_of, &followed, &ep_rule);
+
+    print_releases("Releases without an exact release date", &records, format);
+}
+
+/// Loads the release update checkpoint and decides whether to resume it.
+///
+/// # Arguments
+///
+/// * `params` - Parameters of the current invocation
+/// * `release_types` - Release types of the current invocation
+/// * `resume` - Resume an existing checkpoint with its original parameters
+/// * `restart` - Discard an existing checkpoint
+///
+/// # Returns
+///
+/// The state manager to use for this run together with the release types
+/// to fetch, which are the checkpoint's types when a run is resumed.
+/// Exits with an error if a checkpoint with different parameters exists and
+/// neither `resume` nor `restart` was given.
+async fn load_checkpoint(
+    params: RunParams,
+    release_types: &utils::ReleaseKinds,
+    resume: bool,
+    restart: bool,
+) -> (StateManager, utils::ReleaseKinds) {
+    let force = params.force;
+    let mut fresh = StateManager::new(STATE_TYPE_RELEASES.to_string());
+    let existing = fresh.load().await.ok().filter(|state| !state.is_empty());
+
+    let state = match existing {
+        None => {
+            if resume {
+                warning!("No interrupted release update found. Starting a new run.");
+            }
+            None
+        }
+        Some(state) if restart || (force && !resume) => {
+            info!(
+                "Discarding interrupted release update ({}).",
+                describe_checkpoint(state.get_state())
+            );
+            None
+        }
+        Some(state) if state.matches(&params) => {
+            info!(
+                "Resuming interrupted release update ({}).",
+                describe_checkpoint(state.get_state())
+            );
+            Some(state)
+        }
+        Some(state) if resume => {
+            info!(
+                "Resuming interrupted release update with its original parameters ({}).",
+                describe_checkpoint(state.get_state())
+            );
+            Some(state)
+        }
+        Some(state) => error!(
+            "An interrupted release update with different parameters exists ({}).\nRun sporlcli releases update --resume to continue it or --restart to discard it.",
+            describe_checkpoint(state.get_state())
+        ),
+    };
+
+    match state {
+        Some(state) => {
+            let kinds = match &state.get_state().params {
+                Some(p) => match utils::parse_release_kinds(&p.release_types) {
+                    Ok(kinds) => kinds,
+                    Err(e) => error!("Invalid release types in checkpoint: {}", e),
+                },
+                None => release_types.clone(),
+            };
+            (state, kinds)
+        }
+        None => {
+            fresh.start(params);
+            (fresh, release_types.clone())
+        }
+    }
+}
+
+/// Formats a short, human readable description of a checkpoint.
+fn describe_checkpoint(checkpoint: &Checkpoint) -> String {
+    let started = DateTime::from_timestamp(checkpoint.started_at as i64, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M UTC").to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    let params = match &checkpoint.params {
+        Some(p) if p.force => format!("--type {} --force", p.release_types),
+        Some(p) => format!("--type {}", p.release_types),
+        None => "unknown parameters".to_string(),
+    };
+
+    format!(
+        "run {id}, started {started}, {params}, {done} artists done, {failed} failed",
+        id = checkpoint.run_id,
+        done = checkpoint.completed.len(),
+        failed = checkpoint.failed.len(),
+    )
 }
 
 /// Lists cached releases with optional time-based filtering and tabular display.
@@ -302,33 +966,47 @@ pub async fn update_releases(force: bool, release_types: &utils::ReleaseKinds) {
 ///
 /// # Arguments
 ///
-/// * `weeks_include` - Number of weeks before the target date to include (None = current week only)
-/// * `release_date` - Specific target date (None = current date)
+/// * `weeks` - Week selection (`--week`, `--from`/`--to`, `--previous-weeks`, `--release-date`)
+/// * `types` - Release type filter (`--type`, `--exclude-type`); all types by default
+/// * `genres` - Artist genre filter (`--genre`, `--exclude-genre`); all genres by default
+/// * `artists` - Credited artist filter (`--artist`); all artists by default
+/// * `show_hidden` - Also list releases hidden by title patterns or artist rules
+/// * `format` - Output format (`table`, `json`, `ndjson`, `csv` or `markdown`)
 ///
 /// # Display Format
 ///
-/// For each week, displays a table with:
+/// Machine-readable formats print the releases of all selected weeks as one
+/// document, including IDs, all artists, album type, release date precision,
+/// week and year (see the `output` module). With `show_hidden`, hidden releases
+/// are part of the document with their reason in `hidden`.
+///
+/// For each week, the table formats display a table with:
 /// - **Date**: Release date in YYYY-MM-DD format
 /// - **Name**: Album/release title
-/// - **Artists**: Primary artist name (first artist if multiple)
+/// - **Artists**: All credited artists, followed ones marked with `*`
 ///
 /// Each week's releases are displayed in a separate table with week/year headers.
+/// With `show_hidden`, a second table lists the hidden releases of the week and
+/// why they are hidden; otherwise only their number is reported.
 ///
 /// # Time Range Logic
 ///
 /// The function determines which weeks to display:
-/// - If `release_date` is provided, uses that as the target date
-/// - If `weeks_include` is provided, shows that many weeks before the target
-/// - If both are None, shows only the current week
-/// - If `weeks_include` is 0, shows only the target week
+/// - If `--week` is provided, shows that week (and `--previous-weeks` before it)
+/// - If `--from` is provided, shows every week covering `--from` to `--to` (default today)
+/// - If `--release-date` is provided, uses that as the target date
+/// - If `--previous-weeks` is provided, shows that many weeks before the target
+/// - If nothing is provided, shows only the current week
+/// - Invalid dates or weeks are reported as an error
 ///
 /// # Data Processing
 ///
 /// For each week's releases:
 /// 1. **Cache Loading**: Loads release data from weekly cache files
 /// 2. **Duplicate Removal**: Eliminates duplicate albums across artists
-/// 3. **Data Transformation**: Converts to table-friendly format
-/// 4. **Sorting**: Orders by date (descending) then artist (ascending)
+/// 3. **Sorting**: Orders by date (descending) then artist (ascending)
+/// 4. **Hiding**: Splits off releases hidden by `TITLE_INCLUDE`/`TITLE_EXCLUDE`
+///    and artist rules
 /// 5. **Table Generation**: Creates formatted table output
 ///
 /// # Error Handling
@@ -360,33 +1038,51 @@ pub async fn update_releases(force: bool, release_types: &utils::ReleaseKinds) {
 ///
 /// # Show 2 weeks before specific date
 /// sporlcli releases --release-date 2023-12-25 --previous-weeks 2
+///
+/// # Show a specific week
+/// sporlcli releases --week 2025/32
+///
+/// # Only singles, without releases the artist only appears on
+/// sporlcli releases --type single --exclude-type appears_on
+///
+/// # Releases of techno artists, but no hard techno
+/// sporlcli releases --genre techno --exclude-genre "/^hard/"
+///
+/// # Also list releases hidden by title patterns or artist rules
+/// sporlcli releases --show-hidden
+///
+/// # Only releases crediting a given artist, followed or not
+/// sporlcli releases --artist "daft punk"
+///
+/// # Show all weeks covering a date range
+/// sporlcli releases --from 2025-07-01 --to 2025-07-31
 /// ```
 ///
 /// # Output Example
 ///
 /// ```text
 /// Week: 42	Year: 2023
-/// ┌────────────┬─────────────────────────────┬─────────────────┐
-/// │ date       │ name                        │ artists         │
-/// ├────────────┼─────────────────────────────┼─────────────────┤
-/// │ 2023-10-20 │ New Album Title             │ Artist Name     │
-/// │ 2023-10-19 │ Latest Single               │ Another Artist  │
-/// │ 2023-10-18 │ EP Release                  │ Band Name       │
-/// └────────────┴─────────────────────────────┴─────────────────┘
+/// ┌────────────┬────────┬─────────────────────────────┬────────────────────────┐
+/// │ date       │ type   │ name                        │ artists                │
+/// ├────────────┼────────┼─────────────────────────────┼────────────────────────┤
+/// │ 2023-10-20 │ album  │ New Album Title             │ Artist Name*           │
+/// │ 2023-10-19 │ single │ Latest Single               │ Guest; Another Artist* │
+/// │ 2023-10-18 │ single │ EP Release                  │ Band Name*             │
+/// └────────────┴────────┴─────────────────────────────┴────────────────────────┘
 ///
 /// Week: 41	Year: 2023
-/// ┌────────────┬─────────────────────────────┬─────────────────┐
-/// │ date       │ name                        │ artists         │
-/// ├────────────┼─────────────────────────────┼─────────────────┤
-/// │ 2023-10-13 │ Previous Week Album         │ Previous Artist │
-/// └────────────┴─────────────────────────────┴─────────────────┘
+/// ┌────────────┬────────┬─────────────────────────────┬─────────────────┐
+/// │ date       │ type   │ name                        │ artists         │
+/// ├────────────┼────────┼─────────────────────────────┼─────────────────┤
+/// │ 2023-10-13 │ album  │ Previous Week Album         │ Previous Artist │
+/// └────────────┴────────┴─────────────────────────────┴─────────────────┘
 /// ```
 ///
 /// # Data Quality Features
 ///
 /// - **Duplicate Removal**: Handles same album appearing multiple times
-/// - **Consistent Sorting**: Always orders by date then artist
-/// - **Artist Simplification**: Shows primary artist to avoid cluttered display
+/// - **Consistent Sorting**: Always orders by date then followed artist
+/// - **Artist Highlighting**: Marks the followed artists among all credited ones
 /// - **Date Formatting**: Uses standard YYYY-MM-DD format for clarity
 ///
 /// # Performance Notes
@@ -395,72 +1091,427 @@ pub async fn update_releases(force: bool, release_types: &utils::ReleaseKinds) {
 /// - Table generation is fast for typical week sizes
 /// - Large time ranges may require multiple cache file loads
 /// - Output formatting time is proportional to total releases shown
-pub async fn list_releases(weeks_include: Option<u32>, release_date: Option<String>) {
-    // let release_date = match NaiveDate::parse_from_str(&album.release_date, "%Y-%m-%d")
-    let curr_date = utils::get_date_from_string(release_date);
-    let cur_year = curr_date.year();
-    let release_weeks = utils::get_custom_week_range(curr_date, weeks_include.unwrap_or(0));
-
-    for release_week in release_weeks.clone() {
-        let mut weekly_releases: Vec<Album> = match ReleaseWeekManager::new(
-            release_week.week.clone(),
-            cur_year,
-            None,
+pub async fn list_releases(
+    weeks: &WeekSelection,
+    types: &ReleaseTypeFilter,
+    genres: &GenreFilter,
+    artists: &ArtistFilter,
+    show_hidden: bool,
+    format: OutputFormat,
+) {
+    let release_weeks = match utils::resolve_release_weeks(weeks) {
+        Ok(release_weeks) => release_weeks,
+        Err(e) => error!("{}", e),
+    };
+
+    let ep_rule = config::ep_rule();
+    let genres_by_artist = match utils::load_genres_for(genres).await {
+        Ok(genres_by_artist) => genres_by_artist,
+        Err(e) => error!("{}", e),
+    };
+    let titles = config::title_filter();
+    let artist_release_mgr = ArtistReleaseManager::load()
+        .await
+        .unwrap_or_else(|_| ArtistReleaseManager::new(None));
+    let followed = artist_release_mgr.followed_artist_ids();
+    let rules = ArtistRulesManager::load_for(&artist_release_mgr).await;
+    let editions = EditionIndexManager::load()
+        .await
+        .unwrap_or_else(|_| EditionIndexManager::new(HashMap::new()));
+    let mut records: Vec<ReleaseRecord> = Vec::new();
+    for release_week in release_weeks {
+        let (mut weekly_releases, mut hidden) = match utils::get_weekly_releases(
+            release_week.week,
+            release_week.year,
+            &titles,
+            &followed,
         )
-        .load_from_cache()
         .await
         {
-            Ok(manager) => match manager.get_releases().await {
-                Ok(releases) => releases,
-                Err(e) => {
-                    warning!(
-                        "Failed to load releases for week {}/{}: {}\nRun sporlcli releases update.",
-                        release_week.week.clone(),
-                        cur_year,
-                        e
-                    );
-                    continue;
-                }
-            },
+            Ok(releases) => releases,
             Err(e) => {
-                warning!(
-                    "Failed to load releases for week {}/{}: {:?}\nRun sporlcli releases update.",
-                    release_week.week.clone(),
-                    cur_year,
-                    e
-                );
+                warning!("{}", e);
                 continue;
             }
         };
 
-        utils::remove_duplicate_albums(&mut weekly_releases);
-
-        let mut weekly_releases_row: Vec<ReleaseTableRow> = weekly_releases
-            .into_iter()
-            .map(|a| ReleaseTableRow {
-                date: a.release_date,
-                name: a.name,
-                artists: a
-                    .artists
-                    .iter()
-                    .map(|a| a.name.clone())
-                    .collect::<Vec<String>>()
-                    .first()
-                    .unwrap_or(&String::new())
-                    .clone(),
+        let selected = |album: &Album| {
+            types.matches(album, &ep_rule)
+                && genres.matches(album, &genres_by_artist)
+                && artists.matches(album)
+        };
+        weekly_releases.retain(selected);
+        hidden.retain(|h| selected(&h.album));
+        hidden.extend(utils::split_hidden_releases(
+            &mut weekly_releases,
+            |album| rules.hidden_reason(album, &ep_rule),
+        ));
+
+        let weekly_records = edition_records(
+            weekly_releases,
+            &editions,
+            |_| Some(release_week.clone()),
+            &followed,
+            &ep_rule,
+        );
+        let hidden_records: Vec<ReleaseRecord> = hidden
+            .iter()
+            .map(|h| ReleaseRecord {
+                hidden: Some(h.reason.clone()),
+                ..ReleaseRecord::new(&h.album, Some(&release_week), &followed, &ep_rule)
             })
             .collect();
 
-        utils::sort_release_table_rows(&mut weekly_releases_row);
+        if format.is_machine_readable() {
+            // all weeks form a single document
+            records.extend(weekly_records);
+            if show_hidden {
+                records.extend(hidden_records);
+            }
+        } else {
+            let title = format!(
+                "Week: {week}\tYear: {year}",
+                week = release_week.week,
+                year = release_week.year
+            );
+            print_releases(&title, &weekly_records, format);
+            if show_hidden && !hidden_records.is_empty() {
+                println!(
+                    "{}\n{}\n",
+                    output::heading(&format!("Hidden in week {}", release_week.week), format),
+                    output::render_table(
+                        hidden_records.iter().map(HiddenReleaseTableRow::from),
+                        format
+                    )
+                );
+            } else if !hidden_records.is_empty() {
+                info!(
+                    "{} releases hidden. Use --show-hidden to list them.",
+                    hidden_records.len()
+                );
+            }
+        }
+    }
 
-        let table = Table::new(weekly_releases_row);
-        println!(
-            "Week: {week}\tYear: {year}\n{table}\n",
-            week = release_week.week.clone(),
-            year = cur_year,
-            table = table
-        );
+    if format.is_machine_readable() {
+        print_releases("", &records, format);
+    }
+}
+
+/// Lists releases discovered since the previous successful update.
+///
+/// Every release in the artist cache carries a `first_seen_at` timestamp that
+/// survives subsequent updates. This command shows all releases whose
+/// timestamp is newer than the previous successful `releases update` run, so
+/// the output contains exactly what the latest update found. An explicit
+/// point in time can be given instead.
+///
+/// # Arguments
+///
+/// * `since` - Optional point in time (Unix seconds, YYYY-MM-DD or RFC 3339);
+///   defaults to the end of the previous successful update
+/// * `format` - Output format (`table`, `json`, `ndjson`, `csv` or `markdown`)
+///
+/// # Example Usage
+///
+/// ```bash
+/// # What did the last update find?
+/// sporlcli releases new
+///
+/// # Everything discovered since a given day
+/// sporlcli releases new --since 2023-10-13
+/// ```
+///
+/// # Output Example
+///
+/// ```text
+/// New releases since 2023-10-13 09:12 UTC
+/// ┌────────────┬───────┬─────────────────────────────┬─────────────────┐
+/// │ date       │ type  │ name                        │ artists         │
+/// ├────────────┼───────┼─────────────────────────────┼─────────────────┤
+/// │ 2023-10-20 │ album │ New Album Title             │ Artist Name     │
+/// └────────────┴───────┴─────────────────────────────┴─────────────────┘
+/// ```
+pub async fn new_releases(since: Option<String>, format: OutputFormat) {
+    let since = match since {
+        Some(since) => match utils::parse_timestamp(&since) {
+            Ok(ts) => ts,
+            Err(e) => error!("{}", e),
+        },
+        None => {
+            let history = UpdateHistoryManager::load()
+                .await
+                .unwrap_or_else(|_| UpdateHistoryManager::new(Vec::new()));
+            if history.last_run().is_none() {
+                warning!("No completed release update recorded. Run sporlcli releases update.");
+            }
+            history.new_releases_threshold()
+        }
+    };
+
+    let artist_release_mgr = match ArtistReleaseManager::load().await {
+        Ok(manager) => manager,
+        Err(e) => error!(
+            "Failed to load release cache: {}\nRun sporlcli releases update.",
+            e
+        ),
+    };
+
+    let mut new_releases = artist_release_mgr.releases_first_seen_since(since);
+    let ep_rule = config::ep_rule();
+    let rules = ArtistRulesManager::load_for(&artist_release_mgr).await;
+    let titles = config::title_filter();
+    new_releases.retain(|album| {
+        titles.hidden_reason(&album.name).is_none() && rules.permits(album, &ep_rule)
+    });
+    utils::remove_duplicate_albums(&mut new_releases);
+
+    let since_label = DateTime::from_timestamp(since as i64, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M UTC").to_string())
+        .unwrap_or_else(|| since.to_string());
+
+    if new_releases.is_empty() && !format.is_machine_readable() {
+        info!("No new releases since {}.", since_label);
+        return;
+    }
+
+    let followed = artist_release_mgr.followed_artist_ids();
+    utils::sort_albums_by_date_and_artist(&mut new_releases, &followed);
+    let editions = EditionIndexManager::load()
+        .await
+        .unwrap_or_else(|_| EditionIndexManager::new(HashMap::new()));
+    let records = edition_records(
+        new_releases,
+        &editions,
+        release_week_of,
+        &followed,
+        &ep_rule,
+    );
+
+    print_releases(
+        &format!("New releases since {}", since_label),
+        &records,
+        format,
+    );
+}
+
+/// Shows the full details and tracklist of a single release.
+///
+/// The release is given by album ID, `spotify:album:` URI, `open.spotify.com`
+/// album URL or a search term matched against the names of cached releases.
+/// Details come from the album details cache; releases that are not cached
+/// yet or cached with an incomplete tracklist (or all, with `refresh`) are
+/// fetched through the several-albums endpoint, completed page by page
+/// through the album tracks endpoint and cached for later calls.
+///
+/// # Arguments
+///
+/// * `query` - Album ID, URI, URL or (part of) a cached release name
+/// * `refresh` - Fetch the details again even if they are cached
+/// * `format` - Output format; `json` and `ndjson` print the release with its
+///   tracks, `csv` prints the tracks, table formats print both as tables
+///
+/// # Error Handling
+///
+/// - Search terms matching no or several cached releases are reported, the
+///   latter with their IDs
+/// - Releases that are neither cached nor fetchable in offline mode are refused
+///
+/// # Example Usage
+///
+/// ```bash
+/// sporlcli releases show 4LH4d3cOWNNsVw41Gqt2kv
+/// sporlcli releases show "https://open.spotify.com/album/4LH4d3cOWNNsVw41Gqt2kv"
+/// sporlcli releases show "summer live" --format json
+/// ```
+///
+/// # Output Example
+///
+/// ```text
+/// Summer Live
+/// ┌──────────┬───────────────────────────────────────────────┐
+/// │ field    │ value                                         │
+/// ├──────────┼───────────────────────────────────────────────┤
+/// │ artists  │ Artist Name*; Guest                           │
+/// │ type     │ album                                         │
+/// │ released │ 2025-08-08                                    │
+/// │ label    │ Some Label                                    │
+/// │ upc      │ 602445790012                                  │
+/// │ tracks   │ 2                                             │
+/// │ duration │ 7:41                                          │
+/// │ url      │ https://open.spotify.com/album/4LH4d3cOWNNsVw │
+/// │ ℗        │ 2025 Some Label                               │
+/// └──────────┴───────────────────────────────────────────────┘
+///
+/// ┌───┬─────────────────┬─────────────────────┬──────────┐
+/// │ # │ name            │ artists             │ duration │
+/// ├───┼─────────────────┼─────────────────────┼──────────┤
+/// │ 1 │ Opening [E]     │ Artist Name         │ 3:35     │
+/// │ 2 │ Closing         │ Artist Name; Guest  │ 4:06     │
+/// └───┴─────────────────┴─────────────────────┴──────────┘
+/// ```
+pub async fn show_release(query: &str, refresh: bool, format: OutputFormat) {
+    let artist_release_mgr = ArtistReleaseManager::load()
+        .await
+        .unwrap_or_else(|_| ArtistReleaseManager::new(None));
+
+    let album_id = match utils::parse_album_id(query) {
+        Some(album_id) => album_id,
+        None => {
+            let releases = artist_release_mgr.find_releases(query);
+            match releases.as_slice() {
+                [album] => album.id.clone(),
+                [] => error!(
+                    "No cached release matches '{}'. Use an album ID or Spotify URL.",
+                    query
+                ),
+                several => error!(
+                    "'{}' matches {} releases: {}. Use the album ID instead.",
+                    query,
+                    several.len(),
+                    several
+                        .iter()
+                        .take(10)
+                        .map(|album| format!(
+                            "{} ({}, {})",
+                            album.name, album.release_date, album.id
+                        ))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            }
+        }
+    };
+
+    let mut details = AlbumDetailsManager::load()
+        .await
+        .unwrap_or_else(|_| AlbumDetailsManager::new(HashMap::new()));
+
+    let album = match details.get(&album_id) {
+        Some(album) if !refresh && album.has_all_tracks() => album.clone(),
+        _ => {
+            spotify::ensure_online("Fetching release details");
+            let mut album = match spotify::releases::get_several_albums(&[album_id.as_str()]).await
+            {
+                Ok(response) => match response.albums.into_iter().next() {
+                    Some(album) => album,
+                    None => error!("Spotify does not know the album {}.", album_id),
+                },
+                Err(e) => error!("Failed to fetch album {}. Err: {}", album_id, e),
+            };
+            if let Err(e) = spotify::releases::get_remaining_album_tracks(&mut album).await {
+                error!(
+                    "Failed to fetch the tracks of album {}. Err: {}",
+                    album_id, e
+                );
+            }
+            if let Err(e) = details.insert(album.clone()).persist().await {
+                warning!("Failed to cache album details. Err: {}", e);
+            }
+            let release_ids = artist_release_mgr.release_ids();
+            if release_ids.contains(&album.id) {
+                let mut editions = EditionIndexManager::load()
+                    .await
+                    .unwrap_or_else(|_| EditionIndexManager::new(HashMap::new()));
+                editions.insert(&album).retain_albums(&release_ids);
+                if let Err(e) = editions.persist().await {
+                    warning!("Failed to save album editions. Err: {}", e);
+                }
+            }
+            album
+        }
+    };
+
+    let record = AlbumRecord::new(&album, &artist_release_mgr.followed_artist_ids());
+    let rendered = match format {
+        OutputFormat::Json | OutputFormat::Ndjson => {
+            output::render(std::slice::from_ref(&record), format)
+        }
+        OutputFormat::Csv => output::render(&record.tracks, format),
+        OutputFormat::Table | OutputFormat::Markdown => {
+            output::render(&record.tracks, format).map(|tracks| {
+                format!(
+                    "{}\n{}\n\n{}",
+                    output::heading(&record.name, format),
+                    output::render_table(record.field_rows(), format),
+                    tracks
+                )
+            })
+        }
+    };
+    match rendered {
+        Ok(rendered) => println!("{}", rendered),
+        Err(e) => error!("Cannot render release. Err: {}", e),
+    }
+}
+
+/// Prints release records in the given output format.
+///
+/// Human-readable formats print `title` above the table, machine-readable
+/// formats print nothing but the records.
+///
+/// # Arguments
+///
+/// * `title` - Heading of the table
+/// * `records` - The releases to print
+/// * `format` - The output format
+fn print_releases(title: &str, records: &[ReleaseRecord], format: OutputFormat) {
+    match output::render(records, format) {
+        Ok(rendered) if format.is_machine_readable() => {
+            if !rendered.is_empty() {
+                println!("{}", rendered);
+            }
+        }
+        Ok(rendered) => println!("{}\n{}\n", output::heading(title, format), rendered),
+        Err(e) => error!("Cannot render releases. Err: {}", e),
+    }
+}
+
+/// Builds release records with the editions of each release grouped into one.
+///
+/// UPCs and explicit flags come from the edition index (filled by
+/// `playlist` and `releases show`); releases without indexed facts are
+/// grouped by title, artists and release date. With `GROUP_EDITIONS=false`
+/// every release gets its own record.
+///
+/// # Arguments
+///
+/// * `albums` - The releases, deduplicated by ID and sorted
+/// * `editions` - The edition index
+/// * `week` - Determines the release week of a record
+/// * `followed` - IDs of the followed artists
+/// * `ep_rule` - Decides which releases count as EPs
+fn edition_records(
+    albums: Vec<Album>,
+    editions: &EditionIndexManager,
+    week: impl Fn(&Album) -> Option<WeekOfTheYear>,
+    followed: &HashSet<String>,
+    ep_rule: &EpRule,
+) -> Vec<ReleaseRecord> {
+    let groups = if config::group_editions_enabled() {
+        utils::group_editions(albums, &editions.upcs(), &editions.explicit_album_ids())
+    } else {
+        albums.into_iter().map(|album| vec![album]).collect()
+    };
+    groups
+        .iter()
+        .map(|editions| {
+            ReleaseRecord::from_editions(editions, week(&editions[0]).as_ref(), followed, ep_rule)
+        })
+        .collect()
+}
+
+/// Determines the release week an album is placed into by `releases update`.
+///
+/// Releases without an exact date only get a week when
+/// `PLACE_APPROXIMATE_RELEASES` is enabled.
+fn release_week_of(album: &Album) -> Option<WeekOfTheYear> {
+    if !album.has_exact_date() && !config::place_approximate_releases() {
+        return None;
     }
+    utils::release_period_start(album)
+        .ok()
+        .map(utils::build_week)
 }
 
 /// Organizes releases by their respective weeks and years for efficient caching.
@@ -472,6 +1523,7 @@ pub async fn list_releases(weeks_include: Option<u32>, release_date: Option<Stri
 /// # Arguments
 ///
 /// * `remote_releases` - Vector of albums from various artists and time periods
+/// * `place_approximate` - Whether month/year precision releases are placed into weeks
 ///
 /// # Returns
 ///
@@ -482,18 +1534,21 @@ pub async fn list_releases(weeks_include: Option<u32>, release_date: Option<Stri
 /// # Processing Logic
 ///
 /// For each release:
-/// 1. **Date Validation**: Ensures release has day-precision date
+/// 1. **Date Validation**: Ensures release has day-precision date unless approximate placement is enabled
 /// 2. **Date Parsing**: Converts string date to structured date object
 /// 3. **Week Calculation**: Determines which release week the date falls into
-/// 4. **Year Extraction**: Gets the year for proper organization
+/// 4. **Year Extraction**: Takes the week's release year, so releases on
+///    January 1st-3rd can belong to the last week of the previous year
 /// 5. **Grouping**: Adds to existing week group or creates new one
 ///
 /// # Date Precision Filtering
 ///
-/// Only processes releases with day-precision dates:
+/// Releases with day-precision dates are always processed:
 /// - Includes: "2023-10-15" (day precision)
-/// - Excludes: "2023-10" (month precision) or "2023" (year precision)
-/// - This ensures accurate week assignment and consistent organization
+/// - Month ("2023-10") and year ("2023") precision releases are only placed
+///   into the week containing the first day of their period when
+///   `place_approximate` is set; otherwise they stay in the artist cache and
+///   are listed by `sporlcli releases undated`
 ///
 /// # Week Assignment Algorithm
 ///
@@ -538,12 +1593,12 @@ pub async fn list_releases(weeks_include: Option<u32>, release_date: Option<Stri
 ///
 /// ```
 /// let releases = vec![/* various albums */];
-/// let organized = prepare_remote_releases(releases).await?;
+/// let organized = prepare_remote_releases(releases, false).await?;
 ///
 /// for week_data in organized {
 ///     println!("Week {} of {}: {} releases",
 ///              week_data.week.week,
-///              week_data.year,
+///              week_data.week.year,
 ///              week_data.releases.len());
 /// }
 /// ```
@@ -562,15 +1617,18 @@ pub async fn list_releases(weeks_include: Option<u32>, release_date: Option<Stri
 /// - Space complexity: O(n) for output organization
 /// - Efficient for typical release volumes
 /// - Scales reasonably with large datasets
-async fn prepare_remote_releases(remote_releases: Vec<Album>) -> Result<Vec<ReleaseWeek>, String> {
+async fn prepare_remote_releases(
+    remote_releases: Vec<Album>,
+    place_approximate: bool,
+) -> Result<Vec<ReleaseWeek>, String> {
     let mut releases_weeks: Vec<ReleaseWeek> = Vec::new();
 
     for album in remote_releases {
-        if album.release_date_precision != "day" {
+        if !album.has_exact_date() && !place_approximate {
             continue;
         }
 
-        let release_date = match NaiveDate::parse_from_str(&album.release_date, "%Y-%m-%d") {
+        let release_date = match utils::release_period_start(&album) {
             Ok(d) => d,
             Err(err) => {
                 warning!(
@@ -583,18 +1641,17 @@ async fn prepare_remote_releases(remote_releases: Vec<Album>) -> Result<Vec<Rele
         };
 
         let release_week_for_album = utils::build_week(release_date);
-        let release_year_for_album = release_date.year();
 
         // Look for an existing ReleaseWeek
         if let Some(week_entry) = releases_weeks.iter_mut().find(|rw| {
-            rw.year == release_year_for_album && rw.week.week == release_week_for_album.week
+            rw.week.year == release_week_for_album.year
+                && rw.week.week == release_week_for_album.week
         }) {
             week_entry.releases.push(album);
         } else {
             // If not found, create a new ReleaseWeek
             releases_weeks.push(ReleaseWeek {
                 week: release_week_for_album,
-                year: release_year_for_album,
                 releases: vec![album],
             });
         }
@@ -0,0 +1,141 @@
+//! Source of the current date for all date and week calculations.
+//!
+//! Release weeks are a local-calendar concept: a release that comes out on
+//! Friday morning in Sydney is already in its release week, even though it is
+//! still Thursday in UTC. The clock therefore resolves "today" in the
+//! configured timezone (`TIMEZONE`, the system timezone by default).
+//!
+//! The clock can be replaced once per process, which is how the global
+//! `--today`/`SPORLCLI_TODAY` override pins every command to a fixed date.
+//! Code that needs a specific clock (e.g. tests) can also create a [`Clock`]
+//! and pass its [`Clock::today`] to the date-taking functions in `utils`.
+//!
+//! # Example
+//!
+//! ```
+//! use sporlcli::clock::{self, Clock};
+//!
+//! clock::install(Clock::fixed(NaiveDate::from_ymd_opt(2025, 8, 8).unwrap()));
+//! assert_eq!(clock::today(), NaiveDate::from_ymd_opt(2025, 8, 8).unwrap());
+//! ```
+
+use std::sync::OnceLock;
+
+use chrono::{Local, NaiveDate, Utc};
+use chrono_tz::Tz;
+
+use crate::config;
+
+/// The process-wide clock, installed at most once.
+static CLOCK: OnceLock<Clock> = OnceLock::new();
+
+/// Timezone used to determine the current calendar date.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DateTimezone {
+    /// The system's local timezone
+    Local,
+    /// A named IANA timezone such as `Australia/Sydney` or `UTC`
+    Named(Tz),
+}
+
+impl DateTimezone {
+    /// Parses an IANA timezone name, or `local` for the system timezone.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Timezone name, e.g. `Europe/Berlin`, `UTC` or `local`
+    ///
+    /// # Returns
+    ///
+    /// A `Result<DateTimezone, String>` with the timezone or an error message
+    /// naming the unknown input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tz = DateTimezone::parse("Australia/Sydney")?;
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let trimmed = input.trim();
+        if trimmed.eq_ignore_ascii_case("local") {
+            return Ok(DateTimezone::Local);
+        }
+        trimmed.parse::<Tz>().map(DateTimezone::Named).map_err(|_| {
+            format!(
+                "Unknown timezone '{input}'. Expected an IANA name like Europe/Berlin or 'local'."
+            )
+        })
+    }
+
+    /// Returns the calendar date of the given instant in this timezone.
+    pub fn date_of(&self, instant: chrono::DateTime<Utc>) -> NaiveDate {
+        match self {
+            DateTimezone::Local => instant.with_timezone(&Local).date_naive(),
+            DateTimezone::Named(tz) => instant.with_timezone(tz).date_naive(),
+        }
+    }
+}
+
+/// Provides the current date for week selection and date defaults.
+///
+/// # Variants
+///
+/// * `System` - The real current date in the given timezone
+/// * `Fixed` - Always the given date, used for `--today` and tests
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Clock {
+    System(DateTimezone),
+    Fixed(NaiveDate),
+}
+
+impl Clock {
+    /// Creates a clock following the system time in the configured timezone.
+    pub fn system() -> Self {
+        Clock::System(config::timezone())
+    }
+
+    /// Creates a clock that always reports the given date.
+    pub fn fixed(today: NaiveDate) -> Self {
+        Clock::Fixed(today)
+    }
+
+    /// Returns the current date according to this clock.
+    pub fn today(&self) -> NaiveDate {
+        match self {
+            Clock::System(timezone) => timezone.date_of(Utc::now()),
+            Clock::Fixed(today) => *today,
+        }
+    }
+}
+
+/// Installs the process-wide clock.
+///
+/// Must be called before the clock is first used; later calls are ignored
+/// and return `false`.
+///
+/// # Arguments
+///
+/// * `clock` - The clock to use for the rest of the process
+///
+/// # Returns
+///
+/// `true` if the clock was installed.
+pub fn install(clock: Clock) -> bool {
+    CLOCK.set(clock).is_ok()
+}
+
+/// Returns the process-wide clock, the system clock unless another was installed.
+pub fn get() -> &'static Clock {
+    CLOCK.get_or_init(Clock::system)
+}
+
+/// Returns today's date according to the process-wide clock.
+///
+/// # Example
+///
+/// ```
+/// let week = utils::build_week(clock::today());
+/// ```
+pub fn today() -> NaiveDate {
+    get().today()
+}
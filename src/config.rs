@@ -10,9 +10,17 @@
 //! 2. `.env` file in the local data directory
 //! 3. Application defaults (where applicable)
 
+use clap::ValueEnum;
 use dotenv;
 use std::{env, path::PathBuf};
 
+use crate::{
+    clock::DateTimezone,
+    error,
+    logging::DEFAULT_LOG_FILE_MAX_SIZE,
+    utils::{self, EpRule, TitleFilter, TitlePattern, WeekScheme},
+};
+
 /// Loads environment variables from a `.env` file in the local data directory.
 ///
 /// Creates the necessary directory structure if it doesn't exist and loads
@@ -220,6 +228,230 @@ pub fn spotify_apiurl() -> String {
     env::var("SPOTIFY_API_URL").expect("SPOTIFY_API_URL must be set")
 }
 
+/// Returns whether releases without an exact release date are placed into weeks.
+///
+/// Reads the optional `PLACE_APPROXIMATE_RELEASES` environment variable. When
+/// set to `true`, releases that Spotify only dates by month or year are put
+/// into the release week containing the first day of that month or year.
+/// Otherwise they are only listed by `sporlcli releases undated`.
+///
+/// # Example
+///
+/// ```
+/// if place_approximate_releases() {
+///     // 2024-10 is placed into the week containing 2024-10-01
+/// }
+/// ```
+pub fn place_approximate_releases() -> bool {
+    env::var("PLACE_APPROXIMATE_RELEASES")
+        .map(|v| matches!(v.trim().to_lowercase().as_str(), "1" | "true" | "yes"))
+        .unwrap_or(false)
+}
+
+/// Returns the week scheme releases are organized by.
+///
+/// Reads the optional `WEEK_SCHEME` environment variable. Supported values are
+/// `sat-fri` (Saturday to Friday, the default), `fri-thu` (Friday to Thursday)
+/// and `iso` (ISO-8601 weeks from Monday to Sunday). Terminates with an error
+/// message if the value is not one of those.
+///
+/// # Example
+///
+/// ```
+/// let week = week_scheme().build_week(NaiveDate::from_ymd_opt(2025, 8, 6).unwrap());
+/// ```
+pub fn week_scheme() -> WeekScheme {
+    match env::var("WEEK_SCHEME") {
+        Ok(value) if !value.trim().is_empty() => WeekScheme::from_str(value.trim(), true)
+            .unwrap_or_else(|_| {
+                error!(
+                    "Invalid WEEK_SCHEME '{}'. Expected sat-fri, fri-thu or iso.",
+                    value
+                )
+            }),
+        _ => WeekScheme::default(),
+    }
+}
+
+/// Returns the timezone used to determine the current date.
+///
+/// Reads the optional `TIMEZONE` environment variable, an IANA timezone name
+/// such as `Australia/Sydney` or `UTC`. Without it the system timezone is
+/// used, so "today" and the current release week match the local calendar.
+/// Terminates with an error message if the timezone is unknown.
+///
+/// # Example
+///
+/// ```
+/// let today = timezone().date_of(Utc::now());
+/// ```
+pub fn timezone() -> DateTimezone {
+    match env::var("TIMEZONE") {
+        Ok(value) if !value.trim().is_empty() => {
+            DateTimezone::parse(&value).unwrap_or_else(|e| error!("Invalid TIMEZONE. {}", e))
+        }
+        _ => DateTimezone::Local,
+    }
+}
+
+/// Returns the thresholds that turn a Spotify single into an EP.
+///
+/// Reads the optional `EP_MIN_TRACKS`, `EP_MAX_TRACKS` and
+/// `EP_MAX_DURATION` (minutes) environment variables. Missing values fall
+/// back to [`EpRule::default`] (4 to 6 tracks, at most 30 minutes).
+/// Terminates with an error message if a value is not a number or the
+/// track range is empty.
+///
+/// # Example
+///
+/// ```
+/// // EP_MIN_TRACKS=3
+/// assert_eq!(ep_rule().min_tracks, 3);
+/// ```
+pub fn ep_rule() -> EpRule {
+    let number = |name: &str, default: u64| -> u64 {
+        match env::var(name) {
+            Ok(value) if !value.trim().is_empty() => value
+                .trim()
+                .parse::<u64>()
+                .unwrap_or_else(|_| error!("Invalid {} '{}'. Expected a number.", name, value)),
+            _ => default,
+        }
+    };
+
+    let default = EpRule::default();
+    let rule = EpRule {
+        min_tracks: number("EP_MIN_TRACKS", default.min_tracks.into()) as u32,
+        max_tracks: number("EP_MAX_TRACKS", default.max_tracks.into()) as u32,
+        max_duration_ms: number("EP_MAX_DURATION", default.max_duration_ms / 60_000) * 60_000,
+    };
+    if rule.min_tracks > rule.max_tracks {
+        error!(
+            "Invalid EP_MIN_TRACKS/EP_MAX_TRACKS. {} is greater than {}.",
+            rule.min_tracks, rule.max_tracks
+        );
+    }
+    rule
+}
+
+/// Returns the title patterns that hide releases and tracks.
+///
+/// Reads the optional `TITLE_INCLUDE` and `TITLE_EXCLUDE` environment
+/// variables, each a `;`-separated list of patterns (see
+/// [`utils::parse_title_pattern`]). Terminates with an error message if a
+/// pattern is invalid.
+///
+/// # Example
+///
+/// ```
+/// // TITLE_EXCLUDE="*(Live)*;remaster;/\b(sped up|slowed)\b/"
+/// assert_eq!(title_filter().exclude.len(), 3);
+/// ```
+pub fn title_filter() -> TitleFilter {
+    let patterns = |name: &str| -> Vec<TitlePattern> {
+        env::var(name)
+            .unwrap_or_default()
+            .split(';')
+            .filter(|pattern| !pattern.trim().is_empty())
+            .map(|pattern| {
+                utils::parse_title_pattern(pattern)
+                    .unwrap_or_else(|e| error!("Invalid {}: {}", name, e))
+            })
+            .collect()
+    };
+
+    TitleFilter {
+        include: patterns("TITLE_INCLUDE"),
+        exclude: patterns("TITLE_EXCLUDE"),
+    }
+}
+
+/// Returns whether editions of the same release are grouped.
+///
+/// Reads the optional `GROUP_EDITIONS` environment variable. Grouping is on
+/// unless it is set to `false`; then explicit and clean versions, deluxe
+/// editions and regional duplicates are listed and added to playlists
+/// separately (see [`utils::group_editions`]).
+///
+/// # Example
+///
+/// ```
+/// if group_editions_enabled() {
+///     groups = utils::group_editions(releases, &upcs, &explicit);
+/// }
+/// ```
+pub fn group_editions_enabled() -> bool {
+    env::var("GROUP_EDITIONS")
+        .map(|v| !matches!(v.trim().to_lowercase().as_str(), "0" | "false" | "no"))
+        .unwrap_or(true)
+}
+
+/// Returns the date that overrides "today" for all commands, if set.
+///
+/// Reads the optional `SPORLCLI_TODAY` environment variable (`YYYY-MM-DD`).
+/// The global `--today` argument takes precedence over it.
+///
+/// # Example
+///
+/// ```
+/// // SPORLCLI_TODAY=2025-08-08
+/// assert_eq!(today_override(), Some("2025-08-08".to_string()));
+/// ```
+pub fn today_override() -> Option<String> {
+    env::var("SPORLCLI_TODAY")
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+}
+
+/// Returns whether sporlcli must not contact Spotify.
+///
+/// Reads the optional `SPORLCLI_OFFLINE` environment variable. When set to
+/// `true`, every command behaves as if `--offline` was given.
+///
+/// # Example
+///
+/// ```
+/// if cli.offline || offline_enabled() {
+///     spotify::set_offline(true);
+/// }
+/// ```
+pub fn offline_enabled() -> bool {
+    env::var("SPORLCLI_OFFLINE")
+        .map(|v| matches!(v.trim().to_lowercase().as_str(), "1" | "true" | "yes"))
+        .unwrap_or(false)
+}
+
+/// Returns whether status messages are also written to the log file.
+///
+/// Reads the optional `LOG_FILE` environment variable. When set to `true`,
+/// messages are appended to `sporlcli/logs/sporlcli.log` in the local data
+/// directory (see the `logging` module).
+///
+/// # Example
+///
+/// ```
+/// if log_file_enabled() {
+///     logging::enable_log_file(log_file_max_size())?;
+/// }
+/// ```
+pub fn log_file_enabled() -> bool {
+    env::var("LOG_FILE")
+        .map(|v| matches!(v.trim().to_lowercase().as_str(), "1" | "true" | "yes"))
+        .unwrap_or(false)
+}
+
+/// Returns the size in bytes after which the log file is rotated.
+///
+/// Reads the optional `LOG_FILE_MAX_SIZE` environment variable and falls
+/// back to 1 MiB if it is missing or not a positive number.
+pub fn log_file_max_size() -> u64 {
+    env::var("LOG_FILE_MAX_SIZE")
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .filter(|size| *size > 0)
+        .unwrap_or(DEFAULT_LOG_FILE_MAX_SIZE)
+}
+
 /// Returns the Spotify OAuth token exchange URL.
 ///
 /// Retrieves the `SPOTIFY_API_TOKEN_URL` environment variable which contains
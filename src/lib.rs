@@ -8,9 +8,14 @@
 //!
 //! - `api` - HTTP API endpoints for the local callback server
 //! - `cli` - Command-line interface implementations
+//! - `clock` - Source of the current date (timezone and `--today` override)
 //! - `config` - Configuration management and environment variables
+//! - `logging` - Status messages on stderr, verbosity levels and the log file
 //! - `management` - High-level data management and caching
+//! - `output` - Table and machine-readable output formats
+//! - `progress` - Progress spinner, plain status lines and JSON progress events
 //! - `server` - Local HTTP server for OAuth callbacks
+//! - `shutdown` - Graceful Ctrl-C handling for long-running commands
 //! - `spotify` - Spotify Web API client implementation
 //! - `types` - Data structures and type definitions
 //! - `utils` - Utility functions and helpers
@@ -30,9 +35,14 @@
 
 pub mod api;
 pub mod cli;
+pub mod clock;
 pub mod config;
+pub mod logging;
 pub mod management;
+pub mod output;
+pub mod progress;
 pub mod server;
+pub mod shutdown;
 pub mod spotify;
 pub mod types;
 pub mod utils;
@@ -58,7 +68,7 @@ pub mod utils;
 /// ```
 pub type Res<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
 
-/// Prints an informational message with a blue bullet point.
+/// Logs an informational message with a blue bullet point to stderr.
 ///
 /// Creates a formatted output line with a distinctive blue "o" indicator
 /// followed by the provided message. Used for general information and
@@ -78,12 +88,11 @@ pub type Res<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync
 #[macro_export]
 macro_rules! info {
   ($($arg:tt)*) => ({
-    use colored::Colorize;
-    println!("[{}] {}", "o".blue().bold(), std::format_args!($($arg)*));
+    $crate::logging::log($crate::logging::Kind::Info, std::format_args!($($arg)*));
   })
 }
 
-/// Prints a success message with a green checkmark.
+/// Logs a success message with a green checkmark to stderr.
 ///
 /// Creates a formatted output line with a green "✓" indicator to signify
 /// successful completion of operations. Used to provide positive feedback
@@ -103,12 +112,11 @@ macro_rules! info {
 #[macro_export]
 macro_rules! success {
   ($($arg:tt)*) => ({
-    use colored::Colorize;
-    println!("[{}] {}", "✓".green().bold(), std::format_args!($($arg)*));
+    $crate::logging::log($crate::logging::Kind::Success, std::format_args!($($arg)*));
   })
 }
 
-/// Prints an error message with a red exclamation mark and exits the program.
+/// Logs an error message with a red exclamation mark to stderr and exits the program.
 ///
 /// Creates a formatted error output with a red "!" indicator and immediately
 /// terminates the program with exit code 1. Used for unrecoverable errors
@@ -135,13 +143,12 @@ macro_rules! success {
 #[macro_export]
 macro_rules! error {
   ($($arg:tt)*) => ({
-    use colored::Colorize;
-    println!("[{}] {}", "!".red().bold(), std::format_args!($($arg)*));
+    $crate::logging::log($crate::logging::Kind::Error, std::format_args!($($arg)*));
     std::process::exit(1);
   })
 }
 
-/// Prints a warning message with a yellow exclamation mark.
+/// Logs a warning message with a yellow exclamation mark to stderr.
 ///
 /// Creates a formatted output line with a yellow "!" indicator to highlight
 /// potential issues or important notices that don't require program termination.
@@ -161,7 +168,47 @@ macro_rules! error {
 #[macro_export]
 macro_rules! warning {
   ($($arg:tt)*) => ({
-    use colored::Colorize;
-    println!("[{}] {}", "!".yellow().bold(), std::format_args!($($arg)*));
+    $crate::logging::log($crate::logging::Kind::Warning, std::format_args!($($arg)*));
+  })
+}
+
+/// Logs a debug message, shown with `-v`.
+///
+/// Used for diagnostic details that are too noisy for regular runs, such as
+/// summaries of every HTTP request sent to Spotify.
+///
+/// # Arguments
+///
+/// The macro accepts the same arguments as `println!`, supporting format
+/// strings and interpolation.
+///
+/// # Example
+///
+/// ```
+/// debug!("GET /v1/me/following -> 200 OK in {} ms", elapsed);
+/// ```
+#[macro_export]
+macro_rules! debug {
+  ($($arg:tt)*) => ({
+    $crate::logging::log($crate::logging::Kind::Debug, std::format_args!($($arg)*));
+  })
+}
+
+/// Logs a trace message, shown with `-vv`.
+///
+/// # Arguments
+///
+/// The macro accepts the same arguments as `println!`, supporting format
+/// strings and interpolation.
+///
+/// # Example
+///
+/// ```
+/// trace!("Sending GET {}", url);
+/// ```
+#[macro_export]
+macro_rules! trace {
+  ($($arg:tt)*) => ({
+    $crate::logging::log($crate::logging::Kind::Trace, std::format_args!($($arg)*));
   })
 }
@@ -0,0 +1,319 @@
+//! Status messages and the optional log file.
+//!
+//! All status output of sporlcli (the `info!`, `success!`, `warning!`,
+//! `error!`, `debug!` and `trace!` macros) goes through this module. Messages
+//! are written to stderr, so stdout only carries the actual command output
+//! (tables, JSON, CSV) and can be redirected safely.
+//!
+//! # Levels
+//!
+//! | Flag    | Shown on stderr                          |
+//! |---------|------------------------------------------|
+//! | `-q`    | Warnings and errors                      |
+//! | default | Info, success, warnings and errors       |
+//! | `-v`    | Additionally debug messages (HTTP calls) |
+//! | `-vv`   | Additionally trace messages              |
+//!
+//! # Log File
+//!
+//! With `LOG_FILE=true` every message at the console level (but at least
+//! info level) is also appended to `{local_data_dir}/sporlcli/logs/sporlcli.log`.
+//! The file is rotated once it exceeds `LOG_FILE_MAX_SIZE` bytes (default
+//! 1 MiB); the previous files are kept as `sporlcli.log.1` to
+//! `sporlcli.log.3`.
+
+use std::{
+    fmt,
+    fs::{self, File, OpenOptions},
+    io::Write,
+    path::PathBuf,
+    sync::{
+        Mutex, OnceLock,
+        atomic::{AtomicU8, Ordering},
+    },
+};
+
+use chrono::Utc;
+use colored::Colorize;
+use serde::Serialize;
+
+use crate::progress::{self, Event, ProgressMode};
+
+/// Number of rotated log files kept next to the current one.
+const ROTATED_LOG_FILES: usize = 3;
+
+/// Default maximum size of the log file before it is rotated.
+pub const DEFAULT_LOG_FILE_MAX_SIZE: u64 = 1024 * 1024;
+
+/// Level shown on the console, stored as `Level as u8`.
+static CONSOLE_LEVEL: AtomicU8 = AtomicU8::new(Level::Info as u8);
+
+/// The log file, if enabled.
+static LOG_FILE: OnceLock<Mutex<LogFile>> = OnceLock::new();
+
+/// Severity of a status message, ordered from most to least important.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Fatal errors
+    Error = 0,
+    /// Recoverable problems
+    Warning = 1,
+    /// Regular progress and result messages
+    Info = 2,
+    /// Diagnostic details such as HTTP request summaries
+    Debug = 3,
+    /// Very detailed diagnostics
+    Trace = 4,
+}
+
+impl Level {
+    /// Determines the console level from the `-q` and `-v` flags.
+    ///
+    /// # Arguments
+    ///
+    /// * `quiet` - Whether `-q`/`--quiet` was given
+    /// * `verbose` - How often `-v`/`--verbose` was given
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(Level::from_flags(false, 0), Level::Info);
+    /// assert_eq!(Level::from_flags(false, 2), Level::Trace);
+    /// assert_eq!(Level::from_flags(true, 0), Level::Warning);
+    /// ```
+    pub fn from_flags(quiet: bool, verbose: u8) -> Self {
+        if quiet {
+            return Level::Warning;
+        }
+        match verbose {
+            0 => Level::Info,
+            1 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+
+    /// Converts the stored numeric representation back to a level.
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Level::Error,
+            1 => Level::Warning,
+            2 => Level::Info,
+            3 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    /// Formats the level as used in the log file (`ERROR`, `WARN`, ...).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warning => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        f.write_str(name)
+    }
+}
+
+/// The kind of a status message, which determines its marker and level.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Kind {
+    /// `[o]` in blue
+    Info,
+    /// `[✓]` in green
+    Success,
+    /// `[!]` in yellow
+    Warning,
+    /// `[!]` in red
+    Error,
+    /// `[-]` dimmed
+    Debug,
+    /// `[.]` dimmed
+    Trace,
+}
+
+impl Kind {
+    /// Returns the level messages of this kind are logged at.
+    pub fn level(&self) -> Level {
+        match self {
+            Kind::Info | Kind::Success => Level::Info,
+            Kind::Warning => Level::Warning,
+            Kind::Error => Level::Error,
+            Kind::Debug => Level::Debug,
+            Kind::Trace => Level::Trace,
+        }
+    }
+}
+
+/// Sets the level of messages shown on the console.
+pub fn set_level(level: Level) {
+    CONSOLE_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Returns the level of messages shown on the console.
+pub fn level() -> Level {
+    Level::from_u8(CONSOLE_LEVEL.load(Ordering::Relaxed))
+}
+
+/// Returns `true` if messages of the given level are shown or written anywhere.
+///
+/// Useful to skip building expensive debug messages.
+pub fn enabled(level: Level) -> bool {
+    level <= self::level() || (LOG_FILE.get().is_some() && level <= file_level())
+}
+
+/// Enables the rotating log file in the local data directory.
+///
+/// # Arguments
+///
+/// * `max_size` - Size in bytes after which the log file is rotated
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success or an error message if the log directory or
+/// file cannot be created. Calling it again has no effect.
+pub fn enable_log_file(max_size: u64) -> Result<(), String> {
+    if LOG_FILE.get().is_some() {
+        return Ok(());
+    }
+
+    let path = log_file_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let file = LogFile::open(path, max_size)?;
+    let _ = LOG_FILE.set(Mutex::new(file));
+    Ok(())
+}
+
+/// Formats a status line with the marker of the given kind.
+///
+/// Used by the logging macros and by commands whose result is a status-like
+/// line that belongs on stdout (e.g. `sporlcli info`).
+///
+/// # Example
+///
+/// ```
+/// println!("{}", format_line(Kind::Info, format_args!("Current release week: 32/2025")));
+/// ```
+pub fn format_line(kind: Kind, args: fmt::Arguments<'_>) -> String {
+    let marker = match kind {
+        Kind::Info => "o".blue().bold(),
+        Kind::Success => "✓".green().bold(),
+        Kind::Warning => "!".yellow().bold(),
+        Kind::Error => "!".red().bold(),
+        Kind::Debug => "-".dimmed(),
+        Kind::Trace => ".".dimmed(),
+    };
+    format!("[{}] {}", marker, args)
+}
+
+/// Writes a status message to stderr and the log file, depending on the levels.
+///
+/// This is the backend of the logging macros; use those instead of calling
+/// it directly. With `--progress json` console messages are emitted as
+/// `message` events so stderr only carries JSON lines.
+pub fn log(kind: Kind, args: fmt::Arguments<'_>) {
+    let level = kind.level();
+
+    if level <= self::level() {
+        if progress::mode() == ProgressMode::Json {
+            progress::emit(Event::Message {
+                kind,
+                message: args.to_string(),
+            });
+        } else {
+            eprintln!("{}", format_line(kind, args));
+        }
+    }
+
+    if let Some(file) = LOG_FILE.get()
+        && level <= file_level()
+        && let Ok(mut file) = file.lock()
+    {
+        file.write_line(level, &args.to_string());
+    }
+}
+
+/// Returns the level of messages written to the log file.
+///
+/// The log file follows the console level but never drops below info, so
+/// `-q` keeps the console quiet without emptying the log.
+fn file_level() -> Level {
+    level().max(Level::Info)
+}
+
+/// Returns the path of the current log file.
+fn log_file_path() -> PathBuf {
+    let mut path = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+    path.push("sporlcli/logs/sporlcli.log");
+    path
+}
+
+/// An append-only log file that rotates itself when it grows too large.
+struct LogFile {
+    /// Path of the current log file
+    path: PathBuf,
+    /// Open handle of the current log file
+    file: File,
+    /// Current size of the log file in bytes
+    size: u64,
+    /// Size after which the file is rotated
+    max_size: u64,
+}
+
+impl LogFile {
+    /// Opens (or creates) the log file for appending.
+    fn open(path: PathBuf, max_size: u64) -> Result<Self, String> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| e.to_string())?;
+        let size = file.metadata().map(|m| m.len()).unwrap_or(0);
+        Ok(Self {
+            path,
+            file,
+            size,
+            max_size,
+        })
+    }
+
+    /// Appends one line, rotating the file first if it is full.
+    ///
+    /// Write failures are ignored: logging must never abort a command.
+    fn write_line(&mut self, level: Level, message: &str) {
+        if self.size >= self.max_size {
+            self.rotate();
+        }
+
+        let line = format!(
+            "{} {:<5} {}\n",
+            Utc::now().format("%Y-%m-%dT%H:%M:%SZ"),
+            level,
+            message
+        );
+        if self.file.write_all(line.as_bytes()).is_ok() {
+            self.size += line.len() as u64;
+        }
+    }
+
+    /// Shifts `sporlcli.log.N` to `.N+1`, dropping the oldest file, and starts a new log.
+    fn rotate(&mut self) {
+        let rotated = |n: usize| PathBuf::from(format!("{}.{}", self.path.display(), n));
+
+        let _ = fs::remove_file(rotated(ROTATED_LOG_FILES));
+        for n in (1..ROTATED_LOG_FILES).rev() {
+            let _ = fs::rename(rotated(n), rotated(n + 1));
+        }
+        let _ = fs::rename(&self.path, rotated(1));
+
+        if let Ok(reopened) = Self::open(self.path.clone(), self.max_size) {
+            *self = reopened;
+        }
+    }
+}
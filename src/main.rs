@@ -9,7 +9,16 @@ use clap::{
 };
 use clap_complete::{Shell, generate};
 
-use sporlcli::{cli, config, error, types::PkceToken, utils};
+use sporlcli::{
+    cli,
+    clock::{self, Clock},
+    config, error,
+    logging::{self, Level},
+    progress::{self, ProgressMode},
+    spotify,
+    types::{ArtistFilter, GenreFilter, OutputFormat, PkceToken, ReleaseTypeFilter, WeekSelection},
+    utils, warning,
+};
 use tokio::sync::Mutex;
 
 /// Creates custom styling for the CLI interface.
@@ -62,6 +71,26 @@ fn styles() -> Styles {
   styles=styles(),
 )]
 struct Cli {
+    /// Only show warnings and errors
+    #[clap(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+
+    /// Show debug messages such as HTTP request summaries (-vv for trace messages)
+    #[clap(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+
+    /// Treat this date (YYYY-MM-DD) as today for all date calculations [env: SPORLCLI_TODAY]
+    #[clap(long, global = true)]
+    today: Option<String>,
+
+    /// How to report progress: spinner, plain status lines or JSON events
+    #[clap(long, value_enum, default_value_t = ProgressMode::Auto, global = true)]
+    progress: ProgressMode,
+
+    /// Never contact Spotify; only use the local cache [env: SPORLCLI_OFFLINE]
+    #[clap(long, global = true)]
+    offline: bool,
+
     /// The subcommand to execute
     #[clap(subcommand)]
     command: Command,
@@ -90,6 +119,9 @@ pub enum Command {
     /// Some helper information about releases and artists
     Info(InfoOptions),
 
+    /// List the genres of the followed artists
+    Genres(GenresOptions),
+
     /// Get shell completions
     Completions(CompletionsOption),
 }
@@ -104,6 +136,7 @@ pub enum Command {
 ///
 /// - `sporlcli artists --search "artist name"` - Search for artists
 /// - `sporlcli artists update` - Update artist data
+/// - `sporlcli artists refresh` - Refresh artist metadata
 #[derive(Parser, Debug, Clone)]
 #[command(
     about = "Handle followed artists",
@@ -114,6 +147,10 @@ pub struct ArtistsOptions {
     #[clap(long)]
     pub search: Option<String>,
 
+    /// Output format of the artist list
+    #[clap(long, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+
     /// Subcommands for artist management operations
     #[command(subcommand)]
     pub command: Option<ArtistsSubcommand>,
@@ -121,23 +158,82 @@ pub struct ArtistsOptions {
 
 /// Subcommands available under the artists command.
 ///
-/// Defines the specific operations that can be performed on artist data,
-/// currently supporting update operations with various configuration options.
+/// Defines the specific operations that can be performed on artist data:
+/// syncing the followed artists and refreshing their metadata.
 #[derive(Subcommand, Debug, Clone)]
 pub enum ArtistsSubcommand {
     /// Update artists data from Spotify
     Update(ArtistsUpdateOpts),
+
+    /// Refresh genres, popularity, followers and images of cached artists
+    Refresh,
+
+    /// Hide all or some release types of an artist from listings and playlists
+    Mute(ArtistsMuteOpts),
+
+    /// Only show the given release types of an artist
+    Allow(ArtistsAllowOpts),
+
+    /// Remove the mute or allow rule of an artist
+    Unmute(ArtistsUnmuteOpts),
+
+    /// List the mute and allow rules of all artists
+    Rules,
+}
+
+/// Options for muting an artist.
+#[derive(Parser, Debug, Clone)]
+pub struct ArtistsMuteOpts {
+    /// Spotify ID or name of the artist
+    pub artist: String,
+
+    /// Only mute these release types (can be repeated); mutes all releases if omitted
+    #[clap(
+        long = "type",
+        value_parser = utils::parse_release_kinds,
+        action = ArgAction::Append,
+        num_args = 1
+    )]
+    pub types: Vec<utils::ReleaseKinds>,
+}
+
+/// Options for showing only some release types of an artist.
+#[derive(Parser, Debug, Clone)]
+pub struct ArtistsAllowOpts {
+    /// Spotify ID or name of the artist
+    pub artist: String,
+
+    /// Release types to keep showing (can be repeated)
+    #[clap(
+        long = "type",
+        value_parser = utils::parse_release_kinds,
+        action = ArgAction::Append,
+        num_args = 1,
+        required = true
+    )]
+    pub types: Vec<utils::ReleaseKinds>,
+}
+
+/// Options for removing the rule of an artist.
+#[derive(Parser, Debug, Clone)]
+pub struct ArtistsUnmuteOpts {
+    /// Spotify ID or name of the artist
+    pub artist: String,
 }
 
 /// Options for updating artist information.
 ///
-/// Controls how the artist update process behaves, including whether to
-/// bypass caching mechanisms and update guards for forced refreshes.
+/// Controls how the artist sync behaves, including whether to discard cached
+/// releases and what happens to artists that are no longer followed.
 #[derive(Parser, Debug, Clone)]
 pub struct ArtistsUpdateOpts {
-    /// Force update, bypassing caches and update guards
+    /// Force update, discarding the cached releases of followed artists
     #[clap(long)]
     pub force: bool,
+
+    /// Remove unfollowed artists and their cached releases instead of archiving them
+    #[clap(long)]
+    pub prune: bool,
 }
 
 /// Configuration options for release-related commands.
@@ -150,20 +246,39 @@ pub struct ArtistsUpdateOpts {
 ///
 /// - `sporlcli releases --previous-weeks 4` - Show releases from last 4 weeks
 /// - `sporlcli releases --release-date 2023-10-17` - Show releases for specific date
+/// - `sporlcli releases --week 2025/32` - Show releases of a specific week
+/// - `sporlcli releases --from 2025-07-01 --to 2025-07-31` - Show releases of all weeks in a range
 /// - `sporlcli releases update` - Update release data
+/// - `sporlcli releases new` - Show releases found since the previous update
 #[derive(Parser, Debug, Clone)]
 #[command(
     about = "Handle releases",
     args_conflicts_with_subcommands = true // disallow mixing query flags with `update`
 )]
 pub struct ReleasesOptions {
-    /// Number of previous weeks to include in the query
-    #[clap(long)]
-    pub previous_weeks: Option<u32>,
+    /// Release weeks to list
+    #[command(flatten)]
+    pub weeks: WeekSelection,
 
-    /// Filter releases by a specific date (YYYY-MM-DD format)
+    /// Release types to list
+    #[command(flatten)]
+    pub types: ReleaseTypeFilter,
+
+    /// Artist genres to list
+    #[command(flatten)]
+    pub genres: GenreFilter,
+
+    /// Credited artists to list
+    #[command(flatten)]
+    pub artists: ArtistFilter,
+
+    /// Also list releases hidden by title patterns or artist rules, with the reason
     #[clap(long)]
-    pub release_date: Option<String>,
+    pub show_hidden: bool,
+
+    /// Output format of release listings (also applies to `new` and `undated`)
+    #[clap(long, value_enum, default_value_t = OutputFormat::Table, global = true)]
+    pub format: OutputFormat,
 
     /// Subcommands for release management operations
     #[command(subcommand)]
@@ -172,12 +287,25 @@ pub struct ReleasesOptions {
 
 /// Subcommands available under the releases command.
 ///
-/// Defines the specific operations that can be performed on release data,
-/// currently supporting update operations with configurable release type filtering.
+/// Defines the specific operations that can be performed on release data:
+/// updating the cache with configurable release type filtering and listing
+/// newly discovered or undated releases.
 #[derive(Subcommand, Debug, Clone)]
 pub enum ReleasesSubcommand {
     /// Update releases data from Spotify
     Update(ReleasesUpdateOpts),
+
+    /// List releases discovered since the previous successful update
+    New(ReleasesNewOpts),
+
+    /// List releases that only have a month or year as release date
+    Undated,
+
+    /// Rebuild the weekly release cache for the configured week scheme
+    Reindex,
+
+    /// Show the details and tracklist of a release
+    Show(ReleasesShowOpts),
 }
 
 /// Options for updating release information.
@@ -191,6 +319,18 @@ pub struct ReleasesUpdateOpts {
     #[clap(long)]
     pub force: bool,
 
+    /// Resume an interrupted update with its original parameters
+    #[clap(long, conflicts_with = "restart")]
+    pub resume: bool,
+
+    /// Discard an interrupted update and start a new one
+    #[clap(long)]
+    pub restart: bool,
+
+    /// Only fetch the artists that failed in earlier updates
+    #[clap(long, conflicts_with_all = ["force", "resume", "restart"])]
+    pub retry_failed: bool,
+
     /// Release type(s) to include during update (can be repeated)
     ///
     /// Accepts values like "album", "single", "compilation", "appears_on", or "all".
@@ -205,6 +345,31 @@ pub struct ReleasesUpdateOpts {
     pub release_types: utils::ReleaseKinds,
 }
 
+/// Options for listing newly discovered releases.
+///
+/// By default everything found since the previous successful update is
+/// shown; `--since` overrides that point in time.
+#[derive(Parser, Debug, Clone)]
+pub struct ReleasesNewOpts {
+    /// Show releases first seen after this point in time (Unix seconds, YYYY-MM-DD or RFC 3339)
+    #[clap(long)]
+    pub since: Option<String>,
+}
+
+/// Options for showing a single release.
+///
+/// The release is given by album ID, Spotify URI or URL, or a search term
+/// matched against the names of cached releases.
+#[derive(Parser, Debug, Clone)]
+pub struct ReleasesShowOpts {
+    /// Album ID, spotify:album: URI, open.spotify.com URL or part of a cached release name
+    pub query: String,
+
+    /// Fetch the release details again even if they are cached
+    #[clap(long)]
+    pub refresh: bool,
+}
+
 /// Options for playlist creation commands.
 ///
 /// Configures the time range and filtering criteria for creating playlists
@@ -215,15 +380,28 @@ pub struct ReleasesUpdateOpts {
 ///
 /// - `sporlcli playlist --previous-weeks 2` - Create playlist for last 2 weeks
 /// - `sporlcli playlist --release-date 2023-10-17` - Create playlist for specific date
+/// - `sporlcli playlist --week 2025-W32` - Create playlist for a specific week
 #[derive(Parser, Debug, Clone)]
 pub struct PlaylistOptions {
-    /// Number of previous weeks to include in the playlist
-    #[clap(long)]
-    previous_weeks: Option<u32>,
+    /// Release weeks to create playlists for
+    #[command(flatten)]
+    weeks: WeekSelection,
+
+    /// Release types to add to the playlists
+    #[command(flatten)]
+    types: ReleaseTypeFilter,
+
+    /// Artist genres to add to the playlists
+    #[command(flatten)]
+    genres: GenreFilter,
+
+    /// Credited artists to add to the playlists
+    #[command(flatten)]
+    artists: ArtistFilter,
 
-    /// Target a specific release date for the playlist (YYYY-MM-DD format)
+    /// Report releases and tracks left out by title patterns or artist rules
     #[clap(long)]
-    release_date: Option<String>,
+    show_hidden: bool,
 }
 
 /// Options for information and statistics commands.
@@ -236,6 +414,7 @@ pub struct PlaylistOptions {
 ///
 /// - `sporlcli info --release-week --previous-weeks 1` - Show current week info
 /// - `sporlcli info --artists` - Show artist statistics
+/// - `sporlcli info --week 2025/32` - Show the dates of a specific week
 #[derive(Parser, Debug, Clone)]
 pub struct InfoOptions {
     /// Display information about the current release week
@@ -246,13 +425,26 @@ pub struct InfoOptions {
     #[clap(long)]
     artists: bool,
 
-    /// Number of previous weeks to include in the information display
-    #[clap(long)]
-    previous_weeks: Option<u32>,
+    /// Release weeks to display information about
+    #[command(flatten)]
+    weeks: WeekSelection,
 
-    /// Filter information by a specific release date (YYYY-MM-DD format)
-    #[clap(long)]
-    release_date: Option<String>,
+    /// Output format of the information
+    #[clap(long, value_enum, default_value_t = OutputFormat::Table)]
+    format: OutputFormat,
+}
+
+/// Options for listing genres.
+///
+/// # Usage
+///
+/// - `sporlcli genres` - List all genres with artist and release counts
+/// - `sporlcli genres --format csv` - Export the genre counts
+#[derive(Parser, Debug, Clone)]
+pub struct GenresOptions {
+    /// Output format of the genre list
+    #[clap(long, value_enum, default_value_t = OutputFormat::Table)]
+    format: OutputFormat,
 }
 
 /// Options for shell completion generation.
@@ -290,6 +482,7 @@ pub struct CompletionsOption {
 /// - `releases` - Handles music release tracking (list, update, filter)
 /// - `playlist` - Creates playlists based on release data
 /// - `info` - Displays statistics and information
+/// - `genres` - Lists the genres of the followed artists
 /// - `completions` - Generates shell completion scripts
 ///
 /// # Error Handling
@@ -311,33 +504,82 @@ async fn main() {
 
     let cli = Cli::parse();
 
+    logging::set_level(Level::from_flags(cli.quiet, cli.verbose));
+    progress::set_mode(cli.progress);
+    if config::log_file_enabled()
+        && let Err(e) = logging::enable_log_file(config::log_file_max_size())
+    {
+        warning!("Cannot open log file. Err: {}", e);
+    }
+
+    if let Some(today) = cli.today.clone().or_else(config::today_override) {
+        match utils::get_date_from_string(Some(today)) {
+            Ok(date) => {
+                clock::install(Clock::fixed(date));
+            }
+            Err(e) => error!("Invalid --today/SPORLCLI_TODAY. {}", e),
+        }
+    }
+
+    if cli.offline || config::offline_enabled() {
+        spotify::set_offline(true);
+    }
+
     match cli.command {
         Command::Auth => {
             let oauth_result: Arc<Mutex<Option<PkceToken>>> = Arc::new(Mutex::new(None));
             cli::auth(Arc::clone(&oauth_result)).await;
         }
         Command::Artists(opt) => match opt.command {
-            Some(ArtistsSubcommand::Update(u)) => cli::update_artists(u.force).await,
-            None => cli::list_artists(opt.search).await,
+            Some(ArtistsSubcommand::Update(u)) => cli::update_artists(u.force, u.prune).await,
+            Some(ArtistsSubcommand::Refresh) => cli::refresh_artists().await,
+            Some(ArtistsSubcommand::Mute(m)) => cli::mute_artist(&m.artist, &m.types).await,
+            Some(ArtistsSubcommand::Allow(a)) => cli::allow_artist(&a.artist, &a.types).await,
+            Some(ArtistsSubcommand::Unmute(u)) => cli::unmute_artist(&u.artist).await,
+            Some(ArtistsSubcommand::Rules) => cli::list_artist_rules().await,
+            None => cli::list_artists(opt.search, opt.format).await,
         },
 
         Command::Releases(opt) => match opt.command {
+            Some(ReleasesSubcommand::Update(u)) if u.retry_failed => {
+                cli::retry_failed_releases(&u.release_types).await
+            }
             Some(ReleasesSubcommand::Update(u)) => {
-                cli::update_releases(u.force, &u.release_types).await
+                cli::update_releases(u.force, &u.release_types, u.resume, u.restart).await
+            }
+            Some(ReleasesSubcommand::New(n)) => cli::new_releases(n.since, opt.format).await,
+            Some(ReleasesSubcommand::Undated) => cli::undated_releases(opt.format).await,
+            Some(ReleasesSubcommand::Reindex) => cli::reindex_releases().await,
+            Some(ReleasesSubcommand::Show(s)) => {
+                cli::show_release(&s.query, s.refresh, opt.format).await
+            }
+            None => {
+                cli::list_releases(
+                    &opt.weeks,
+                    &opt.types,
+                    &opt.genres,
+                    &opt.artists,
+                    opt.show_hidden,
+                    opt.format,
+                )
+                .await
             }
-            None => cli::list_releases(opt.previous_weeks, opt.release_date).await,
         },
 
-        Command::Playlist(opt) => cli::playlist(opt.previous_weeks, opt.release_date).await,
-        Command::Info(opt) => {
-            cli::info(
-                opt.release_week,
-                opt.artists,
-                opt.previous_weeks,
-                opt.release_date,
+        Command::Playlist(opt) => {
+            cli::playlist(
+                &opt.weeks,
+                &opt.types,
+                &opt.genres,
+                &opt.artists,
+                opt.show_hidden,
             )
             .await
         }
+        Command::Info(opt) => {
+            cli::info(opt.release_week, opt.artists, &opt.weeks, opt.format).await
+        }
+        Command::Genres(opt) => cli::list_genres(opt.format).await,
         Command::Completions(opt) => {
             let mut cmd = Cli::command_for_update();
             let name = cmd.get_name().to_string();
@@ -0,0 +1,112 @@
+use crate::types::GetAlbumResponse;
+use std::{collections::HashMap, path::PathBuf};
+
+/// Keeps full album details fetched from Spotify.
+///
+/// Holds the complete album responses, including tracklist, label,
+/// copyrights and UPC, keyed by album ID, so `releases show` can display a
+/// release again without another request and without a connection. Edition
+/// grouping uses the much smaller [`super::EditionIndexManager`] instead.
+///
+/// # Cache Storage
+///
+/// Data is stored in a JSON file at:
+/// - Linux: `~/.local/share/sporlcli/cache/album-details.json`
+/// - macOS: `~/Library/Application Support/sporlcli/cache/album-details.json`
+/// - Windows: `%LOCALAPPDATA%/sporlcli/cache/album-details.json`
+pub struct AlbumDetailsManager {
+    /// Album details by album ID
+    albums: HashMap<String, GetAlbumResponse>,
+}
+
+impl AlbumDetailsManager {
+    /// Creates a new manager with the given album details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let details = AlbumDetailsManager::new(HashMap::new());
+    /// ```
+    pub fn new(albums: HashMap<String, GetAlbumResponse>) -> Self {
+        Self { albums }
+    }
+
+    /// Loads the album details from the local cache file.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing:
+    /// - `Ok(AlbumDetailsManager)` - Manager with the cached album details
+    /// - `Err(String)` - Error message if the file is missing or invalid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let details = AlbumDetailsManager::load()
+    ///     .await
+    ///     .unwrap_or_else(|_| AlbumDetailsManager::new(HashMap::new()));
+    /// ```
+    pub async fn load() -> Result<Self, String> {
+        let content = async_fs::read_to_string(Self::cache_path())
+            .await
+            .map_err(|e| e.to_string())?;
+        let albums: HashMap<String, GetAlbumResponse> =
+            serde_json::from_str(&content).map_err(|e| e.to_string())?;
+        Ok(Self { albums })
+    }
+
+    /// Persists the album details to the local cache file.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success or an error message describing the failure.
+    pub async fn persist(&self) -> Result<(), String> {
+        let path = Self::cache_path();
+        if let Some(parent) = path.parent() {
+            async_fs::create_dir_all(parent)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+
+        let json = serde_json::to_string_pretty(&self.albums).map_err(|e| e.to_string())?;
+        async_fs::write(path, json).await.map_err(|e| e.to_string())
+    }
+
+    /// Returns the cached details of an album, if any.
+    pub fn get(&self, album_id: &str) -> Option<&GetAlbumResponse> {
+        self.albums.get(album_id)
+    }
+
+    /// Stores the details of an album, replacing earlier ones.
+    ///
+    /// # Returns
+    ///
+    /// Returns a mutable reference to self for method chaining.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// details.insert(album).persist().await?;
+    /// ```
+    pub fn insert(&mut self, album: GetAlbumResponse) -> &mut Self {
+        self.albums.insert(album.id.clone(), album);
+        self
+    }
+
+    /// Returns the number of cached albums.
+    pub fn len(&self) -> usize {
+        self.albums.len()
+    }
+
+    /// Returns `true` if no album is cached.
+    pub fn is_empty(&self) -> bool {
+        self.albums.is_empty()
+    }
+
+    /// Returns the filesystem path of the album details cache.
+    fn cache_path() -> PathBuf {
+        let mut path = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+        path.push("sporlcli/cache/album-details.json");
+        path
+    }
+}
@@ -0,0 +1,135 @@
+use crate::types::ArtistReleases;
+use std::path::PathBuf;
+
+/// Keeps artists that are no longer followed together with their cached releases.
+///
+/// When an artist is unfollowed on Spotify, `sporlcli artists update` moves
+/// the artist out of the active artist cache and into this archive instead
+/// of discarding the already fetched release history. If the artist is
+/// followed again later, the archived releases are restored.
+///
+/// # Cache Storage
+///
+/// Data is stored in a JSON file at:
+/// - Linux: `~/.local/share/sporlcli/cache/artist-archive.json`
+/// - macOS: `~/Library/Application Support/sporlcli/cache/artist-archive.json`
+/// - Windows: `%LOCALAPPDATA%/sporlcli/cache/artist-archive.json`
+pub struct ArtistArchiveManager {
+    /// Archived artist-release pairs
+    archived: Vec<ArtistReleases>,
+}
+
+impl ArtistArchiveManager {
+    /// Creates a new, empty archive manager.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let archive = ArtistArchiveManager::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            archived: Vec::new(),
+        }
+    }
+
+    /// Loads the archive from the local cache file.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing:
+    /// - `Ok(ArtistArchiveManager)` - Manager with the archived artists
+    /// - `Err(String)` - Error message if the file is missing or invalid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let archive = ArtistArchiveManager::load()
+    ///     .await
+    ///     .unwrap_or_else(|_| ArtistArchiveManager::new());
+    /// ```
+    pub async fn load() -> Result<Self, String> {
+        let content = async_fs::read_to_string(Self::cache_path())
+            .await
+            .map_err(|e| e.to_string())?;
+        let archived: Vec<ArtistReleases> =
+            serde_json::from_str(&content).map_err(|e| e.to_string())?;
+        Ok(Self { archived })
+    }
+
+    /// Persists the archive to the local cache file.
+    ///
+    /// Creates the cache directory if it doesn't exist yet.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success or an error message describing the failure.
+    pub async fn persist(&self) -> Result<(), String> {
+        let path = Self::cache_path();
+        if let Some(parent) = path.parent() {
+            async_fs::create_dir_all(parent)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+
+        let json = serde_json::to_string_pretty(&self.archived).map_err(|e| e.to_string())?;
+        async_fs::write(path, json).await.map_err(|e| e.to_string())
+    }
+
+    /// Moves the given artists into the archive.
+    ///
+    /// An artist that is already archived is replaced by the new entry, so
+    /// the archive always holds the most recent release history.
+    ///
+    /// # Arguments
+    ///
+    /// * `artists` - Unfollowed artists together with their cached releases
+    ///
+    /// # Returns
+    ///
+    /// Returns a mutable reference to self for method chaining.
+    pub fn archive(&mut self, artists: Vec<ArtistReleases>) -> &mut Self {
+        for ar in artists {
+            self.archived.retain(|a| a.artist.id != ar.artist.id);
+            self.archived.push(ar);
+        }
+        self
+    }
+
+    /// Removes an artist from the archive and returns the archived entry.
+    ///
+    /// Used when a previously unfollowed artist is followed again.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_id` - Spotify ID of the artist to restore
+    ///
+    /// # Returns
+    ///
+    /// The archived entry, or `None` if the artist was never archived.
+    pub fn restore(&mut self, artist_id: &str) -> Option<ArtistReleases> {
+        let pos = self
+            .archived
+            .iter()
+            .position(|ar| ar.artist.id == artist_id)?;
+        Some(self.archived.remove(pos))
+    }
+
+    /// Returns the number of archived artists.
+    pub fn count_artists(&self) -> usize {
+        self.archived.len()
+    }
+
+    /// Returns the filesystem path of the archive file.
+    fn cache_path() -> PathBuf {
+        let mut path = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+        path.push("sporlcli/cache/artist-archive.json");
+        path
+    }
+}
+
+impl Default for ArtistArchiveManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,5 +1,12 @@
-use crate::types::{Album, Artist, ArtistReleases};
-use std::path::PathBuf;
+use crate::{
+    types::{Album, Artist, ArtistReleases, ArtistSyncSummary},
+    utils::{ReleaseKind, ReleaseKinds},
+};
+use chrono::{DateTime, Utc};
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
 
 /// Manages artist data and their associated releases with persistent caching.
 ///
@@ -134,6 +141,28 @@ impl ArtistReleaseManager {
             .map_err(|e| e.to_string())
     }
 
+    /// Returns when the artist cache file was last written.
+    ///
+    /// Used to show how stale cached data is when Spotify cannot be asked,
+    /// e.g. in offline mode.
+    ///
+    /// # Returns
+    ///
+    /// The modification time of the cache file, or `None` if there is no
+    /// cache yet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// if let Some(updated_at) = ArtistReleaseManager::cache_updated_at().await {
+    ///     println!("Cache updated at {}", updated_at);
+    /// }
+    /// ```
+    pub async fn cache_updated_at() -> Option<DateTime<Utc>> {
+        let metadata = async_fs::metadata(Self::cache_path()).await.ok()?;
+        metadata.modified().ok().map(DateTime::<Utc>::from)
+    }
+
     /// Adds a single artist to the manager with an empty releases list.
     ///
     /// Creates a new `ArtistReleases` entry for the artist with no associated
@@ -197,17 +226,143 @@ impl ArtistReleaseManager {
         self
     }
 
-    /// Adds or replaces releases for a specific artist.
+    /// Synchronizes the cached artists with the full list of followed artists.
+    ///
+    /// Compares the cache with `remote` by artist ID instead of by count, so
+    /// follows and unfollows that happen between two updates are both picked
+    /// up. Artists present on both sides keep their cached releases while
+    /// their name and genres are refreshed from the remote data.
+    ///
+    /// # Arguments
+    ///
+    /// * `remote` - The complete list of artists currently followed on Spotify
+    ///
+    /// # Returns
+    ///
+    /// An `ArtistSyncSummary` describing the changes. Unfollowed artists are
+    /// removed from the manager and returned with their releases in
+    /// `removed`, newly followed artists are added with an empty release list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut manager = ArtistReleaseManager::load().await?;
+    /// let summary = manager.sync_artists(followed_artists);
+    /// println!("{} added, {} removed", summary.added.len(), summary.removed.len());
+    /// manager.persist().await?;
+    /// ```
+    pub fn sync_artists(&mut self, remote: Vec<Artist>) -> ArtistSyncSummary {
+        let ars = self.artist_releases.get_or_insert_with(Vec::new);
+        let remote_ids: HashSet<&str> = remote.iter().map(|a| a.id.as_str()).collect();
+
+        let mut summary = ArtistSyncSummary::default();
+        let (kept, removed): (Vec<ArtistReleases>, Vec<ArtistReleases>) = ars
+            .drain(..)
+            .partition(|ar| remote_ids.contains(ar.artist.id.as_str()));
+        *ars = kept;
+        summary.removed = removed;
+
+        let mut seen: HashSet<String> = HashSet::new();
+        for artist in remote {
+            if !seen.insert(artist.id.clone()) {
+                continue;
+            }
+
+            if let Some(ar) = ars.iter_mut().find(|ar| ar.artist.id == artist.id) {
+                ar.artist = artist;
+                summary.unchanged += 1;
+            } else {
+                summary.added.push(artist.clone());
+                ars.push(ArtistReleases {
+                    artist,
+                    releases: Vec::new(),
+                });
+            }
+        }
+
+        summary
+    }
+
+    /// Drops the cached releases of every artist while keeping the artists.
+    ///
+    /// Used by a forced artist update: the cache is synced against the
+    /// followed artists first, so unfollowed artists still leave with their
+    /// releases, and only the releases of still-followed artists are reset.
+    ///
+    /// # Returns
+    ///
+    /// The number of removed releases.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut manager = ArtistReleaseManager::load().await?;
+    /// let summary = manager.sync_artists(followed);
+    /// manager.clear_releases();
+    /// manager.persist().await?;
+    /// ```
+    pub fn clear_releases(&mut self) -> usize {
+        let mut removed = 0;
+        for ar in self.artist_releases.iter_mut().flatten() {
+            removed += ar.releases.len();
+            ar.releases.clear();
+        }
+        removed
+    }
+
+    /// Replaces the metadata of cached artists with freshly fetched data.
     ///
-    /// Updates the releases list for an artist identified by their Spotify ID.
-    /// **Important**: This method clears all existing releases for the artist
-    /// before adding the new ones, as the Spotify API doesn't provide
-    /// incremental release updates by date.
+    /// Matches the given artists by ID and swaps in the new artist objects
+    /// while keeping the cached releases untouched. Artists that are not in
+    /// the cache are ignored, so a refresh never adds new follows.
+    ///
+    /// # Arguments
+    ///
+    /// * `artists` - Artist objects fetched from Spotify's artist endpoints
+    ///
+    /// # Returns
+    ///
+    /// The number of cached artists that were updated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut manager = ArtistReleaseManager::load().await?;
+    /// let updated = manager.update_artist_metadata(fetched_artists);
+    /// manager.persist().await?;
+    /// ```
+    pub fn update_artist_metadata(&mut self, artists: Vec<Artist>) -> usize {
+        let mut updated = 0;
+        if let Some(ars) = &mut self.artist_releases {
+            for artist in artists {
+                if let Some(ar) = ars.iter_mut().find(|ar| ar.artist.id == artist.id) {
+                    ar.artist = artist;
+                    updated += 1;
+                }
+            }
+        }
+        updated
+    }
+
+    /// Merges releases into the cached releases of a specific artist.
+    ///
+    /// Releases are matched by their album ID. Known releases are updated in
+    /// place with the freshly fetched data, unknown releases are appended.
+    /// Nothing is removed, so a run that fetches only some release kinds
+    /// (e.g. `--type single`) keeps the releases of the other kinds. Use
+    /// [`prune_releases_for_artist`](Self::prune_releases_for_artist) to drop
+    /// releases a complete fetch no longer returns.
+    ///
+    /// The `first_seen_at` timestamp of every release survives the merge:
+    /// releases that were already cached keep their original timestamp,
+    /// releases that carry their own timestamp (e.g. restored from the
+    /// archive) keep that one, and all other releases are stamped with the
+    /// current time. A cached `album_group` is kept if the new data has none.
     ///
     /// # Arguments
     ///
     /// * `artist_id` - Spotify ID of the artist to update
-    /// * `releases` - Vector of albums/releases to associate with the artist
+    /// * `releases` - Vector of albums/releases to merge into the artist's releases
     ///
     /// # Returns
     ///
@@ -216,8 +371,8 @@ impl ArtistReleaseManager {
     /// # Behavior
     ///
     /// - Finds the artist by ID
-    /// - Clears their existing releases list
-    /// - Adds all provided releases
+    /// - Replaces releases with a known ID, keeping their first-seen timestamp
+    /// - Appends new releases stamped with the current time
     /// - If artist is not found, no action is taken
     ///
     /// # Example
@@ -229,16 +384,167 @@ impl ArtistReleaseManager {
     /// manager.persist().await?;
     /// ```
     pub fn add_releases_to_artist(&mut self, artist_id: &str, releases: Vec<Album>) -> &mut Self {
-        if let Some(ars) = &mut self.artist_releases {
-            if let Some(ar) = ars.iter_mut().find(|ar| ar.artist.id == artist_id) {
-                // because the Spotify API doesn't have any possibility to get release by release date for an artist we need to clear all previous releases
-                ar.releases.clear();
-                ar.releases.extend(releases);
+        if let Some(ars) = &mut self.artist_releases
+            && let Some(ar) = ars.iter_mut().find(|ar| ar.artist.id == artist_id)
+        {
+            let now = Utc::now().timestamp() as u64;
+
+            for mut release in releases {
+                match ar.releases.iter_mut().find(|r| r.id == release.id) {
+                    Some(cached) => {
+                        release.first_seen_at =
+                            cached.first_seen_at.or(release.first_seen_at).or(Some(now));
+                        if release.album_group.is_none() {
+                            release.album_group = cached.album_group.take();
+                        }
+                        if release.duration_ms.is_none() {
+                            release.duration_ms = cached.duration_ms;
+                        }
+                        *cached = release;
+                    }
+                    None => {
+                        release.first_seen_at = release.first_seen_at.or(Some(now));
+                        ar.releases.push(release);
+                    }
+                }
             }
         }
         self
     }
 
+    /// Stores the total durations of releases, e.g. after fetching full albums.
+    ///
+    /// Spotify's album listing carries no durations, so they are only known
+    /// for releases whose full album details were fetched. Every cached copy
+    /// of a release (one per credited followed artist) is updated.
+    ///
+    /// # Arguments
+    ///
+    /// * `durations` - Total duration in milliseconds per album ID
+    ///
+    /// # Returns
+    ///
+    /// The number of cached releases whose duration changed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// if manager.set_release_durations(&durations) > 0 {
+    ///     manager.persist().await?;
+    /// }
+    /// ```
+    pub fn set_release_durations(&mut self, durations: &HashMap<String, u64>) -> usize {
+        let mut changed = 0;
+        for release in self
+            .artist_releases
+            .iter_mut()
+            .flatten()
+            .flat_map(|ar| ar.releases.iter_mut())
+        {
+            if let Some(duration) = durations.get(&release.id)
+                && release.duration_ms != Some(*duration)
+            {
+                release.duration_ms = Some(*duration);
+                changed += 1;
+            }
+        }
+        changed
+    }
+
+    /// Removes cached releases of the given kinds that are no longer available.
+    ///
+    /// Only call this with the result of a complete fetch of these kinds:
+    /// every cached release of one of the `kinds` whose ID is not in
+    /// `fetched_ids` is considered gone and removed. Releases of other kinds
+    /// are never touched.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_id` - Spotify ID of the artist
+    /// * `kinds` - Release kinds the complete fetch covered
+    /// * `fetched_ids` - Album IDs returned by the complete fetch
+    ///
+    /// # Returns
+    ///
+    /// The number of removed releases.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let response = get_release_for_artist(id.clone(), &token, 50, &kinds).await?;
+    /// if response.is_complete() {
+    ///     let ids = response.items.iter().map(|a| a.id.clone()).collect();
+    ///     manager.prune_releases_for_artist(&id, &kinds, &ids);
+    /// }
+    /// manager.add_releases_to_artist(&id, response.items);
+    /// ```
+    pub fn prune_releases_for_artist(
+        &mut self,
+        artist_id: &str,
+        kinds: &ReleaseKinds,
+        fetched_ids: &HashSet<String>,
+    ) -> usize {
+        let Some(ar) = self
+            .artist_releases
+            .iter_mut()
+            .flatten()
+            .find(|ar| ar.artist.id == artist_id)
+        else {
+            return 0;
+        };
+
+        let before = ar.releases.len();
+        ar.releases.retain(|r| {
+            fetched_ids.contains(&r.id)
+                || !ReleaseKind::from_album(r).is_some_and(|kind| kinds.0.contains(&kind))
+        });
+        before - ar.releases.len()
+    }
+
+    /// Returns all releases first seen at or after the given timestamp.
+    ///
+    /// Collects releases across all artists whose `first_seen_at` is not
+    /// older than `since`. A release credited to several followed artists is
+    /// returned once, with the earliest first-seen timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `since` - Unix timestamp; releases seen before it are skipped
+    ///
+    /// # Returns
+    ///
+    /// The matching releases in no particular order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let manager = ArtistReleaseManager::load().await?;
+    /// let new_releases = manager.releases_first_seen_since(last_update);
+    /// println!("{} new releases", new_releases.len());
+    /// ```
+    pub fn releases_first_seen_since(&self, since: u64) -> Vec<Album> {
+        let mut by_id: HashMap<String, Album> = HashMap::new();
+        for ar in self.artist_releases.iter().flatten() {
+            for release in &ar.releases {
+                let Some(seen_at) = release.first_seen_at else {
+                    continue;
+                };
+                if seen_at < since {
+                    continue;
+                }
+
+                match by_id.get_mut(&release.id) {
+                    Some(existing) if existing.first_seen_at <= Some(seen_at) => {}
+                    Some(existing) => *existing = release.clone(),
+                    None => {
+                        by_id.insert(release.id.clone(), release.clone());
+                    }
+                }
+            }
+        }
+        by_id.into_values().collect()
+    }
+
     /// Retrieves all releases for a specific artist.
     ///
     /// Returns a cloned vector of all albums associated with the specified artist.
@@ -268,6 +574,34 @@ impl ArtistReleaseManager {
         })
     }
 
+    /// Counts the releases that are not cached for the artist yet.
+    ///
+    /// Call this before [`Self::add_releases_to_artist`] to find out how many
+    /// of the fetched releases are new.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_id` - Spotify ID of the artist
+    /// * `releases` - Releases fetched for the artist
+    ///
+    /// # Returns
+    ///
+    /// The number of releases whose ID is not cached for the artist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let new = manager.count_unknown_releases(&artist.id, &response.items);
+    /// manager.add_releases_to_artist(&artist.id, response.items);
+    /// ```
+    pub fn count_unknown_releases(&self, artist_id: &str, releases: &[Album]) -> usize {
+        let cached = self.get_releases_for_artist(artist_id).unwrap_or_default();
+        releases
+            .iter()
+            .filter(|release| !cached.iter().any(|c| c.id == release.id))
+            .count()
+    }
+
     /// Retrieves all artists managed by this instance.
     ///
     /// Returns a cloned vector of all artists in the manager, regardless
@@ -359,6 +693,202 @@ impl ArtistReleaseManager {
         self.artist_releases.clone()
     }
 
+    /// Returns the genres of every cached artist by artist ID.
+    ///
+    /// Used to filter releases by the genres of their followed artists.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let genres = manager.genres_by_artist();
+    /// println!("{:?}", genres.get("4Z8W4fKeB5YxbusRsdQVPb"));
+    /// ```
+    pub fn genres_by_artist(&self) -> HashMap<String, Vec<String>> {
+        self.artist_releases
+            .iter()
+            .flatten()
+            .map(|ar| (ar.artist.id.clone(), ar.artist.genres.clone()))
+            .collect()
+    }
+
+    /// Returns the IDs of all cached (followed) artists.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let followed = manager.followed_artist_ids();
+    /// let row = utils::format_artists(&album.artists, &followed);
+    /// ```
+    pub fn followed_artist_ids(&self) -> HashSet<String> {
+        self.artist_releases
+            .iter()
+            .flatten()
+            .map(|ar| ar.artist.id.clone())
+            .collect()
+    }
+
+    /// Returns the IDs of all cached releases.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let release_ids = manager.release_ids();
+    /// editions.retain_albums(&release_ids);
+    /// ```
+    pub fn release_ids(&self) -> HashSet<String> {
+        self.artist_releases
+            .iter()
+            .flatten()
+            .flat_map(|ar| ar.releases.iter().map(|album| album.id.clone()))
+            .collect()
+    }
+
+    /// Returns the followed artists each cached release was fetched for.
+    ///
+    /// Unlike the credited artists of a release, this also covers releases
+    /// a followed artist only appears on.
+    ///
+    /// # Returns
+    ///
+    /// Artist IDs by album ID.
+    pub fn release_owners(&self) -> HashMap<String, Vec<String>> {
+        let mut owners: HashMap<String, Vec<String>> = HashMap::new();
+        for ar in self.artist_releases.iter().flatten() {
+            for release in &ar.releases {
+                owners
+                    .entry(release.id.clone())
+                    .or_default()
+                    .push(ar.artist.id.clone());
+            }
+        }
+        owners
+    }
+
+    /// Finds a cached artist by ID or name.
+    ///
+    /// Tries, in this order, an exact ID, an exact name (ignoring case) and a
+    /// unique name substring (ignoring case).
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Spotify artist ID or (part of) the artist name
+    ///
+    /// # Returns
+    ///
+    /// The artist, or an error message if no artist or several artists match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let artist = manager.find_artist("radiohead")?;
+    /// ```
+    pub fn find_artist(&self, query: &str) -> Result<Artist, String> {
+        let artists: Vec<&Artist> = self
+            .artist_releases
+            .iter()
+            .flatten()
+            .map(|ar| &ar.artist)
+            .collect();
+        let needle = query.trim().to_lowercase();
+
+        if let Some(artist) = artists.iter().find(|a| a.id == query.trim()) {
+            return Ok((*artist).clone());
+        }
+        if let Some(artist) = artists.iter().find(|a| a.name.to_lowercase() == needle) {
+            return Ok((*artist).clone());
+        }
+
+        let matches: Vec<&&Artist> = artists
+            .iter()
+            .filter(|a| a.name.to_lowercase().contains(&needle))
+            .collect();
+        match matches.as_slice() {
+            [artist] => Ok((**artist).clone()),
+            [] => Err(format!("No followed artist matches '{query}'.")),
+            several => Err(format!(
+                "'{}' matches several artists: {}. Use the artist ID instead.",
+                query,
+                several
+                    .iter()
+                    .map(|a| format!("{} ({})", a.name, a.id))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+        }
+    }
+
+    /// Finds cached releases by name.
+    ///
+    /// Releases named exactly like the query (ignoring case) win; without
+    /// any, all releases whose name contains the query are returned. Each
+    /// release is returned once, even if it is cached for several artists.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - (Part of) the release name
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let releases = manager.find_releases("ok computer");
+    /// ```
+    pub fn find_releases(&self, query: &str) -> Vec<Album> {
+        let needle = query.trim().to_lowercase();
+        let mut releases: Vec<Album> = self
+            .artist_releases
+            .iter()
+            .flatten()
+            .flat_map(|ar| ar.releases.iter())
+            .filter(|album| album.name.to_lowercase().contains(&needle))
+            .cloned()
+            .collect();
+        let mut seen = HashSet::new();
+        releases.retain(|album| seen.insert(album.id.clone()));
+
+        if releases
+            .iter()
+            .any(|album| album.name.to_lowercase() == needle)
+        {
+            releases.retain(|album| album.name.to_lowercase() == needle);
+        }
+        releases
+    }
+
+    /// Counts the cached artists and releases of every genre.
+    ///
+    /// A release credited to several followed artists of the same genre is
+    /// counted once for that genre.
+    ///
+    /// # Returns
+    ///
+    /// `(genre, artists, releases)` tuples, sorted by the number of artists
+    /// (descending) and then by genre.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// for (genre, artists, releases) in manager.genre_counts() {
+    ///     println!("{genre}: {artists} artists, {releases} releases");
+    /// }
+    /// ```
+    pub fn genre_counts(&self) -> Vec<(String, usize, usize)> {
+        let mut counts: HashMap<&str, (usize, HashSet<&str>)> = HashMap::new();
+        for ar in self.artist_releases.iter().flatten() {
+            for genre in &ar.artist.genres {
+                let (artists, releases) = counts.entry(genre.as_str()).or_default();
+                *artists += 1;
+                releases.extend(ar.releases.iter().map(|r| r.id.as_str()));
+            }
+        }
+
+        let mut counts: Vec<(String, usize, usize)> = counts
+            .into_iter()
+            .map(|(genre, (artists, releases))| (genre.to_string(), artists, releases.len()))
+            .collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        counts
+    }
+
     /// Returns the filesystem path where artist-release data is cached.
     ///
     /// Constructs the platform-specific path to the cache file using the
@@ -0,0 +1,155 @@
+use crate::types::{EditionInfo, GetAlbumResponse};
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
+
+/// Keeps the UPCs and explicit flags of albums for edition grouping.
+///
+/// Playlist runs fetch full album details anyway; this index keeps only the
+/// edition facts of those albums, so release listings can group editions the
+/// same way the playlist does without reading full tracklists. Entries of
+/// releases that left the artist cache are pruned when the index is updated.
+///
+/// # Cache Storage
+///
+/// Data is stored in a JSON file at:
+/// - Linux: `~/.local/share/sporlcli/cache/album-editions.json`
+/// - macOS: `~/Library/Application Support/sporlcli/cache/album-editions.json`
+/// - Windows: `%LOCALAPPDATA%/sporlcli/cache/album-editions.json`
+pub struct EditionIndexManager {
+    /// Edition facts by album ID
+    editions: HashMap<String, EditionInfo>,
+}
+
+impl EditionIndexManager {
+    /// Creates a new manager with the given edition facts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let editions = EditionIndexManager::new(HashMap::new());
+    /// ```
+    pub fn new(editions: HashMap<String, EditionInfo>) -> Self {
+        Self { editions }
+    }
+
+    /// Loads the edition index from the local cache file.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing:
+    /// - `Ok(EditionIndexManager)` - Manager with the cached edition facts
+    /// - `Err(String)` - Error message if the file is missing or invalid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let editions = EditionIndexManager::load()
+    ///     .await
+    ///     .unwrap_or_else(|_| EditionIndexManager::new(HashMap::new()));
+    /// ```
+    pub async fn load() -> Result<Self, String> {
+        let content = async_fs::read_to_string(Self::cache_path())
+            .await
+            .map_err(|e| e.to_string())?;
+        let editions: HashMap<String, EditionInfo> =
+            serde_json::from_str(&content).map_err(|e| e.to_string())?;
+        Ok(Self { editions })
+    }
+
+    /// Persists the edition index to the local cache file.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success or an error message describing the failure.
+    pub async fn persist(&self) -> Result<(), String> {
+        let path = Self::cache_path();
+        if let Some(parent) = path.parent() {
+            async_fs::create_dir_all(parent)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+
+        let json = serde_json::to_string_pretty(&self.editions).map_err(|e| e.to_string())?;
+        async_fs::write(path, json).await.map_err(|e| e.to_string())
+    }
+
+    /// Stores the edition facts of an album, replacing earlier ones.
+    ///
+    /// # Returns
+    ///
+    /// Returns a mutable reference to self for method chaining.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// editions.insert(&album).persist().await?;
+    /// ```
+    pub fn insert(&mut self, album: &GetAlbumResponse) -> &mut Self {
+        self.editions
+            .insert(album.id.clone(), EditionInfo::from(album));
+        self
+    }
+
+    /// Drops the edition facts of albums that are no longer cached.
+    ///
+    /// # Arguments
+    ///
+    /// * `album_ids` - IDs of the releases in the artist cache
+    ///
+    /// # Returns
+    ///
+    /// The number of removed entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// editions.retain_albums(&manager.release_ids());
+    /// ```
+    pub fn retain_albums(&mut self, album_ids: &HashSet<String>) -> usize {
+        let before = self.editions.len();
+        self.editions.retain(|id, _| album_ids.contains(id));
+        before - self.editions.len()
+    }
+
+    /// Returns the known UPCs by album ID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let groups = utils::group_editions(albums, &editions.upcs(), &editions.explicit_album_ids());
+    /// ```
+    pub fn upcs(&self) -> HashMap<String, String> {
+        self.editions
+            .iter()
+            .filter_map(|(id, info)| Some((id.clone(), info.upc.clone()?)))
+            .collect()
+    }
+
+    /// Returns the IDs of the albums with explicit tracks.
+    pub fn explicit_album_ids(&self) -> HashSet<String> {
+        self.editions
+            .iter()
+            .filter(|(_, info)| info.explicit)
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+
+    /// Returns the number of indexed albums.
+    pub fn len(&self) -> usize {
+        self.editions.len()
+    }
+
+    /// Returns `true` if no album is indexed.
+    pub fn is_empty(&self) -> bool {
+        self.editions.is_empty()
+    }
+
+    /// Returns the filesystem path of the edition index.
+    fn cache_path() -> PathBuf {
+        let mut path = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+        path.push("sporlcli/cache/album-editions.json");
+        path
+    }
+}
@@ -0,0 +1,128 @@
+use crate::types::UpdateRun;
+use std::path::PathBuf;
+
+/// Maximum number of update runs kept in the history file.
+const MAX_HISTORY_ENTRIES: usize = 50;
+
+/// Keeps a history of successfully completed `releases update` runs.
+///
+/// Together with the per-release `first_seen_at` timestamps this allows
+/// sporlcli to answer "what is new since the last update" without keeping
+/// snapshots of older caches around. Only the most recent runs are kept.
+///
+/// # Cache Storage
+///
+/// Data is stored in a JSON file at:
+/// - Linux: `~/.local/share/sporlcli/cache/update-history.json`
+/// - macOS: `~/Library/Application Support/sporlcli/cache/update-history.json`
+/// - Windows: `%LOCALAPPDATA%/sporlcli/cache/update-history.json`
+pub struct UpdateHistoryManager {
+    /// Completed runs, oldest first
+    runs: Vec<UpdateRun>,
+}
+
+impl UpdateHistoryManager {
+    /// Creates a new history manager with the given runs, oldest first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let history = UpdateHistoryManager::new(Vec::new());
+    /// ```
+    pub fn new(runs: Vec<UpdateRun>) -> Self {
+        Self { runs }
+    }
+
+    /// Loads the update history from the local cache file.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing:
+    /// - `Ok(UpdateHistoryManager)` - Manager with the recorded runs
+    /// - `Err(String)` - Error message if the file is missing or invalid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let history = UpdateHistoryManager::load()
+    ///     .await
+    ///     .unwrap_or_else(|_| UpdateHistoryManager::new(Vec::new()));
+    /// ```
+    pub async fn load() -> Result<Self, String> {
+        let content = async_fs::read_to_string(Self::cache_path())
+            .await
+            .map_err(|e| e.to_string())?;
+        let runs: Vec<UpdateRun> = serde_json::from_str(&content).map_err(|e| e.to_string())?;
+        Ok(Self { runs })
+    }
+
+    /// Persists the update history to the local cache file.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success or an error message describing the failure.
+    pub async fn persist(&self) -> Result<(), String> {
+        let path = Self::cache_path();
+        if let Some(parent) = path.parent() {
+            async_fs::create_dir_all(parent)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+
+        let json = serde_json::to_string_pretty(&self.runs).map_err(|e| e.to_string())?;
+        async_fs::write(path, json).await.map_err(|e| e.to_string())
+    }
+
+    /// Records a successfully completed run.
+    ///
+    /// Drops the oldest entries once more than 50 runs are stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `run` - The completed run
+    ///
+    /// # Returns
+    ///
+    /// Returns a mutable reference to self for method chaining.
+    pub fn record(&mut self, run: UpdateRun) -> &mut Self {
+        self.runs.push(run);
+        if self.runs.len() > MAX_HISTORY_ENTRIES {
+            let excess = self.runs.len() - MAX_HISTORY_ENTRIES;
+            self.runs.drain(..excess);
+        }
+        self
+    }
+
+    /// Returns the most recent successful run, if any.
+    pub fn last_run(&self) -> Option<&UpdateRun> {
+        self.runs.last()
+    }
+
+    /// Returns the point in time that separates "new" from "known" releases.
+    ///
+    /// Releases first seen after the second most recent run finished were
+    /// discovered by the most recent run, so that run's predecessor is the
+    /// "previous successful update". With fewer than two recorded runs every
+    /// release counts as new and `0` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let history = UpdateHistoryManager::load().await?;
+    /// let since = history.new_releases_threshold();
+    /// let new_releases = artist_manager.releases_first_seen_since(since);
+    /// ```
+    pub fn new_releases_threshold(&self) -> u64 {
+        match self.runs.len() {
+            0 | 1 => 0,
+            n => self.runs[n - 2].finished_at,
+        }
+    }
+
+    /// Returns the filesystem path of the history file.
+    fn cache_path() -> PathBuf {
+        let mut path = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+        path.push("sporlcli/cache/update-history.json");
+        path
+    }
+}
@@ -23,6 +23,12 @@
 //!     ↓
 //! Management Layer
 //!     ├── ArtistReleaseManager (Artist + Release associations)
+//!     ├── ArtistArchiveManager (Unfollowed artists and their releases)
+//!     ├── UpdateHistoryManager (Completed release update runs)
+//!     ├── RetryQueueManager (Artists whose release fetch failed)
+//!     ├── ArtistRulesManager (Muted artists and per-artist type rules)
+//!     ├── AlbumDetailsManager (Full album details with tracklists)
+//!     ├── EditionIndexManager (UPCs and explicit flags for edition grouping)
 //!     ├── TokenManager (OAuth token lifecycle)
 //!     ├── ReleaseWeekManager (Weekly release organization)
 //!     └── StateManager (Operation state tracking)
@@ -41,6 +47,26 @@
 //! - Bulk operations for artist and release management
 //! - Cache synchronization with Spotify's followed artists
 //!
+//! [`ArtistArchiveManager`] - Keeps unfollowed artists together with their
+//! cached releases so the history survives an unfollow and is restored when
+//! the artist is followed again.
+//!
+//! [`UpdateHistoryManager`] - Records completed release updates so releases
+//! can be reported as new since the previous successful update.
+//!
+//! [`RetryQueueManager`] - Queues artists whose releases could not be fetched,
+//! with the last error and whether it is permanent, for
+//! `releases update --retry-failed`.
+//!
+//! [`ArtistRulesManager`] - Keeps the mute and allow rules of followed
+//! artists that hide releases from listings and playlists.
+//!
+//! [`AlbumDetailsManager`] - Caches full album details (tracklist, label,
+//! copyrights, UPC) for `releases show`.
+//!
+//! [`EditionIndexManager`] - Keeps the UPC and explicit flag of the cached
+//! releases fetched for playlists, for edition grouping.
+//!
 //! ### Authentication Management
 //!
 //! [`TokenManager`] - Handles OAuth token lifecycle with features including:
@@ -76,8 +102,13 @@
 //! ~/.local/share/sporlcli/  (Linux)
 //! ~/Library/Application Support/sporlcli/  (macOS)
 //! %LOCALAPPDATA%/sporlcli/  (Windows)
+//! ├── artist-rules.json            # Mute and allow rules of artists
 //! ├── cache/
 //! │   ├── artist-releases.json     # Artist-release associations
+//! │   ├── artist-archive.json      # Unfollowed artists and their releases
+//! │   ├── update-history.json      # Completed release update runs
+//! │   ├── album-details.json       # Full album details with tracklists
+//! │   ├── album-editions.json      # UPCs and explicit flags of releases
 //! │   └── token.json               # OAuth tokens
 //! ├── releases/
 //! │   ├── 2023/
@@ -131,16 +162,16 @@
 //! }
 //! ```
 //!
-//! ### Cache Invalidation
+//! ### Cache Synchronization
 //! ```rust
-//! // Check if cache is outdated
-//! let cached_count = manager.count_artists();
-//! let remote_count = get_remote_artist_count().await?;
+//! // Diff the cache against all followed artists by ID
+//! let followed = fetch_all_followed_artists().await?;
+//! let summary = manager.sync_artists(followed);
 //!
-//! if remote_count > cached_count {
-//!     // Update cache with new data
-//!     update_cache().await?;
-//! }
+//! // Keep the release history of unfollowed artists
+//! archive.archive(summary.removed);
+//! manager.persist().await?;
+//! archive.persist().await?;
 //! ```
 //!
 //! ## Performance Considerations
@@ -204,14 +235,26 @@
 //! - [`STATE_TYPE_ARTISTS`] - Identifier for artist processing state
 //! - [`STATE_TYPE_RELEASES`] - Identifier for release processing state
 
+mod album;
+mod archive;
 mod artist;
 mod auth;
+mod edition;
+mod history;
 mod release;
+mod retry;
+mod rules;
 mod state;
 
+pub use album::AlbumDetailsManager;
+pub use archive::ArtistArchiveManager;
 pub use artist::ArtistReleaseManager;
 pub use auth::TokenManager;
+pub use edition::EditionIndexManager;
+pub use history::UpdateHistoryManager;
 pub use release::ReleaseWeekManager;
+pub use retry::RetryQueueManager;
+pub use rules::ArtistRulesManager;
 pub use state::STATE_TYPE_ARTISTS;
 pub use state::STATE_TYPE_RELEASES;
 pub use state::StateManager;
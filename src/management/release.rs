@@ -1,6 +1,6 @@
 use std::{io::Error, path::PathBuf};
 
-use crate::types::Album;
+use crate::{config, types::Album};
 
 /// Error types that can occur during release management operations.
 ///
@@ -258,6 +258,10 @@ impl ReleaseWeekManager {
     /// The path follows the pattern:
     /// `{local_data_dir}/sporlcli/releases/{year}/{week}/releases.json`
     ///
+    /// Weeks of a non-default week scheme (see `WEEK_SCHEME`) are kept apart in
+    /// `{local_data_dir}/sporlcli/releases/{scheme}/{year}/{week}/releases.json`,
+    /// so switching schemes never mixes weeks of different definitions.
+    ///
     /// Example paths:
     /// - Linux: `~/.local/share/sporlcli/releases/2023/42/releases.json`
     /// - macOS: `~/Library/Application Support/sporlcli/releases/2023/42/releases.json`
@@ -280,8 +284,12 @@ impl ReleaseWeekManager {
     /// ```
     fn get_path(&self) -> PathBuf {
         let mut path = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+        path.push("sporlcli/releases");
+        if let Some(scheme_dir) = config::week_scheme().cache_dir() {
+            path.push(scheme_dir);
+        }
         path.push(format!(
-            "sporlcli/releases/{year}/{week}/releases.json",
+            "{year}/{week}/releases.json",
             year = self.year.clone(),
             week = self.week.clone(),
         ));
@@ -0,0 +1,157 @@
+use crate::types::FailedArtist;
+use chrono::Utc;
+use std::path::PathBuf;
+
+/// Keeps the queue of artists whose releases could not be fetched.
+///
+/// `releases update` continues past failing artists and records them here,
+/// together with the error and whether it is permanent. A later successful
+/// fetch removes the artist again; `releases update --retry-failed` fetches
+/// only the queued artists.
+///
+/// # Cache Storage
+///
+/// Data is stored in a JSON file at:
+/// - Linux: `~/.local/share/sporlcli/cache/retry-queue.json`
+/// - macOS: `~/Library/Application Support/sporlcli/cache/retry-queue.json`
+/// - Windows: `%LOCALAPPDATA%/sporlcli/cache/retry-queue.json`
+pub struct RetryQueueManager {
+    /// Queued artists in the order they first failed
+    entries: Vec<FailedArtist>,
+}
+
+impl RetryQueueManager {
+    /// Creates a new retry queue with the given entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let queue = RetryQueueManager::new(Vec::new());
+    /// ```
+    pub fn new(entries: Vec<FailedArtist>) -> Self {
+        Self { entries }
+    }
+
+    /// Loads the retry queue from the local cache file.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing:
+    /// - `Ok(RetryQueueManager)` - Manager with the queued artists
+    /// - `Err(String)` - Error message if the file is missing or invalid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let queue = RetryQueueManager::load()
+    ///     .await
+    ///     .unwrap_or_else(|_| RetryQueueManager::new(Vec::new()));
+    /// ```
+    pub async fn load() -> Result<Self, String> {
+        let content = async_fs::read_to_string(Self::cache_path())
+            .await
+            .map_err(|e| e.to_string())?;
+        let entries: Vec<FailedArtist> =
+            serde_json::from_str(&content).map_err(|e| e.to_string())?;
+        Ok(Self { entries })
+    }
+
+    /// Persists the retry queue to the local cache file.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success or an error message describing the failure.
+    pub async fn persist(&self) -> Result<(), String> {
+        let path = Self::cache_path();
+        if let Some(parent) = path.parent() {
+            async_fs::create_dir_all(parent)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+
+        let json = serde_json::to_string_pretty(&self.entries).map_err(|e| e.to_string())?;
+        async_fs::write(path, json).await.map_err(|e| e.to_string())
+    }
+
+    /// Records a failed attempt for an artist.
+    ///
+    /// Adds the artist to the queue or, if it is queued already, updates the
+    /// error and increases its attempt counter.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_id` - Spotify ID of the artist
+    /// * `artist_name` - Name of the artist
+    /// * `error` - Error message of the failed attempt
+    /// * `permanent` - Whether the error is permanent
+    ///
+    /// # Returns
+    ///
+    /// Returns a mutable reference to self for method chaining.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// queue.record("artist_id", "Artist", "404 Not Found", true).persist().await?;
+    /// ```
+    pub fn record(
+        &mut self,
+        artist_id: &str,
+        artist_name: &str,
+        error: &str,
+        permanent: bool,
+    ) -> &mut Self {
+        let now = Utc::now().timestamp() as u64;
+        match self.entries.iter_mut().find(|e| e.artist_id == artist_id) {
+            Some(entry) => {
+                entry.artist_name = artist_name.to_string();
+                entry.error = error.to_string();
+                entry.permanent = permanent;
+                entry.attempts += 1;
+                entry.last_failed_at = now;
+            }
+            None => self.entries.push(FailedArtist {
+                artist_id: artist_id.to_string(),
+                artist_name: artist_name.to_string(),
+                error: error.to_string(),
+                permanent,
+                attempts: 1,
+                last_failed_at: now,
+            }),
+        }
+        self
+    }
+
+    /// Removes an artist from the queue, e.g. after a successful fetch.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the artist was queued.
+    pub fn remove(&mut self, artist_id: &str) -> bool {
+        let before = self.entries.len();
+        self.entries.retain(|e| e.artist_id != artist_id);
+        self.entries.len() != before
+    }
+
+    /// Returns `true` if the artist is queued.
+    pub fn contains(&self, artist_id: &str) -> bool {
+        self.entries.iter().any(|e| e.artist_id == artist_id)
+    }
+
+    /// Returns all queued artists in the order they first failed.
+    pub fn entries(&self) -> &[FailedArtist] {
+        &self.entries
+    }
+
+    /// Returns `true` if no artist is queued.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the filesystem path of the retry queue file.
+    fn cache_path() -> PathBuf {
+        let mut path = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+        path.push("sporlcli/cache/retry-queue.json");
+        path
+    }
+}
@@ -0,0 +1,247 @@
+use crate::{
+    management::ArtistReleaseManager,
+    types::{Album, ArtistRule},
+    utils::EpRule,
+    warning,
+};
+use std::{collections::HashMap, io::ErrorKind, path::PathBuf};
+
+/// Keeps the local mute and allow rules of followed artists.
+///
+/// Rules are keyed by artist ID and hide releases from `releases` listings
+/// and playlists; the artists themselves stay in the [`ArtistReleaseManager`]
+/// and their releases are still fetched and cached.
+///
+/// A release is hidden only if every followed artist it was fetched for
+/// hides it, so muting an artist does not hide a collaboration with another
+/// followed artist.
+///
+/// # Storage
+///
+/// The rules are user configuration, not cache, and are stored at:
+/// - Linux: `~/.local/share/sporlcli/artist-rules.json`
+/// - macOS: `~/Library/Application Support/sporlcli/artist-rules.json`
+/// - Windows: `%LOCALAPPDATA%/sporlcli/artist-rules.json`
+pub struct ArtistRulesManager {
+    /// Rules in the order they were set
+    rules: Vec<ArtistRule>,
+    /// Followed artists each release was fetched for, by album ID (not persisted)
+    owners: HashMap<String, Vec<String>>,
+}
+
+impl ArtistRulesManager {
+    /// Creates a manager with the given rules.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let rules = ArtistRulesManager::new(Vec::new());
+    /// ```
+    pub fn new(rules: Vec<ArtistRule>) -> Self {
+        Self {
+            rules,
+            owners: HashMap::new(),
+        }
+    }
+
+    /// Loads the rules from the local data directory.
+    ///
+    /// A missing rules file means no rules have been set yet and yields an
+    /// empty manager. An unreadable or invalid file is an error, so a
+    /// hand-edited file is never mistaken for "no rules" and overwritten.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing:
+    /// - `Ok(ArtistRulesManager)` - Manager with the stored rules, empty if none are stored
+    /// - `Err(String)` - Error message if the file cannot be read or is invalid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let rules = match ArtistRulesManager::load().await {
+    ///     Ok(rules) => rules,
+    ///     Err(e) => error!("Failed to load artist rules. Err: {}", e),
+    /// };
+    /// ```
+    pub async fn load() -> Result<Self, String> {
+        let content = match async_fs::read_to_string(Self::path()).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Self::new(Vec::new())),
+            Err(e) => return Err(e.to_string()),
+        };
+        let rules: Vec<ArtistRule> = serde_json::from_str(&content).map_err(|e| e.to_string())?;
+        Ok(Self::new(rules))
+    }
+
+    /// Loads the rules ready for filtering the releases of an artist cache.
+    ///
+    /// Falls back to no rules if none are stored. Invalid rules are ignored
+    /// with a warning, so listings keep working until the file is fixed.
+    ///
+    /// # Arguments
+    ///
+    /// * `manager` - The artist cache, telling which followed artists each
+    ///   release belongs to
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let rules = ArtistRulesManager::load_for(&manager).await;
+    /// releases.retain(|album| rules.permits(album, &ep_rule));
+    /// ```
+    pub async fn load_for(manager: &ArtistReleaseManager) -> Self {
+        let rules = match Self::load().await {
+            Ok(rules) => rules,
+            Err(e) => {
+                warning!(
+                    "Ignoring artist rules, {} is invalid. Err: {}",
+                    Self::path().display(),
+                    e
+                );
+                Self::new(Vec::new())
+            }
+        };
+        rules.with_owners(manager.release_owners())
+    }
+
+    /// Sets the followed artists each release was fetched for.
+    ///
+    /// Without owners, the credited artists of a release are used instead,
+    /// which misses releases the followed artist only appears on.
+    ///
+    /// # Arguments
+    ///
+    /// * `owners` - Artist IDs by album ID, see [`ArtistReleaseManager::release_owners`]
+    pub fn with_owners(mut self, owners: HashMap<String, Vec<String>>) -> Self {
+        self.owners = owners;
+        self
+    }
+
+    /// Persists the rules to the local data directory.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success or an error message describing the failure.
+    pub async fn persist(&self) -> Result<(), String> {
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            async_fs::create_dir_all(parent)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+
+        let json = serde_json::to_string_pretty(&self.rules).map_err(|e| e.to_string())?;
+        async_fs::write(path, json).await.map_err(|e| e.to_string())
+    }
+
+    /// Sets the rule of an artist, replacing an existing one.
+    ///
+    /// # Returns
+    ///
+    /// Returns a mutable reference to self for method chaining.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// rules.set(rule).persist().await?;
+    /// ```
+    pub fn set(&mut self, rule: ArtistRule) -> &mut Self {
+        match self
+            .rules
+            .iter_mut()
+            .find(|r| r.artist_id == rule.artist_id)
+        {
+            Some(existing) => *existing = rule,
+            None => self.rules.push(rule),
+        }
+        self
+    }
+
+    /// Removes the rule of an artist.
+    ///
+    /// # Returns
+    ///
+    /// The removed rule, if the artist had one.
+    pub fn remove(&mut self, artist_id: &str) -> Option<ArtistRule> {
+        let index = self.rules.iter().position(|r| r.artist_id == artist_id)?;
+        Some(self.rules.remove(index))
+    }
+
+    /// Returns the rule of an artist, if any.
+    pub fn get(&self, artist_id: &str) -> Option<&ArtistRule> {
+        self.rules.iter().find(|r| r.artist_id == artist_id)
+    }
+
+    /// Returns all rules in the order they were set.
+    pub fn rules(&self) -> &[ArtistRule] {
+        &self.rules
+    }
+
+    /// Returns `true` if no rule is set.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Returns `true` if the release is shown despite the rules.
+    ///
+    /// A release is shown if at least one followed artist it belongs to has
+    /// no rule or a rule that lets it through.
+    ///
+    /// # Arguments
+    ///
+    /// * `album` - The release to check
+    /// * `ep_rule` - Thresholds that turn a single into an EP
+    pub fn permits(&self, album: &Album, ep_rule: &EpRule) -> bool {
+        self.hidden_reason(album, ep_rule).is_none()
+    }
+
+    /// Explains why the rules hide a release.
+    ///
+    /// # Arguments
+    ///
+    /// * `album` - The release to check
+    /// * `ep_rule` - Thresholds that turn a single into an EP
+    ///
+    /// # Returns
+    ///
+    /// `None` if the release is shown, otherwise the rules that hide it,
+    /// e.g. `artist rule: Some Artist muted`.
+    pub fn hidden_reason(&self, album: &Album, ep_rule: &EpRule) -> Option<String> {
+        if self.rules.is_empty() {
+            return None;
+        }
+
+        let credited: Vec<String>;
+        let owners = match self.owners.get(&album.id) {
+            Some(owners) => owners,
+            None => {
+                credited = album.artists.iter().map(|a| a.id.clone()).collect();
+                &credited
+            }
+        };
+
+        let mut hiding = Vec::new();
+        for artist_id in owners {
+            match self.get(artist_id) {
+                Some(rule) if !rule.permits(album, ep_rule) => {
+                    hiding.push(format!("{} {}", rule.artist_name, rule.describe()));
+                }
+                _ => return None,
+            }
+        }
+
+        if hiding.is_empty() {
+            None
+        } else {
+            Some(format!("artist rule: {}", hiding.join(", ")))
+        }
+    }
+
+    /// Returns the filesystem path of the rules file.
+    fn path() -> PathBuf {
+        let mut path = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+        path.push("sporlcli/artist-rules.json");
+        path
+    }
+}
@@ -1,3 +1,7 @@
+use crate::types::{Checkpoint, RunParams};
+use chrono::Utc;
+use rand::{Rng, distr::Alphanumeric};
+use serde::Deserialize;
 use std::{io::Error, path::PathBuf};
 
 /// State type identifier for tracking artist update status.
@@ -48,12 +52,38 @@ impl From<Error> for StateError {
     }
 }
 
+/// On-disk representation of a checkpoint.
+///
+/// Older versions stored a bare list of processed IDs; those files are still
+/// accepted and turned into a checkpoint without run parameters.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredCheckpoint {
+    Current(Checkpoint),
+    Legacy(Vec<String>),
+}
+
+impl From<StoredCheckpoint> for Checkpoint {
+    fn from(stored: StoredCheckpoint) -> Self {
+        match stored {
+            StoredCheckpoint::Current(checkpoint) => checkpoint,
+            StoredCheckpoint::Legacy(ids) => Checkpoint {
+                run_id: "legacy".to_string(),
+                completed: ids.into_iter().collect(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
 /// Manages application state with persistent storage for tracking operations.
 ///
 /// Provides functionality to track the state of various operations (like artist
-/// updates or release processing) by maintaining lists of processed items. This
-/// enables resume capability, prevents duplicate processing, and provides audit
-/// trails for long-running operations.
+/// updates or release processing) as a checkpoint. Besides the processed items
+/// the checkpoint records the run ID, the parameters and start time of the run
+/// and the items that failed together with their error. This enables safe
+/// resume capability, prevents duplicate processing, and provides audit trails
+/// for long-running operations.
 ///
 /// # State Types
 ///
@@ -63,9 +93,9 @@ impl From<Error> for StateError {
 ///
 /// # Storage Format
 ///
-/// State is stored as JSON arrays of strings, typically containing IDs of
-/// processed items. This simple format allows for easy inspection and
-/// modification if needed.
+/// State is stored as a JSON object (see [`Checkpoint`]) with the completed
+/// IDs as a set and failed IDs mapped to their error messages. This simple
+/// format allows for easy inspection and modification if needed.
 ///
 /// # File Organization
 ///
@@ -82,14 +112,14 @@ impl From<Error> for StateError {
 pub struct StateManager {
     /// The type of state being managed (e.g., "state_artists")
     state_type: String,
-    /// The current state as a vector of string identifiers
-    state: Vec<String>,
+    /// The current checkpoint
+    state: Checkpoint,
 }
 
 impl StateManager {
     /// Creates a new StateManager for the specified state type.
     ///
-    /// Initializes the manager with an empty state vector. The state type
+    /// Initializes the manager with an empty checkpoint. The state type
     /// determines the filename used for persistent storage and helps organize
     /// different types of operational state.
     ///
@@ -113,14 +143,72 @@ impl StateManager {
     pub fn new(state_type: String) -> Self {
         Self {
             state_type,
-            state: Vec::new(),
+            state: Checkpoint::default(),
         }
     }
 
+    /// Starts a new run with the given parameters.
+    ///
+    /// Discards all progress held in memory, generates a new run ID and
+    /// records the parameters and start time of the run. The checkpoint is
+    /// not persisted automatically.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Parameters the new run is started with
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut state = StateManager::new(STATE_TYPE_RELEASES.to_string());
+    /// state.start(RunParams { release_types: "album".to_string(), force: false });
+    /// state.persist().await?;
+    /// ```
+    pub fn start(&mut self, params: RunParams) {
+        let started_at = Utc::now().timestamp() as u64;
+        let suffix: String = rand::rng()
+            .sample_iter(&Alphanumeric)
+            .take(6)
+            .map(char::from)
+            .collect();
+
+        self.state = Checkpoint {
+            run_id: format!("{started_at}-{suffix}"),
+            params: Some(params),
+            started_at,
+            ..Default::default()
+        };
+    }
+
+    /// Checks whether the checkpoint belongs to a run with the given parameters.
+    ///
+    /// Legacy checkpoints without recorded parameters never match.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Parameters of the run that wants to resume
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the recorded parameters equal `params`.
+    pub fn matches(&self, params: &RunParams) -> bool {
+        self.state.params.as_ref() == Some(params)
+    }
+
+    /// Checks whether the checkpoint holds any progress worth resuming.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if no item has been completed or failed yet.
+    pub fn is_empty(&self) -> bool {
+        self.state.completed.is_empty() && self.state.failed.is_empty()
+    }
+
     /// Adds an item to the current state.
     ///
-    /// Appends a string identifier to the state vector. This is typically
-    /// used to mark an item as processed during operations. The item is
+    /// Inserts a string identifier into the set of completed items. This is
+    /// typically used to mark an item as processed during operations. A
+    /// previously recorded failure of the item is removed. The item is
     /// added to memory but not automatically persisted.
     ///
     /// # Arguments
@@ -137,24 +225,42 @@ impl StateManager {
     /// // Remember to persist changes
     /// manager.persist().await?;
     /// ```
+    pub fn add(&mut self, item: String) {
+        self.state.failed.remove(&item);
+        self.state.completed.insert(item);
+    }
+
+    /// Records that processing an item failed.
     ///
-    /// # Note
+    /// Failed items are not treated as completed, so a resumed run retries
+    /// them. The error message of the latest attempt is kept.
     ///
-    /// This method does not check for duplicates. If duplicate prevention
-    /// is needed, use the `has()` method to check before adding.
-    pub fn add(&mut self, item: String) {
-        self.state.push(item);
+    /// # Arguments
+    ///
+    /// * `item` - String identifier of the failed item
+    /// * `error` - Description of the failure
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// if let Err(e) = fetch_releases(&artist_id).await {
+    ///     state.fail(artist_id, e.to_string());
+    ///     state.persist().await?;
+    /// }
+    /// ```
+    pub fn fail(&mut self, item: String, error: String) {
+        self.state.failed.insert(item, error);
     }
 
-    /// Returns a reference to the current state vector.
+    /// Returns a reference to the current checkpoint.
     ///
     /// Provides read-only access to the complete state without cloning.
-    /// Useful for inspecting the current state, checking its size, or
-    /// iterating over processed items.
+    /// Useful for inspecting the run parameters, checking the progress, or
+    /// iterating over processed and failed items.
     ///
     /// # Returns
     ///
-    /// A reference to the vector of string identifiers in the current state.
+    /// A reference to the current checkpoint.
     ///
     /// # Example
     ///
@@ -162,18 +268,18 @@ impl StateManager {
     /// let manager = StateManager::new(STATE_TYPE_ARTISTS.to_string()).load().await?;
     /// let state = manager.get_state();
     ///
-    /// println!("Processed {} items", state.len());
-    /// for item in state {
-    ///     println!("Processed: {}", item);
+    /// println!("Processed {} items", state.completed.len());
+    /// for (item, error) in &state.failed {
+    ///     println!("Failed: {} ({})", item, error);
     /// }
     /// ```
-    pub fn get_state(&self) -> &Vec<String> {
+    pub fn get_state(&self) -> &Checkpoint {
         &self.state
     }
 
     /// Persists the current state to the cache file.
     ///
-    /// Serializes the current state vector to JSON and writes it to the
+    /// Serializes the current checkpoint to JSON and writes it to the
     /// appropriate state file. Creates the necessary directory structure
     /// if it doesn't exist. The data is formatted with pretty printing
     /// for better readability.
@@ -240,7 +346,7 @@ impl StateManager {
     /// - The state file doesn't exist for this state type
     /// - The file cannot be read due to permissions or I/O issues
     /// - The file content is not valid JSON
-    /// - The JSON structure doesn't match the expected `Vec<String>` format
+    /// - The JSON structure is neither a checkpoint nor a legacy list of IDs
     ///
     /// # Example
     ///
@@ -248,7 +354,7 @@ impl StateManager {
     /// let mut manager = StateManager::new(STATE_TYPE_ARTISTS.to_string());
     /// let loaded_manager = manager.load().await?;
     ///
-    /// println!("Loaded state with {} items", loaded_manager.get_state().len());
+    /// println!("Loaded state with {} items", loaded_manager.get_state().completed.len());
     /// ```
     ///
     /// # Note
@@ -261,17 +367,17 @@ impl StateManager {
         let json = async_fs::read_to_string(path)
             .await
             .map_err(|e| StateError::IoError(e))?;
-        let state: Vec<String> =
+        let state: StoredCheckpoint =
             serde_json::from_str(&json).map_err(|e| StateError::SerdeError(e))?;
         Ok(Self {
             state_type: self.state_type.clone(),
-            state,
+            state: state.into(),
         })
     }
 
     /// Checks if a specific item exists in the current state.
     ///
-    /// Performs a linear search through the state vector to determine if
+    /// Looks the item up in the set of completed items to determine if
     /// the specified item has already been processed. This is useful for
     /// preventing duplicate processing and implementing conditional logic.
     ///
@@ -281,13 +387,8 @@ impl StateManager {
     ///
     /// # Returns
     ///
-    /// Returns `true` if the item exists in the state, `false` otherwise.
-    ///
-    /// # Performance Note
-    ///
-    /// This method performs a linear search, so performance will degrade
-    /// with very large state vectors. For frequent lookups on large datasets,
-    /// consider using a HashSet-based approach.
+    /// Returns `true` if the item has been completed, `false` otherwise.
+    /// Failed items are not considered completed.
     ///
     /// # Example
     ///
@@ -308,12 +409,12 @@ impl StateManager {
     /// Despite the parameter name `artist_id`, this method works with any
     /// string identifier depending on the state type being managed.
     pub fn has(&self, artist_id: String) -> bool {
-        self.state.contains(&artist_id)
+        self.state.completed.contains(&artist_id)
     }
 
     /// Clears the current state and removes the state file.
     ///
-    /// Empties the in-memory state vector and deletes the corresponding
+    /// Resets the in-memory checkpoint and deletes the corresponding
     /// state file from disk. This is useful for resetting operations,
     /// starting fresh, or cleaning up after completed operations.
     ///
@@ -332,7 +433,7 @@ impl StateManager {
     ///
     /// # Behavior
     ///
-    /// 1. Clears the in-memory state vector
+    /// 1. Resets the in-memory checkpoint
     /// 2. Attempts to remove the state file from disk
     /// 3. Both operations must succeed for the method to return Ok(())
     ///
@@ -355,7 +456,7 @@ impl StateManager {
     /// - Removing stale state files
     pub async fn clear(&mut self) -> Result<(), StateError> {
         let path = Self::get_path(&self);
-        self.state.clear();
+        self.state = Checkpoint::default();
         async_fs::remove_file(path)
             .await
             .map_err(|e| StateError::IoError(e))
@@ -0,0 +1,782 @@
+//! Output rendering for listing commands.
+//!
+//! `artists`, `releases`, `genres` and `info` print their results either as human
+//! readable tables or in one of several machine-readable formats selected
+//! with `--format`:
+//!
+//! - `table` - Unicode tables (default)
+//! - `markdown` - Markdown tables
+//! - `json` - A single versioned JSON document
+//! - `ndjson` - One JSON record per line
+//! - `csv` - Comma separated values with a header row
+//!
+//! # JSON Schema
+//!
+//! JSON output is wrapped in an envelope carrying the schema version and the
+//! kind of records it contains:
+//!
+//! ```json
+//! { "version": 1, "kind": "releases", "items": [ ... ] }
+//! ```
+//!
+//! NDJSON lines contain the bare records of the same schema. Fields are only
+//! ever added within a version; renaming or removing a field bumps
+//! [`OUTPUT_VERSION`].
+
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::collections::HashSet;
+use tabled::{Table, Tabled, settings::Style};
+
+use crate::{
+    types::{
+        Album, AlbumArtist, AlbumFieldRow, Artist, ArtistCountTableRow, ArtistTableRow, Copyright,
+        GetAlbumResponse, HiddenReleaseTableRow, OutputFormat, ReleaseTableRow, Track,
+        TrackTableRow, WeekOfTheYear,
+    },
+    utils::{self, EpRule, WeekScheme},
+};
+
+/// Version of the machine-readable output schema.
+pub const OUTPUT_VERSION: u32 = 1;
+
+/// A record that can be rendered in every output format.
+pub trait OutputRecord: Serialize {
+    /// Table row type used for the `table` and `markdown` formats
+    type Row: Tabled;
+
+    /// Kind of the records, reported in the JSON envelope
+    const KIND: &'static str;
+
+    /// Column names of the CSV header
+    const CSV_HEADER: &'static [&'static str];
+
+    /// Returns the CSV fields in the order of [`Self::CSV_HEADER`].
+    fn csv_fields(&self) -> Vec<String>;
+
+    /// Returns the table row representing this record.
+    fn table_row(&self) -> Self::Row;
+}
+
+/// JSON envelope wrapping the records of one command invocation.
+#[derive(Serialize)]
+struct Envelope<'a, R: Serialize> {
+    /// Output schema version
+    version: u32,
+    /// Kind of the contained records
+    kind: &'static str,
+    /// The records
+    items: &'a [R],
+}
+
+/// A release in machine-readable output.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReleaseRecord {
+    /// Spotify album ID
+    pub id: String,
+    /// Album name
+    pub name: String,
+    /// Spotify album type (`album`, `single`, `compilation`)
+    pub album_type: String,
+    /// Relationship to the followed artist (`album`, `single`, `compilation`, `appears_on`)
+    pub album_group: Option<String>,
+    /// Release kind including derived EPs (`album`, `single`, `ep`, `compilation`)
+    pub kind: String,
+    /// Number of tracks, `null` if unknown
+    pub total_tracks: Option<u32>,
+    /// Total duration in milliseconds, `null` if unknown
+    pub duration_ms: Option<u64>,
+    /// Release date as reported by Spotify (`2024-10-18`, `2024-10` or `2024`)
+    pub release_date: String,
+    /// Precision of the release date (`day`, `month` or `year`)
+    pub release_date_precision: String,
+    /// Release week number, `null` if the release is not placed into a week
+    pub week: Option<u32>,
+    /// Release year of the week, `null` if the release is not placed into a week
+    pub year: Option<i32>,
+    /// All credited artists
+    pub artists: Vec<AlbumArtist>,
+    /// Unix timestamp when the release was first seen by sporlcli
+    pub first_seen_at: Option<u64>,
+    /// Why the release is hidden, `null` for shown releases (see `--show-hidden`)
+    pub hidden: Option<String>,
+    /// IDs of the credited artists that are followed
+    pub followed_artist_ids: Vec<String>,
+    /// IDs of the other editions grouped into this release (see `GROUP_EDITIONS`)
+    pub edition_ids: Vec<String>,
+}
+
+impl ReleaseRecord {
+    /// Builds a record from an album and the release week it is listed in.
+    ///
+    /// # Arguments
+    ///
+    /// * `album` - The release
+    /// * `week` - The release week the album belongs to, if any
+    /// * `followed` - IDs of the followed artists
+    /// * `ep_rule` - Decides which releases count as EPs
+    pub fn new(
+        album: &Album,
+        week: Option<&WeekOfTheYear>,
+        followed: &HashSet<String>,
+        ep_rule: &EpRule,
+    ) -> Self {
+        Self {
+            id: album.id.clone(),
+            name: album.name.clone(),
+            album_type: album.album_type.clone(),
+            album_group: album.album_group.clone(),
+            kind: utils::release_kind_name(album, ep_rule),
+            total_tracks: album.total_tracks,
+            duration_ms: album.duration_ms,
+            release_date: album.release_date.clone(),
+            release_date_precision: album.release_date_precision.clone(),
+            week: week.map(|w| w.week),
+            year: week.map(|w| w.year),
+            artists: album.artists.clone(),
+            first_seen_at: album.first_seen_at,
+            hidden: None,
+            followed_artist_ids: album
+                .artists
+                .iter()
+                .filter(|artist| followed.contains(&artist.id))
+                .map(|artist| artist.id.clone())
+                .collect(),
+            edition_ids: Vec::new(),
+        }
+    }
+
+    /// Builds a record from a group of editions of the same release.
+    ///
+    /// The record describes the first (preferred) edition and lists the IDs
+    /// of the others, see [`utils::group_editions`].
+    ///
+    /// # Arguments
+    ///
+    /// * `editions` - The editions, preferred one first; must not be empty
+    /// * `week` - The release week the release belongs to, if any
+    /// * `followed` - IDs of the followed artists
+    /// * `ep_rule` - Decides which releases count as EPs
+    pub fn from_editions(
+        editions: &[Album],
+        week: Option<&WeekOfTheYear>,
+        followed: &HashSet<String>,
+        ep_rule: &EpRule,
+    ) -> Self {
+        Self {
+            edition_ids: editions[1..].iter().map(|album| album.id.clone()).collect(),
+            ..Self::new(&editions[0], week, followed, ep_rule)
+        }
+    }
+}
+
+impl OutputRecord for ReleaseRecord {
+    type Row = ReleaseTableRow;
+
+    const KIND: &'static str = "releases";
+
+    const CSV_HEADER: &'static [&'static str] = &[
+        "id",
+        "name",
+        "album_type",
+        "album_group",
+        "release_date",
+        "release_date_precision",
+        "week",
+        "year",
+        "artists",
+        "artist_ids",
+        "first_seen_at",
+        "kind",
+        "total_tracks",
+        "duration_ms",
+        "hidden",
+        "followed_artist_ids",
+        "edition_ids",
+    ];
+
+    fn csv_fields(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.name.clone(),
+            self.album_type.clone(),
+            self.album_group.clone().unwrap_or_default(),
+            self.release_date.clone(),
+            self.release_date_precision.clone(),
+            optional(self.week),
+            optional(self.year),
+            join_artists(&self.artists, |a| &a.name),
+            join_artists(&self.artists, |a| &a.id),
+            optional(self.first_seen_at),
+            self.kind.clone(),
+            optional(self.total_tracks),
+            optional(self.duration_ms),
+            self.hidden.clone().unwrap_or_default(),
+            self.followed_artist_ids.join("; "),
+            self.edition_ids.join("; "),
+        ]
+    }
+
+    fn table_row(&self) -> ReleaseTableRow {
+        let date = if self.release_date_precision == "day" {
+            self.release_date.clone()
+        } else {
+            format!("{} (approx.)", self.release_date)
+        };
+
+        ReleaseTableRow {
+            date,
+            kind: utils::release_type_label(&self.kind, self.album_group.as_deref()),
+            name: if self.edition_ids.is_empty() {
+                self.name.clone()
+            } else {
+                format!("{} ({} editions)", self.name, self.edition_ids.len() + 1)
+            },
+            artists: utils::format_artists(
+                &self.artists,
+                &self.followed_artist_ids.iter().cloned().collect(),
+            ),
+        }
+    }
+}
+
+impl From<&ReleaseRecord> for HiddenReleaseTableRow {
+    fn from(record: &ReleaseRecord) -> Self {
+        let row = record.table_row();
+        Self {
+            date: row.date,
+            kind: row.kind,
+            name: row.name,
+            artists: row.artists,
+            reason: record.hidden.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// A followed artist in machine-readable output.
+#[derive(Debug, Clone, Serialize)]
+pub struct ArtistRecord {
+    /// Spotify artist ID
+    pub id: String,
+    /// Artist name
+    pub name: String,
+    /// All genres of the artist
+    pub genres: Vec<String>,
+    /// Popularity between 0 and 100, `null` if unknown
+    pub popularity: Option<u32>,
+    /// Number of followers, `null` if unknown
+    pub followers: Option<u64>,
+    /// Spotify web player URL, `null` if unknown
+    pub url: Option<String>,
+}
+
+impl From<&Artist> for ArtistRecord {
+    fn from(artist: &Artist) -> Self {
+        Self {
+            id: artist.id.clone(),
+            name: artist.name.clone(),
+            genres: artist.genres.clone(),
+            popularity: artist.popularity,
+            followers: artist.followers.as_ref().map(|f| f.total),
+            url: artist.external_urls.spotify.clone(),
+        }
+    }
+}
+
+impl OutputRecord for ArtistRecord {
+    type Row = ArtistTableRow;
+
+    const KIND: &'static str = "artists";
+
+    const CSV_HEADER: &'static [&'static str] =
+        &["id", "name", "genres", "popularity", "followers", "url"];
+
+    fn csv_fields(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.name.clone(),
+            self.genres.join("; "),
+            optional(self.popularity),
+            optional(self.followers),
+            self.url.clone().unwrap_or_default(),
+        ]
+    }
+
+    fn table_row(&self) -> ArtistTableRow {
+        ArtistTableRow {
+            name: self.name.clone(),
+            genres: self
+                .genres
+                .iter()
+                .take(3)
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(","),
+            popularity: optional(self.popularity),
+            followers: optional(self.followers),
+        }
+    }
+}
+
+/// A release week in machine-readable output.
+#[derive(Debug, Clone, Serialize, Tabled)]
+pub struct WeekRecord {
+    /// Week number within the release year
+    pub week: u32,
+    /// Release year the week belongs to
+    pub year: i32,
+    /// Display label of the week in the configured week scheme
+    pub label: String,
+    /// Week scheme the week is defined by (`sat-fri`, `fri-thu` or `iso`)
+    pub scheme: String,
+    /// First day of the week (YYYY-MM-DD)
+    pub start: String,
+    /// Last day of the week (YYYY-MM-DD)
+    pub end: String,
+}
+
+impl WeekRecord {
+    /// Builds a record from a release week.
+    ///
+    /// # Arguments
+    ///
+    /// * `week` - The release week
+    /// * `scheme` - The week scheme the week was built with
+    pub fn new(week: &WeekOfTheYear, scheme: WeekScheme) -> Self {
+        let day = |date: Option<&NaiveDate>| date.map(|d| d.to_string()).unwrap_or_default();
+        Self {
+            week: week.week,
+            year: week.year,
+            label: scheme.label(week.week, week.year),
+            scheme: scheme.to_string(),
+            start: day(week.dates.first()),
+            end: day(week.dates.last()),
+        }
+    }
+}
+
+impl OutputRecord for WeekRecord {
+    type Row = WeekRecord;
+
+    const KIND: &'static str = "weeks";
+
+    const CSV_HEADER: &'static [&'static str] =
+        &["week", "year", "label", "scheme", "start", "end"];
+
+    fn csv_fields(&self) -> Vec<String> {
+        vec![
+            self.week.to_string(),
+            self.year.to_string(),
+            self.label.clone(),
+            self.scheme.clone(),
+            self.start.clone(),
+            self.end.clone(),
+        ]
+    }
+
+    fn table_row(&self) -> WeekRecord {
+        self.clone()
+    }
+}
+
+/// Artist counts of the local cache and Spotify in machine-readable output.
+#[derive(Debug, Clone, Serialize)]
+pub struct ArtistCountRecord {
+    /// Number of artists in the local cache
+    pub cache: u64,
+    /// Number of artists followed on Spotify, `null` in offline mode
+    pub remote: Option<u64>,
+    /// When the artist cache was last written (RFC 3339), `null` if there is no cache
+    pub cache_updated_at: Option<String>,
+}
+
+impl OutputRecord for ArtistCountRecord {
+    type Row = ArtistCountTableRow;
+
+    const KIND: &'static str = "artist_counts";
+
+    const CSV_HEADER: &'static [&'static str] = &["cache", "remote", "cache_updated_at"];
+
+    fn csv_fields(&self) -> Vec<String> {
+        vec![
+            self.cache.to_string(),
+            optional(self.remote),
+            self.cache_updated_at.clone().unwrap_or_default(),
+        ]
+    }
+
+    fn table_row(&self) -> ArtistCountTableRow {
+        ArtistCountTableRow {
+            cache: self.cache.to_string(),
+            remote: optional(self.remote),
+            cache_updated_at: self.cache_updated_at.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// A genre of the followed artists in machine-readable output.
+#[derive(Debug, Clone, Serialize, Tabled)]
+pub struct GenreRecord {
+    /// Genre name as reported by Spotify
+    pub genre: String,
+    /// Number of cached artists with this genre
+    pub artists: usize,
+    /// Number of cached releases of these artists
+    pub releases: usize,
+}
+
+impl OutputRecord for GenreRecord {
+    type Row = GenreRecord;
+
+    const KIND: &'static str = "genres";
+
+    const CSV_HEADER: &'static [&'static str] = &["genre", "artists", "releases"];
+
+    fn csv_fields(&self) -> Vec<String> {
+        vec![
+            self.genre.clone(),
+            self.artists.to_string(),
+            self.releases.to_string(),
+        ]
+    }
+
+    fn table_row(&self) -> GenreRecord {
+        self.clone()
+    }
+}
+
+/// A release with its full details in machine-readable output (`releases show`).
+#[derive(Debug, Clone, Serialize)]
+pub struct AlbumRecord {
+    /// Spotify album ID
+    pub id: String,
+    /// Album name
+    pub name: String,
+    /// Spotify album type (`album`, `single`, `compilation`), `null` if unknown
+    pub album_type: Option<String>,
+    /// Release date as reported by Spotify
+    pub release_date: String,
+    /// Precision of the release date (`day`, `month` or `year`), `null` if unknown
+    pub release_date_precision: Option<String>,
+    /// All credited artists
+    pub artists: Vec<AlbumArtist>,
+    /// IDs of the credited artists that are followed
+    pub followed_artist_ids: Vec<String>,
+    /// Record label, `null` if unknown
+    pub label: Option<String>,
+    /// Universal Product Code, `null` if unknown
+    pub upc: Option<String>,
+    /// Number of tracks, `null` if unknown
+    pub total_tracks: Option<u32>,
+    /// Total duration in milliseconds, `null` unless all tracks are known
+    pub duration_ms: Option<u64>,
+    /// Spotify web player URL, `null` if unknown
+    pub url: Option<String>,
+    /// Copyright statements
+    pub copyrights: Vec<Copyright>,
+    /// The tracks in album order
+    pub tracks: Vec<TrackRecord>,
+}
+
+impl AlbumRecord {
+    /// Builds a record from full album details.
+    ///
+    /// # Arguments
+    ///
+    /// * `album` - The album details
+    /// * `followed` - IDs of the followed artists
+    pub fn new(album: &GetAlbumResponse, followed: &HashSet<String>) -> Self {
+        Self {
+            id: album.id.clone(),
+            name: album.name.clone(),
+            album_type: album.album_type.clone(),
+            release_date: album.release_date.clone(),
+            release_date_precision: album.release_date_precision.clone(),
+            artists: album.artists.clone(),
+            followed_artist_ids: album
+                .artists
+                .iter()
+                .filter(|artist| followed.contains(&artist.id))
+                .map(|artist| artist.id.clone())
+                .collect(),
+            label: album.label.clone(),
+            upc: album.external_ids.upc.clone(),
+            total_tracks: album.total_tracks.or(album.tracks.total),
+            duration_ms: album.duration_ms(),
+            url: album.external_urls.spotify.clone(),
+            copyrights: album.copyrights.clone(),
+            tracks: album.tracks.items.iter().map(TrackRecord::from).collect(),
+        }
+    }
+
+    /// Returns the album fields as `field`/`value` rows for table formats.
+    pub fn field_rows(&self) -> Vec<AlbumFieldRow> {
+        let followed: HashSet<String> = self.followed_artist_ids.iter().cloned().collect();
+        let mut rows = vec![
+            ("artists", utils::format_artists(&self.artists, &followed)),
+            ("type", self.album_type.clone().unwrap_or_default()),
+            ("released", self.release_date.clone()),
+            ("label", self.label.clone().unwrap_or_default()),
+            ("upc", self.upc.clone().unwrap_or_default()),
+            ("tracks", optional(self.total_tracks)),
+            (
+                "duration",
+                self.duration_ms
+                    .map(utils::format_track_length)
+                    .unwrap_or_default(),
+            ),
+            ("url", self.url.clone().unwrap_or_default()),
+        ];
+        rows.extend(self.copyrights.iter().map(|c| {
+            let field = if c.kind == "P" { "℗" } else { "©" };
+            (field, c.text.clone())
+        }));
+
+        rows.into_iter()
+            .filter(|(_, value)| !value.is_empty())
+            .map(|(field, value)| AlbumFieldRow {
+                field: field.to_string(),
+                value,
+            })
+            .collect()
+    }
+}
+
+impl OutputRecord for AlbumRecord {
+    type Row = ReleaseTableRow;
+
+    const KIND: &'static str = "album";
+
+    const CSV_HEADER: &'static [&'static str] = &[
+        "id",
+        "name",
+        "album_type",
+        "release_date",
+        "artists",
+        "artist_ids",
+        "label",
+        "upc",
+        "total_tracks",
+        "duration_ms",
+        "url",
+    ];
+
+    fn csv_fields(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.name.clone(),
+            self.album_type.clone().unwrap_or_default(),
+            self.release_date.clone(),
+            join_artists(&self.artists, |a| &a.name),
+            join_artists(&self.artists, |a| &a.id),
+            self.label.clone().unwrap_or_default(),
+            self.upc.clone().unwrap_or_default(),
+            optional(self.total_tracks),
+            optional(self.duration_ms),
+            self.url.clone().unwrap_or_default(),
+        ]
+    }
+
+    fn table_row(&self) -> ReleaseTableRow {
+        ReleaseTableRow {
+            date: self.release_date.clone(),
+            kind: self.album_type.clone().unwrap_or_default(),
+            name: self.name.clone(),
+            artists: utils::format_artists(
+                &self.artists,
+                &self.followed_artist_ids.iter().cloned().collect(),
+            ),
+        }
+    }
+}
+
+/// A track of a release in machine-readable output.
+#[derive(Debug, Clone, Serialize)]
+pub struct TrackRecord {
+    /// Spotify track ID
+    pub id: String,
+    /// Track name
+    pub name: String,
+    /// Disc the track is on, `null` if unknown
+    pub disc_number: Option<u32>,
+    /// Position on the disc, `null` if unknown
+    pub track_number: Option<u32>,
+    /// All credited artists of the track
+    pub artists: Vec<AlbumArtist>,
+    /// Track length in milliseconds, `null` if unknown
+    pub duration_ms: Option<u64>,
+    /// Whether the track has explicit lyrics
+    pub explicit: bool,
+    /// Spotify URI of the track
+    pub uri: String,
+}
+
+impl From<&Track> for TrackRecord {
+    fn from(track: &Track) -> Self {
+        Self {
+            id: track.id.clone(),
+            name: track.name.clone(),
+            disc_number: track.disc_number,
+            track_number: track.track_number,
+            artists: track.artists.clone(),
+            duration_ms: track.duration_ms,
+            explicit: track.explicit,
+            uri: track.uri.clone(),
+        }
+    }
+}
+
+impl OutputRecord for TrackRecord {
+    type Row = TrackTableRow;
+
+    const KIND: &'static str = "tracks";
+
+    const CSV_HEADER: &'static [&'static str] = &[
+        "id",
+        "name",
+        "disc_number",
+        "track_number",
+        "artists",
+        "artist_ids",
+        "duration_ms",
+        "explicit",
+        "uri",
+    ];
+
+    fn csv_fields(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.name.clone(),
+            optional(self.disc_number),
+            optional(self.track_number),
+            join_artists(&self.artists, |a| &a.name),
+            join_artists(&self.artists, |a| &a.id),
+            optional(self.duration_ms),
+            self.explicit.to_string(),
+            self.uri.clone(),
+        ]
+    }
+
+    fn table_row(&self) -> TrackTableRow {
+        let number = match (self.disc_number, self.track_number) {
+            (Some(disc), Some(track)) if disc > 1 => format!("{disc}-{track}"),
+            (_, track) => optional(track),
+        };
+        let name = if self.explicit {
+            format!("{} [E]", self.name)
+        } else {
+            self.name.clone()
+        };
+
+        TrackTableRow {
+            number,
+            name,
+            artists: utils::format_artists(&self.artists, &HashSet::new()),
+            duration: self
+                .duration_ms
+                .map(utils::format_track_length)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Renders records in the given output format.
+///
+/// # Arguments
+///
+/// * `records` - The records to render
+/// * `format` - The output format
+///
+/// # Returns
+///
+/// The rendered output without a trailing newline, or an error message if
+/// JSON serialization fails.
+///
+/// # Example
+///
+/// ```
+/// let records: Vec<ArtistRecord> = artists.iter().map(ArtistRecord::from).collect();
+/// println!("{}", render(&records, OutputFormat::Csv)?);
+/// ```
+pub fn render<R: OutputRecord>(records: &[R], format: OutputFormat) -> Result<String, String> {
+    match format {
+        OutputFormat::Table | OutputFormat::Markdown => Ok(render_table(
+            records.iter().map(OutputRecord::table_row),
+            format,
+        )),
+        OutputFormat::Json => serde_json::to_string_pretty(&Envelope {
+            version: OUTPUT_VERSION,
+            kind: R::KIND,
+            items: records,
+        })
+        .map_err(|e| e.to_string()),
+        OutputFormat::Ndjson => records
+            .iter()
+            .map(|record| serde_json::to_string(record).map_err(|e| e.to_string()))
+            .collect::<Result<Vec<_>, _>>()
+            .map(|lines| lines.join("\n")),
+        OutputFormat::Csv => {
+            let mut lines = vec![csv_line(R::CSV_HEADER.iter().map(|h| h.to_string()))];
+            lines.extend(records.iter().map(|r| csv_line(r.csv_fields())));
+            Ok(lines.join("\n"))
+        }
+    }
+}
+
+/// Renders table rows as a Unicode table or, for `markdown`, a Markdown table.
+///
+/// # Arguments
+///
+/// * `rows` - The table rows
+/// * `format` - `OutputFormat::Markdown` for Markdown, anything else for the default style
+pub fn render_table<T: Tabled>(rows: impl IntoIterator<Item = T>, format: OutputFormat) -> String {
+    let mut table = Table::new(rows);
+    if format == OutputFormat::Markdown {
+        table.with(Style::markdown());
+    }
+    table.to_string()
+}
+
+/// Formats a heading above a table, as a Markdown heading for `markdown`.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(heading("New releases", OutputFormat::Markdown), "## New releases\n");
+/// ```
+pub fn heading(title: &str, format: OutputFormat) -> String {
+    if format == OutputFormat::Markdown {
+        format!("## {title}\n")
+    } else {
+        title.to_string()
+    }
+}
+
+/// Formats an optional value, using an empty string for `None`.
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+/// Joins one field of all artists with `; `.
+fn join_artists(artists: &[AlbumArtist], field: impl Fn(&AlbumArtist) -> &String) -> String {
+    artists
+        .iter()
+        .map(|a| field(a).as_str())
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Joins fields to a CSV line, quoting fields as described in RFC 4180.
+fn csv_line(fields: impl IntoIterator<Item = String>) -> String {
+    fields
+        .into_iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
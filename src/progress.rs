@@ -0,0 +1,593 @@
+//! Progress reporting for long-running commands.
+//!
+//! Commands like `releases update` report their progress through a
+//! [`Progress`] handle, which renders differently depending on the progress
+//! mode selected with the global `--progress` flag:
+//!
+//! | Mode    | Output                                                        |
+//! |---------|---------------------------------------------------------------|
+//! | `auto`  | `bar` if stderr is a terminal, `plain` otherwise (default)    |
+//! | `bar`   | An animated spinner on stderr                                 |
+//! | `plain` | A regular status line at most every 10 seconds, e.g. for cron |
+//! | `json`  | One JSON event per line on stderr, e.g. for dashboards        |
+//!
+//! # JSON Events
+//!
+//! In `json` mode every line on stderr is a JSON object carrying the schema
+//! version, a UTC timestamp and the event name. Status messages that would
+//! normally be printed as `[o] ...` lines are emitted as `message` events, so
+//! stderr stays parseable:
+//!
+//! ```json
+//! {"version":1,"ts":"2025-08-08T06:00:00Z","event":"artist_started","artist_id":"...","artist_name":"...","position":1,"total":120}
+//! {"version":1,"ts":"2025-08-08T06:00:01Z","event":"releases_found","artist_id":"...","artist_name":"...","count":12}
+//! {"version":1,"ts":"2025-08-08T06:00:01Z","event":"artist_finished","artist_id":"...","artist_name":"...","position":1,"total":120,"status":"fetched","error":null}
+//! {"version":1,"ts":"2025-08-08T06:00:02Z","event":"rate_limit_wait","seconds":30,"reason":"throttle"}
+//! {"version":1,"ts":"2025-08-08T06:00:32Z","event":"update_finished","fetched":1,"skipped":0,"failed":0,"new_releases":3,"weeks":52,"elapsed_seconds":32,"interrupted":false}
+//! {"version":1,"ts":"2025-08-08T06:00:32Z","event":"message","kind":"success","message":"Release cache updated."}
+//! ```
+//!
+//! Like the output formats, fields are only ever added within a version;
+//! renaming or removing a field bumps [`EVENT_VERSION`].
+
+use std::{
+    io::IsTerminal,
+    sync::{
+        Mutex, OnceLock,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use chrono::Utc;
+use clap::ValueEnum;
+use indicatif::{ProgressBar, ProgressStyle};
+use serde::Serialize;
+use tokio::time::sleep;
+
+use crate::{debug, info, logging::Kind, shutdown, types::UpdateSummary};
+
+/// Version of the JSON progress event schema.
+pub const EVENT_VERSION: u32 = 1;
+
+/// Minimum time between two status lines in `plain` mode.
+const PLAIN_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Braille characters of the spinner animation.
+const TICK_CHARS: &str = "⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏";
+
+/// Total time spent in throttle waits, in milliseconds.
+static THROTTLED_MS: AtomicU64 = AtomicU64::new(0);
+
+/// The resolved process-wide progress mode, set at most once.
+static MODE: OnceLock<ProgressMode> = OnceLock::new();
+
+/// How progress of long-running commands is reported.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum ProgressMode {
+    /// Spinner on a terminal, plain status lines otherwise
+    #[default]
+    Auto,
+    /// Always draw the animated spinner
+    Bar,
+    /// Periodic plain status lines
+    Plain,
+    /// Structured JSON events, one per line
+    Json,
+}
+
+impl ProgressMode {
+    /// Resolves `auto` to `bar` or `plain`; other modes are returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_terminal` - Whether stderr, where progress is drawn, is a terminal
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(ProgressMode::Auto.resolve(false), ProgressMode::Plain);
+    /// assert_eq!(ProgressMode::Json.resolve(true), ProgressMode::Json);
+    /// ```
+    pub fn resolve(self, is_terminal: bool) -> Self {
+        match self {
+            ProgressMode::Auto if is_terminal => ProgressMode::Bar,
+            ProgressMode::Auto => ProgressMode::Plain,
+            mode => mode,
+        }
+    }
+}
+
+/// Sets the process-wide progress mode, resolving `auto` against stderr.
+///
+/// Must be called before progress is first reported; later calls are ignored.
+pub fn set_mode(mode: ProgressMode) {
+    let _ = MODE.set(mode.resolve(std::io::stderr().is_terminal()));
+}
+
+/// Returns the resolved process-wide progress mode (never `auto`).
+pub fn mode() -> ProgressMode {
+    *MODE.get_or_init(|| ProgressMode::Auto.resolve(std::io::stderr().is_terminal()))
+}
+
+/// Outcome of processing one artist.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArtistStatus {
+    /// Releases were fetched from Spotify
+    Fetched,
+    /// The artist was already processed according to the checkpoint
+    Skipped,
+    /// Fetching the releases failed
+    Failed,
+}
+
+/// Why a command is waiting before its next request.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WaitReason {
+    /// Spotify answered `429 Too Many Requests` with a `Retry-After` header
+    RetryAfter,
+    /// Spotify answered `502 Bad Gateway`
+    BadGateway,
+    /// Deliberate pause between request batches to stay below the rate limit
+    Throttle,
+    /// Backoff before retrying a request that failed with a transient error
+    Backoff,
+}
+
+impl WaitReason {
+    /// Returns a human readable description used outside of `json` mode.
+    fn describe(&self) -> &'static str {
+        match self {
+            WaitReason::RetryAfter => "rate limited by Spotify",
+            WaitReason::BadGateway => "Spotify returned 502 Bad Gateway",
+            WaitReason::Throttle => "pausing between batches",
+            WaitReason::Backoff => "backing off before retrying",
+        }
+    }
+}
+
+/// A structured progress event.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    /// Processing of an artist started
+    ArtistStarted {
+        artist_id: String,
+        artist_name: String,
+        /// 1-based position of the artist in this run
+        position: usize,
+        total: usize,
+    },
+    /// Processing of an artist finished
+    ArtistFinished {
+        artist_id: String,
+        artist_name: String,
+        /// 1-based position of the artist in this run
+        position: usize,
+        total: usize,
+        status: ArtistStatus,
+        /// Error message if the status is `failed`
+        error: Option<String>,
+    },
+    /// Releases of an artist were fetched
+    ReleasesFound {
+        artist_id: String,
+        artist_name: String,
+        count: usize,
+    },
+    /// The command waits before sending the next request
+    RateLimitWait { seconds: u64, reason: WaitReason },
+    /// A status message (`info!`, `warning!`, ...)
+    Message { kind: Kind, message: String },
+    /// A release update finished, with its summary counters
+    UpdateFinished {
+        #[serde(flatten)]
+        summary: UpdateSummary,
+        elapsed_seconds: u64,
+        /// Whether the update was stopped by Ctrl-C before all artists were processed
+        interrupted: bool,
+    },
+}
+
+/// A JSON event line: the event with version and timestamp.
+#[derive(Serialize)]
+struct EventLine<'a> {
+    /// Event schema version
+    version: u32,
+    /// UTC timestamp (RFC 3339, seconds precision)
+    ts: String,
+    /// The event itself, flattened into the line
+    #[serde(flatten)]
+    event: &'a Event,
+}
+
+/// Serializes an event to a single JSON line without trailing newline.
+///
+/// # Example
+///
+/// ```
+/// let line = event_line(&Event::RateLimitWait { seconds: 30, reason: WaitReason::Throttle });
+/// assert!(line.contains("\"event\":\"rate_limit_wait\""));
+/// ```
+pub fn event_line(event: &Event) -> String {
+    serde_json::to_string(&EventLine {
+        version: EVENT_VERSION,
+        ts: Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+        event,
+    })
+    .unwrap_or_default()
+}
+
+/// Reports a structured progress event.
+///
+/// Events are written to stderr in `json` mode only. In other modes, waits
+/// are logged as debug messages and all other events are dropped, as the
+/// progress bar or status lines already cover them.
+pub fn emit(event: Event) {
+    match (mode(), &event) {
+        (ProgressMode::Json, _) => eprintln!("{}", event_line(&event)),
+        (_, Event::RateLimitWait { seconds, reason }) => {
+            debug!("Waiting {}s: {}", seconds, reason.describe())
+        }
+        _ => {}
+    }
+}
+
+/// Reports a wait and sleeps for its duration.
+///
+/// Use this instead of a bare `sleep` before retrying or continuing requests,
+/// so dashboards can tell a waiting run from a stuck one. Throttle waits are
+/// tracked separately so the ETA of a determinate [`Progress`] can forecast
+/// them instead of averaging them into the time per item. Throttle and
+/// backoff waits end early on Ctrl-C, as the request they protect is not sent
+/// anymore; waits of a request in flight (429, 502) always run to completion
+/// so the request can finish.
+///
+/// # Arguments
+///
+/// * `duration` - How long to wait
+/// * `reason` - Why the command waits
+pub async fn wait(duration: Duration, reason: WaitReason) {
+    emit(Event::RateLimitWait {
+        seconds: duration.as_secs(),
+        reason,
+    });
+    if !matches!(reason, WaitReason::Throttle | WaitReason::Backoff) {
+        sleep(duration).await;
+        return;
+    }
+
+    let started = Instant::now();
+    tokio::select! {
+        _ = sleep(duration) => {}
+        _ = shutdown::interrupted() => {}
+    }
+    if reason == WaitReason::Throttle {
+        THROTTLED_MS.fetch_add(started.elapsed().as_millis() as u64, Ordering::Relaxed);
+    }
+}
+
+/// Returns the total time spent in throttle waits so far.
+fn throttled() -> Duration {
+    Duration::from_millis(THROTTLED_MS.load(Ordering::Relaxed))
+}
+
+/// Estimates the remaining time of an operation.
+///
+/// The time per item is measured without throttle waits, which are planned
+/// ahead and added as `planned_waits` instead. Other waits (429 and 502
+/// retries) stay in the measured time, so they are averaged into the estimate.
+///
+/// # Arguments
+///
+/// * `done` - Number of finished items
+/// * `total` - Total number of items
+/// * `elapsed` - Time since the operation started
+/// * `throttled` - Time spent in throttle waits since the operation started
+/// * `planned_waits` - Throttle waits still ahead
+///
+/// # Returns
+///
+/// The estimated remaining time, or `None` as long as no item is finished.
+///
+/// # Example
+///
+/// ```
+/// // 10 of 20 items in 40s, 20s of it throttled, one more 20s pause planned
+/// let eta = estimate_eta(10, 20, secs(40), secs(20), secs(20));
+/// assert_eq!(eta, Some(secs(40)));
+/// ```
+pub fn estimate_eta(
+    done: u64,
+    total: u64,
+    elapsed: Duration,
+    throttled: Duration,
+    planned_waits: Duration,
+) -> Option<Duration> {
+    let remaining = total.saturating_sub(done);
+    if remaining == 0 {
+        return Some(Duration::ZERO);
+    }
+    if done == 0 {
+        return None;
+    }
+
+    let per_item = elapsed.saturating_sub(throttled) / done as u32;
+    Some(per_item * remaining as u32 + planned_waits)
+}
+
+/// Formats a duration as `HH:MM:SS`, like the elapsed time of the progress bar.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(format_duration(Duration::from_secs(3725)), "01:02:05");
+/// ```
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+/// Throttling state of the `plain` mode.
+#[derive(Default)]
+struct PlainState {
+    /// When the last status line was printed
+    last_line: Option<Instant>,
+    /// The latest message that was not printed yet
+    pending: Option<String>,
+}
+
+/// ETA bookkeeping of a determinate progress.
+#[derive(Default)]
+struct EtaState {
+    /// Throttle wait total when the current phase started
+    throttled_base: Duration,
+    /// Throttle waits still ahead in the current phase
+    planned_waits: Duration,
+}
+
+/// Progress handle of one long-running operation.
+///
+/// Mirrors the parts of `indicatif::ProgressBar` used by the commands, and
+/// renders according to the process-wide [`ProgressMode`]. A progress is
+/// either a spinner ([`Progress::new`]) or a determinate bar with a known
+/// total, elapsed time and ETA ([`Progress::with_total`]).
+pub struct Progress {
+    /// The spinner or bar; hidden outside of `bar` mode but still counting
+    bar: ProgressBar,
+    /// The resolved mode
+    mode: ProgressMode,
+    /// Whether the progress has a known total
+    determinate: bool,
+    /// Throttling state for `plain` mode
+    plain: Mutex<PlainState>,
+    /// ETA bookkeeping for determinate progress
+    eta: Mutex<EtaState>,
+}
+
+impl Progress {
+    /// Starts reporting progress of an operation with an unknown total.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Initial message describing the operation
+    pub fn new(title: &str) -> Self {
+        Self::create(title, None)
+    }
+
+    /// Starts reporting progress of an operation with a known number of items.
+    ///
+    /// Renders as a bar with position, elapsed time and ETA. Call
+    /// [`Progress::inc`] for every finished item and [`Progress::start_phase`]
+    /// to continue with a different kind of items.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Initial message describing the operation
+    /// * `total` - Number of items to process
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let pb = Progress::with_total("Fetching releases...", artists.len() as u64);
+    /// for artist in artists {
+    ///     // fetch
+    ///     pb.inc(1);
+    /// }
+    /// pb.finish_and_clear();
+    /// ```
+    pub fn with_total(title: &str, total: u64) -> Self {
+        Self::create(title, Some(total))
+    }
+
+    /// Creates the progress and its (possibly hidden) bar.
+    fn create(title: &str, total: Option<u64>) -> Self {
+        let mode = mode();
+        let bar = match (mode, total) {
+            (ProgressMode::Bar, None) => {
+                let bar = ProgressBar::new_spinner();
+                bar.set_style(
+                    ProgressStyle::with_template("{spinner:.blue} {msg}")
+                        .unwrap()
+                        .tick_chars(TICK_CHARS),
+                );
+                bar
+            }
+            (ProgressMode::Bar, Some(total)) => {
+                let bar = ProgressBar::new(total);
+                bar.set_style(
+                    ProgressStyle::with_template(
+                        "{spinner:.blue} [{elapsed_precise}] {bar:30.cyan/blue} {pos}/{len} ETA {prefix} {msg}",
+                    )
+                    .unwrap()
+                    .tick_chars(TICK_CHARS)
+                    .progress_chars("=> "),
+                );
+                bar
+            }
+            (_, total) => {
+                let bar = ProgressBar::hidden();
+                bar.set_length(total.unwrap_or(0));
+                bar
+            }
+        };
+        if mode == ProgressMode::Bar {
+            bar.enable_steady_tick(Duration::from_millis(100));
+        }
+
+        let progress = Self {
+            bar,
+            mode,
+            determinate: total.is_some(),
+            plain: Mutex::new(PlainState::default()),
+            eta: Mutex::new(EtaState {
+                throttled_base: throttled(),
+                planned_waits: Duration::ZERO,
+            }),
+        };
+        progress.update_eta();
+        progress.set_message(title);
+        progress
+    }
+
+    /// Restarts the determinate progress for the next phase of the operation.
+    ///
+    /// Resets position, elapsed time and ETA, e.g. when switching from
+    /// fetching artists to writing week files.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Message describing the new phase
+    /// * `total` - Number of items of the new phase
+    pub fn start_phase(&self, title: &str, total: u64) {
+        self.bar.reset();
+        self.bar.set_length(total);
+        if let Ok(mut eta) = self.eta.lock() {
+            *eta = EtaState {
+                throttled_base: throttled(),
+                planned_waits: Duration::ZERO,
+            };
+        }
+        self.update_eta();
+        self.set_message(title);
+    }
+
+    /// Marks `delta` more items as finished.
+    pub fn inc(&self, delta: u64) {
+        self.bar.inc(delta);
+        self.update_eta();
+    }
+
+    /// Sets the throttle waits still ahead, which are added to the ETA.
+    pub fn set_planned_waits(&self, planned_waits: Duration) {
+        if let Ok(mut eta) = self.eta.lock() {
+            eta.planned_waits = planned_waits;
+        }
+        self.update_eta();
+    }
+
+    /// Returns the time since the operation (or its current phase) started.
+    pub fn elapsed(&self) -> Duration {
+        self.bar.elapsed()
+    }
+
+    /// Returns the estimated remaining time of the current phase.
+    pub fn eta(&self) -> Option<Duration> {
+        let eta = self.eta.lock().ok()?;
+        estimate_eta(
+            self.bar.position(),
+            self.bar.length().unwrap_or(0),
+            self.bar.elapsed(),
+            throttled().saturating_sub(eta.throttled_base),
+            eta.planned_waits,
+        )
+    }
+
+    /// Updates the message of the progress.
+    ///
+    /// In `plain` mode the message is printed if the last line is at least
+    /// 10 seconds old; otherwise it is kept and printed when the operation
+    /// finishes. Determinate progress prefixes plain lines with position,
+    /// elapsed time and ETA. In `json` mode messages are dropped in favour of
+    /// events.
+    pub fn set_message(&self, message: impl Into<String>) {
+        let message = message.into();
+        match self.mode {
+            ProgressMode::Plain => {
+                if let Ok(mut plain) = self.plain.lock() {
+                    if plain
+                        .last_line
+                        .is_none_or(|last| last.elapsed() >= PLAIN_INTERVAL)
+                    {
+                        plain.last_line = Some(Instant::now());
+                        plain.pending = None;
+                        info!("{}", self.plain_line(&message));
+                    } else {
+                        plain.pending = Some(message);
+                    }
+                }
+            }
+            ProgressMode::Json => {}
+            _ => self.bar.set_message(message),
+        }
+    }
+
+    /// Hides the progress while `f` runs, e.g. to print a warning.
+    pub fn suspend<F: FnOnce() -> R, R>(&self, f: F) -> R {
+        self.bar.suspend(f)
+    }
+
+    /// Stops the progress and leaves the last message on screen.
+    pub fn finish(&self) {
+        self.flush();
+        self.bar.finish();
+    }
+
+    /// Stops the progress and removes it from the screen.
+    pub fn finish_and_clear(&self) {
+        self.flush();
+        self.bar.finish_and_clear();
+    }
+
+    /// Refreshes the ETA shown as prefix of the bar.
+    fn update_eta(&self) {
+        if self.mode == ProgressMode::Bar && self.determinate {
+            self.bar.set_prefix(
+                self.eta()
+                    .map(format_duration)
+                    .unwrap_or_else(|| "--:--:--".to_string()),
+            );
+        }
+    }
+
+    /// Formats a `plain` mode line, prefixed with the counters if determinate.
+    fn plain_line(&self, message: &str) -> String {
+        if !self.determinate || self.bar.length().unwrap_or(0) == 0 {
+            return message.to_string();
+        }
+        let eta = self
+            .eta()
+            .map(format_duration)
+            .unwrap_or_else(|| "unknown".to_string());
+        format!(
+            "[{}/{}, {} elapsed, ETA {}] {}",
+            self.bar.position(),
+            self.bar.length().unwrap_or(0),
+            format_duration(self.bar.elapsed()),
+            eta,
+            message
+        )
+    }
+
+    /// Prints the pending message of `plain` mode, if any.
+    fn flush(&self) {
+        if let Ok(mut plain) = self.plain.lock()
+            && let Some(message) = plain.pending.take()
+        {
+            plain.last_line = Some(Instant::now());
+            info!("{}", self.plain_line(&message));
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! Graceful handling of Ctrl-C during long-running commands.
+//!
+//! Commands that can be interrupted safely (currently `releases update`)
+//! call [`install`] when they start. The first Ctrl-C only sets a flag: the
+//! command finishes the request in flight, stops scheduling new work, saves
+//! its checkpoint and caches and exits with [`EXIT_INTERRUPTED`]. A second
+//! Ctrl-C quits immediately with the same exit code.
+//!
+//! Commands that never call [`install`] keep the default behavior of
+//! terminating on the first Ctrl-C.
+//!
+//! # Example
+//!
+//! ```
+//! shutdown::install();
+//! for artist in artists {
+//!     if shutdown::requested() {
+//!         break;
+//!     }
+//!     // fetch releases of the artist
+//! }
+//! ```
+
+use std::sync::{
+    OnceLock,
+    atomic::{AtomicBool, Ordering},
+};
+
+use tokio::sync::Notify;
+
+use crate::warning;
+
+/// Exit code of a command that was stopped by Ctrl-C (128 + SIGINT).
+pub const EXIT_INTERRUPTED: i32 = 130;
+
+/// Whether a shutdown was requested.
+static REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// Whether the signal handler is installed.
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/// Wakes up tasks waiting in [`interrupted`].
+static NOTIFY: OnceLock<Notify> = OnceLock::new();
+
+/// Installs the Ctrl-C handler for the rest of the process.
+///
+/// Must be called from within the Tokio runtime. Calling it again has no
+/// effect.
+pub fn install() {
+    if INSTALLED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    tokio::spawn(async {
+        if tokio::signal::ctrl_c().await.is_err() {
+            return;
+        }
+        warning!("Interrupted. Finishing the current request and saving progress...");
+        warning!("Press Ctrl-C again to quit immediately.");
+        request();
+
+        if tokio::signal::ctrl_c().await.is_ok() {
+            warning!("Quit without saving progress.");
+            std::process::exit(EXIT_INTERRUPTED);
+        }
+    });
+}
+
+/// Requests a graceful shutdown, as the first Ctrl-C does.
+pub fn request() {
+    REQUESTED.store(true, Ordering::SeqCst);
+    notify().notify_waiters();
+}
+
+/// Returns `true` once a graceful shutdown was requested.
+pub fn requested() -> bool {
+    REQUESTED.load(Ordering::SeqCst)
+}
+
+/// Completes once a graceful shutdown is requested.
+///
+/// Used to cut waits short, e.g. in `tokio::select!` next to a sleep.
+pub async fn interrupted() {
+    let notified = notify().notified();
+    if requested() {
+        return;
+    }
+    notified.await;
+}
+
+/// Returns the notifier shared by [`request`] and [`interrupted`].
+fn notify() -> &'static Notify {
+    NOTIFY.get_or_init(Notify::new)
+}
@@ -1,12 +1,13 @@
 use std::time::Duration;
 
 use reqwest::{Client, StatusCode};
-use tokio::time::sleep;
 
 use crate::{
     config, error,
     management::TokenManager,
-    types::{Artist, FollowedArtistsResponse},
+    progress::{self, WaitReason},
+    spotify::RequestExt,
+    types::{Artist, FollowedArtistsResponse, GetSeveralArtistsResponse},
     utils,
 };
 
@@ -67,7 +68,7 @@ pub async fn get_artist(
         }
 
         let client = Client::new();
-        let response = client.get(&api_url).bearer_auth(token).send().await;
+        let response = client.get(&api_url).bearer_auth(token).send_logged().await;
 
         let response = match response {
             Ok(resp) => match resp.error_for_status() {
@@ -75,7 +76,7 @@ pub async fn get_artist(
                 Err(err) => {
                     if let Some(status) = err.status() {
                         if status == StatusCode::BAD_GATEWAY {
-                            sleep(Duration::from_secs(10)).await;
+                            progress::wait(Duration::from_secs(10), WaitReason::BadGateway).await;
                             continue; // retry
                         }
                     }
@@ -158,7 +159,7 @@ pub async fn get_total_artist_count() -> Result<u64, reqwest::Error> {
         let api_url = format!("{uri}/me/following?type={type}&limit={limit}", uri = &config::spotify_apiurl(), type = "artist", limit = "1");
 
         let client = Client::new();
-        let response = client.get(&api_url).bearer_auth(token).send().await;
+        let response = client.get(&api_url).bearer_auth(token).send_logged().await;
 
         let response = match response {
             Ok(resp) => match resp.error_for_status() {
@@ -166,7 +167,7 @@ pub async fn get_total_artist_count() -> Result<u64, reqwest::Error> {
                 Err(err) => {
                     if let Some(status) = err.status() {
                         if status == StatusCode::BAD_GATEWAY {
-                            sleep(Duration::from_secs(10)).await;
+                            progress::wait(Duration::from_secs(10), WaitReason::BadGateway).await;
                             continue; // retry
                         }
                     }
@@ -187,3 +188,79 @@ pub async fn get_total_artist_count() -> Result<u64, reqwest::Error> {
         return Ok(res.artists.total.unwrap_or_else(|| 0));
     }
 }
+
+/// Retrieves full artist objects for up to 50 artists in a single request.
+///
+/// Uses Spotify's several-artists endpoint to fetch current metadata such as
+/// genres, popularity, follower count, images and external URLs. Artists that
+/// no longer exist are answered with `null` by Spotify and are left out of the
+/// result.
+///
+/// # Arguments
+///
+/// * `token` - Valid access token for Spotify API authentication
+/// * `ids` - Spotify artist IDs to look up (at most 50)
+///
+/// # Returns
+///
+/// Returns a `Result` containing:
+/// - `Ok(Vec<Artist>)` - The artists that were found
+/// - `Err(reqwest::Error)` - Network error, API error, or other HTTP-related error
+///
+/// # Retry Logic
+///
+/// Retries after 10 seconds on 502 Bad Gateway responses and honours the
+/// `Retry-After` header of 429 Too Many Requests responses up to 120 seconds.
+///
+/// # Example
+///
+/// ```
+/// let ids = vec!["4NHQUGzhtTLFvgF5SZesLK".to_string()];
+/// let artists = get_several_artists(&token, &ids).await?;
+/// ```
+pub async fn get_several_artists(
+    token: &str,
+    ids: &[String],
+) -> Result<Vec<Artist>, reqwest::Error> {
+    let api_url = format!(
+        "{uri}/artists?ids={ids}",
+        uri = &config::spotify_apiurl(),
+        ids = ids.join(",")
+    );
+
+    loop {
+        let client = Client::new();
+        let response = client
+            .get(&api_url)
+            .bearer_auth(token)
+            .send_logged()
+            .await?;
+
+        if response.status() == StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = response
+                .headers()
+                .get("retry-after")
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse::<u64>().ok())
+                .unwrap_or(0);
+            if retry_after <= 120 {
+                progress::wait(Duration::from_secs(retry_after), WaitReason::RetryAfter).await;
+                continue; // retry
+            }
+        }
+
+        let response = match response.error_for_status() {
+            Ok(valid_response) => valid_response,
+            Err(err) => {
+                if err.status() == Some(StatusCode::BAD_GATEWAY) {
+                    progress::wait(Duration::from_secs(10), WaitReason::BadGateway).await;
+                    continue; // retry
+                }
+                return Err(err); // propagate other errors
+            }
+        };
+
+        let res = response.json::<GetSeveralArtistsResponse>().await?;
+        return Ok(res.artists.into_iter().flatten().collect());
+    }
+}
@@ -9,6 +9,7 @@ use crate::{
     config, error,
     management::TokenManager,
     server::start_api_server,
+    spotify::RequestExt,
     success,
     types::{PkceToken, Token},
     utils, warning,
@@ -234,7 +235,7 @@ pub async fn refresh_token(refresh_token: &str) -> Result<Token, String> {
             ("refresh_token", refresh_token),
             ("client_id", &config::spotify_client_id()),
         ])
-        .send()
+        .send_logged()
         .await
         .map_err(|e| e.to_string())?;
 
@@ -320,7 +321,7 @@ pub async fn exchange_code_pkce(code: &str, verifier: &str) -> Result<Token, req
             ("code_verifier", verifier),
             ("redirect_uri", redirect_uri),
         ])
-        .send()
+        .send_logged()
         .await?;
 
     let json: Value = res.json().await?;
@@ -47,6 +47,7 @@
 //! [`artists`] - Handles artist-related API operations:
 //! - **Followed Artists**: Retrieval of user's followed artists with pagination support
 //! - **Artist Counting**: Efficient total count queries without full data transfer
+//! - **Metadata Refresh**: Batch lookup of current artist metadata, 50 IDs per request
 //! - **Cursor Pagination**: Handles Spotify's cursor-based pagination system
 //! - **Rate Limiting**: Intelligent retry logic for API rate limits
 //!
@@ -134,6 +135,7 @@
 //! - `GET /me/playlists` - User's playlists for duplicate checking
 //!
 //! ### Artist Information
+//! - `GET /artists` - Batch artist metadata (genres, popularity, followers, images)
 //! - `GET /artists/{id}/albums` - Artist's discography with filtering
 //!
 //! ### Album Details
@@ -229,3 +231,154 @@ pub mod artists;
 pub mod auth;
 pub mod playlist;
 pub mod releases;
+
+use std::{
+    future::Future,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Instant,
+};
+
+use reqwest::{RequestBuilder, Response, StatusCode};
+
+use crate::{debug, error, logging, trace};
+
+/// Whether requests to Spotify are refused (`--offline`).
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables offline mode for the rest of the process.
+///
+/// In offline mode [`RequestExt::send_logged`] refuses every request, so a
+/// command is guaranteed to only use the local cache.
+pub fn set_offline(offline: bool) {
+    OFFLINE.store(offline, Ordering::Relaxed);
+}
+
+/// Returns `true` if requests to Spotify are refused (`--offline`).
+pub fn is_offline() -> bool {
+    OFFLINE.load(Ordering::Relaxed)
+}
+
+/// Exits with a clear error if the given action needs Spotify in offline mode.
+///
+/// Commands that cannot work without Spotify call this before touching any
+/// cache, so they fail before doing partial work.
+///
+/// # Arguments
+///
+/// * `action` - What the command tries to do, e.g. `"Updating the release cache"`
+///
+/// # Example
+///
+/// ```
+/// spotify::ensure_online("Creating playlists");
+/// ```
+pub fn ensure_online(action: &str) {
+    if is_offline() {
+        error!(
+            "{} needs Spotify, but offline mode is enabled (--offline/SPORLCLI_OFFLINE).",
+            action
+        );
+    }
+}
+
+/// Sends Spotify API requests with debug logging.
+///
+/// Every request of the Spotify layer goes through [`RequestExt::send_logged`],
+/// which logs a one-line summary of each request and response at debug level
+/// (`-v`), e.g. `GET /v1/me/following?type=artist&limit=50 -> 200 OK in 182 ms`.
+/// Request bodies, form parameters and tokens are never logged.
+///
+/// In offline mode (see [`set_offline`]) no request is sent; the command
+/// exits with an error naming the refused request instead.
+pub(crate) trait RequestExt {
+    /// Sends the request like `RequestBuilder::send`, logging a summary.
+    fn send_logged(self) -> impl Future<Output = Result<Response, reqwest::Error>> + Send;
+}
+
+impl RequestExt for RequestBuilder {
+    async fn send_logged(self) -> Result<Response, reqwest::Error> {
+        let (client, request) = self.build_split();
+        let request = request?;
+
+        let method = request.method().clone();
+        let target = match request.url().query() {
+            Some(query) => format!("{}?{}", request.url().path(), query),
+            None => request.url().path().to_string(),
+        };
+        if is_offline() {
+            error!(
+                "Offline mode (--offline/SPORLCLI_OFFLINE): refusing to send {} {}.",
+                method, target
+            );
+        }
+        trace!("Sending {} {}", method, request.url());
+
+        let started = Instant::now();
+        let result = client.execute(request).await;
+        if logging::enabled(logging::Level::Debug) {
+            let elapsed = started.elapsed().as_millis();
+            match &result {
+                Ok(response) => debug!(
+                    "{} {} -> {} in {} ms{}",
+                    method,
+                    target,
+                    response.status(),
+                    elapsed,
+                    response
+                        .content_length()
+                        .map(|len| format!(" ({} bytes)", len))
+                        .unwrap_or_default()
+                ),
+                Err(e) => debug!("{} {} failed after {} ms: {}", method, target, elapsed, e),
+            }
+        }
+        result
+    }
+}
+
+/// How a failed Spotify request should be handled by callers that retry.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ErrorClass {
+    /// Retrying later may succeed: rate limits, server errors, network problems
+    /// and expired tokens
+    Transient,
+    /// Retrying will fail again: unknown or deleted IDs, forbidden resources
+    /// and responses that cannot be decoded
+    Permanent,
+}
+
+impl ErrorClass {
+    /// Classifies an HTTP error status.
+    ///
+    /// `401`, `408`, `429` and all `5xx` statuses are transient; every other
+    /// status (e.g. `400` for an invalid ID or `404` for a deleted artist) is
+    /// permanent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(ErrorClass::from_status(StatusCode::NOT_FOUND), ErrorClass::Permanent);
+    /// assert_eq!(ErrorClass::from_status(StatusCode::BAD_GATEWAY), ErrorClass::Transient);
+    /// ```
+    pub fn from_status(status: StatusCode) -> Self {
+        match status {
+            StatusCode::UNAUTHORIZED
+            | StatusCode::REQUEST_TIMEOUT
+            | StatusCode::TOO_MANY_REQUESTS => ErrorClass::Transient,
+            status if status.is_server_error() => ErrorClass::Transient,
+            _ => ErrorClass::Permanent,
+        }
+    }
+
+    /// Classifies a request error by its HTTP status or, without one, its cause.
+    ///
+    /// Undecodable responses are permanent; connection problems, timeouts and
+    /// other transport errors are transient.
+    pub fn of(error: &reqwest::Error) -> Self {
+        match error.status() {
+            Some(status) => Self::from_status(status),
+            None if error.is_decode() => ErrorClass::Permanent,
+            None => ErrorClass::Transient,
+        }
+    }
+}
@@ -1,11 +1,12 @@
 use std::time::Duration;
 
 use reqwest::{Client, StatusCode};
-use tokio::time::sleep;
 
 use crate::{
     config, error,
     management::TokenManager,
+    progress::{self, WaitReason},
+    spotify::RequestExt,
     types::{
         AddTrackToPlaylistRequest, AddTrackToPlaylistResponse, CreatePlaylistRequest,
         CreatePlaylistResponse, GetUserPlaylistsResponse, Track,
@@ -93,7 +94,7 @@ pub async fn create(name: String) -> Result<CreatePlaylistResponse, reqwest::Err
                 public: false,
                 collaborative: false
             }))
-            .send()
+            .send_logged()
             .await;
 
         let response = match response {
@@ -102,7 +103,7 @@ pub async fn create(name: String) -> Result<CreatePlaylistResponse, reqwest::Err
                 Err(err) => {
                     if let Some(status) = err.status() {
                         if status == StatusCode::BAD_GATEWAY {
-                            sleep(Duration::from_secs(10)).await;
+                            progress::wait(Duration::from_secs(10), WaitReason::BadGateway).await;
                             continue; // retry
                         }
                     }
@@ -192,7 +193,7 @@ pub async fn exists(playlist_name: &str) -> Result<bool, reqwest::Error> {
     loop {
         let client = Client::new();
         let token = token_mgr.get_valid_token().await;
-        let response = client.get(&api_url).bearer_auth(token).send().await;
+        let response = client.get(&api_url).bearer_auth(token).send_logged().await;
 
         let response = match response {
             Ok(resp) => match resp.error_for_status() {
@@ -200,7 +201,7 @@ pub async fn exists(playlist_name: &str) -> Result<bool, reqwest::Error> {
                 Err(err) => {
                     if let Some(status) = err.status() {
                         if status == StatusCode::BAD_GATEWAY {
-                            sleep(Duration::from_secs(10)).await;
+                            progress::wait(Duration::from_secs(10), WaitReason::BadGateway).await;
                             continue; // retry
                         }
                     }
@@ -325,7 +326,7 @@ pub async fn add_tracks(
             .json(&serde_json::json!(AddTrackToPlaylistRequest {
                 uris: tracks.iter().map(|track| track.uri.clone()).collect()
             }))
-            .send()
+            .send_logged()
             .await;
 
         let response = match response {
@@ -334,7 +335,7 @@ pub async fn add_tracks(
                 Err(err) => {
                     if let Some(status) = err.status() {
                         if status == StatusCode::BAD_GATEWAY {
-                            sleep(Duration::from_secs(10)).await;
+                            progress::wait(Duration::from_secs(10), WaitReason::BadGateway).await;
                             continue; // retry
                         }
                     }
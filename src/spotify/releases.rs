@@ -1,12 +1,13 @@
 use std::time::Duration;
 
 use reqwest::{Client, StatusCode};
-use tokio::time::sleep;
 
 use crate::{
     config, error,
     management::TokenManager,
-    types::{Album, AlbumResponse, GetSeveralAlbumsResponse},
+    progress::{self, WaitReason},
+    spotify::RequestExt,
+    types::{Album, AlbumResponse, GetAlbumResponse, GetSeveralAlbumsResponse, Tracks},
     utils, warning,
 };
 
@@ -26,7 +27,8 @@ use crate::{
 /// # Returns
 ///
 /// Returns a `Result` containing:
-/// - `Ok(Vec<Album>)` - List of albums matching the criteria
+/// - `Ok(AlbumResponse)` - The first page of albums matching the criteria;
+///   [`AlbumResponse::is_complete`] tells whether it holds all of them
 /// - `Err(reqwest::Error)` - Network error, API error, or HTTP error
 ///
 /// # Rate Limiting
@@ -56,7 +58,8 @@ use crate::{
 ///
 /// - Rate limit responses are handled automatically with retry logic
 /// - Network errors are propagated to the caller
-/// - Invalid artist IDs result in API errors that are propagated
+/// - Error statuses (e.g. 404 for a deleted artist) are returned as errors
+///   carrying the status, see [`super::ErrorClass`]
 /// - Malformed responses are handled by reqwest's JSON parsing
 ///
 /// # Example
@@ -66,14 +69,14 @@ use crate::{
 /// let token = "BQC..."; // Valid access token
 /// let release_types = utils::parse_release_kinds("album,single")?;
 ///
-/// let albums = get_release_for_artist(
+/// let response = get_release_for_artist(
 ///     artist_id.to_string(),
 ///     token,
 ///     20,
 ///     &release_types
 /// ).await?;
 ///
-/// println!("Found {} releases", albums.len());
+/// println!("Found {} of {} releases", response.items.len(), response.total);
 /// ```
 ///
 /// # Performance Notes
@@ -87,39 +90,45 @@ pub async fn get_release_for_artist(
     token: &str,
     limit: u32,
     release_types: &utils::ReleaseKinds,
-) -> Result<Vec<Album>, reqwest::Error> {
+) -> Result<AlbumResponse, reqwest::Error> {
     let client = Client::new();
     let api_url = format!(
         "{uri}/artists/{id}/albums?include_groups={include_groups}&limit={limit}",
         uri = &config::spotify_apiurl(),
         id = artist_id,
-        include_groups = format!("{}", release_types),
+        include_groups = release_types.fetch_groups(),
         limit = limit
     );
 
-    let response = client.get(&api_url).bearer_auth(token).send().await?;
-    // check for retry-after header
-    if response.status() == StatusCode::TOO_MANY_REQUESTS {
-        if let Some(retry_after) = response.headers().get("retry-after") {
+    loop {
+        let response = client
+            .get(&api_url)
+            .bearer_auth(token)
+            .send_logged()
+            .await?;
+
+        // check for retry-after header
+        if response.status() == StatusCode::TOO_MANY_REQUESTS
+            && let Some(retry_after) = response.headers().get("retry-after")
+        {
             let retry_after = retry_after
                 .to_str()
                 .unwrap_or("0")
                 .parse::<u64>()
                 .unwrap_or(0);
             if retry_after <= 120 {
-                sleep(Duration::from_secs(retry_after)).await;
-            } else {
-                warning!(
-                    "Retry after has reached a abnormal high of {} seconds. Try your best tommorrow again.",
-                    retry_after
-                );
+                progress::wait(Duration::from_secs(retry_after), WaitReason::RetryAfter).await;
+                continue; // retry
             }
+            warning!(
+                "Retry after has reached a abnormal high of {} seconds. Try your best tommorrow again.",
+                retry_after
+            );
         }
-    }
 
-    let json = response.json::<AlbumResponse>().await?;
-
-    Ok(json.items)
+        // surface the status (e.g. 404 for a deleted artist) instead of a decode error
+        return response.error_for_status()?.json::<AlbumResponse>().await;
+    }
 }
 
 /// Retrieves detailed information for multiple albums in a single API request.
@@ -203,11 +212,28 @@ pub async fn get_release_for_artist(
 pub async fn get_several_releases(
     albums: &Vec<Album>,
 ) -> Result<GetSeveralAlbumsResponse, reqwest::Error> {
-    let album_ids = albums
-        .iter()
-        .map(|a| a.id.as_str())
-        .collect::<Vec<_>>()
-        .join(",");
+    let album_ids: Vec<&str> = albums.iter().map(|a| a.id.as_str()).collect();
+    get_several_albums(&album_ids).await
+}
+
+/// Retrieves detailed information for several albums by their IDs.
+///
+/// Same as [`get_several_releases`], for callers that only know album IDs.
+/// Unknown IDs are left out of the response.
+///
+/// # Arguments
+///
+/// * `album_ids` - Up to 20 Spotify album IDs
+///
+/// # Example
+///
+/// ```
+/// let response = get_several_albums(&["4LH4d3cOWNNsVw41Gqt2kv"]).await?;
+/// ```
+pub async fn get_several_albums(
+    album_ids: &[&str],
+) -> Result<GetSeveralAlbumsResponse, reqwest::Error> {
+    let album_ids = album_ids.join(",");
 
     let api_url = format!(
         "{url}/albums?ids={album_ids}",
@@ -228,7 +254,7 @@ pub async fn get_several_releases(
     loop {
         let client = Client::new();
         let token = token_mgr.get_valid_token().await;
-        let response = client.get(&api_url).bearer_auth(token).send().await;
+        let response = client.get(&api_url).bearer_auth(token).send_logged().await;
 
         let response = match response {
             Ok(resp) => match resp.error_for_status() {
@@ -236,7 +262,7 @@ pub async fn get_several_releases(
                 Err(err) => {
                     if let Some(status) = err.status() {
                         if status == StatusCode::BAD_GATEWAY {
-                            sleep(Duration::from_secs(10)).await;
+                            progress::wait(Duration::from_secs(10), WaitReason::BadGateway).await;
                             continue; // retry
                         }
                     }
@@ -253,3 +279,62 @@ pub async fn get_several_releases(
         return Ok(json);
     }
 }
+
+/// Fetches the tracks of an album that Spotify did not embed.
+///
+/// Album responses only embed the first page of tracks. This follows the
+/// `next` links of the album tracks endpoint until every track is listed.
+///
+/// # Arguments
+///
+/// * `album` - Album response whose track list is completed in place
+///
+/// # Retry Logic
+///
+/// Retries 502 Bad Gateway errors after 10 seconds, like [`get_several_albums`].
+///
+/// # Example
+///
+/// ```
+/// let mut album = get_several_albums(&[album_id]).await?.albums.remove(0);
+/// get_remaining_album_tracks(&mut album).await?;
+/// assert!(album.has_all_tracks());
+/// ```
+pub async fn get_remaining_album_tracks(
+    album: &mut GetAlbumResponse,
+) -> Result<(), reqwest::Error> {
+    let mut token_mgr = match TokenManager::load().await {
+        Ok(manager) => manager,
+        Err(e) => {
+            error!(
+                "Failed to load token. Please run sporlcli auth\n Error: {}",
+                e
+            );
+        }
+    };
+
+    let client = Client::new();
+    while let Some(api_url) = album.tracks.next.clone() {
+        let token = token_mgr.get_valid_token().await;
+        let response = match client
+            .get(&api_url)
+            .bearer_auth(token)
+            .send_logged()
+            .await?
+            .error_for_status()
+        {
+            Ok(response) => response,
+            Err(err) if err.status() == Some(StatusCode::BAD_GATEWAY) => {
+                progress::wait(Duration::from_secs(10), WaitReason::BadGateway).await;
+                continue; // retry
+            }
+            Err(err) => return Err(err),
+        };
+
+        let page = response.json::<Tracks>().await?;
+        album.tracks.items.extend(page.items);
+        album.tracks.next = page.next;
+    }
+
+    Ok(())
+}
@@ -1,7 +1,11 @@
 use chrono::NaiveDate;
+use clap::{ArgAction, Args, ValueEnum};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use tabled::Tabled;
 
+use crate::utils::{self, EpRule, GenrePattern, ReleaseKind, ReleaseKinds};
+
 /// Represents an OAuth access token with refresh capabilities.
 ///
 /// Contains all the necessary information for authenticating with the Spotify API,
@@ -34,11 +38,13 @@ pub struct PkceToken {
     pub token: Option<Token>,
 }
 
-/// Represents a Spotify artist with basic information and genre classification.
+/// Represents a Spotify artist with metadata and genre classification.
 ///
-/// Contains the essential information about an artist including their unique identifier,
-/// display name, and associated genres. This is used throughout the application
-/// when working with artist data from the Spotify API.
+/// Contains the information about an artist as returned by Spotify's artist
+/// objects, including popularity, follower count, images and external URLs.
+/// All metadata beyond id, name and genres is optional so that caches written
+/// by older versions keep loading. `refreshed_at` records when the metadata
+/// was last fetched from Spotify.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Artist {
     /// Unique Spotify identifier for the artist
@@ -46,7 +52,49 @@ pub struct Artist {
     /// Display name of the artist
     pub name: String,
     /// List of genres associated with the artist
+    #[serde(default)]
     pub genres: Vec<String>,
+    /// Popularity of the artist between 0 and 100
+    #[serde(default)]
+    pub popularity: Option<u32>,
+    /// Follower information for the artist
+    #[serde(default)]
+    pub followers: Option<Followers>,
+    /// Artist images in various sizes, widest first
+    #[serde(default)]
+    pub images: Vec<Image>,
+    /// Known external URLs for the artist
+    #[serde(default)]
+    pub external_urls: ExternalUrls,
+    /// Unix timestamp when the metadata was last fetched from Spotify
+    #[serde(default)]
+    pub refreshed_at: Option<u64>,
+}
+
+/// Follower information of a Spotify artist.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Followers {
+    /// Total number of followers
+    pub total: u64,
+}
+
+/// Represents an image hosted by Spotify.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Image {
+    /// Source URL of the image
+    pub url: String,
+    /// Image height in pixels, if known
+    pub height: Option<u32>,
+    /// Image width in pixels, if known
+    pub width: Option<u32>,
+}
+
+/// External URLs of a Spotify object.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExternalUrls {
+    /// The Spotify web player URL of the object
+    #[serde(default)]
+    pub spotify: Option<String>,
 }
 
 /// Represents an artist row for table display purposes.
@@ -60,6 +108,24 @@ pub struct ArtistTableRow {
     pub name: String,
     /// Comma-separated string of artist's genres
     pub genres: String,
+    /// Popularity between 0 and 100, empty if unknown
+    pub popularity: String,
+    /// Number of followers, empty if unknown
+    pub followers: String,
+}
+
+/// Represents the artist counts of `sporlcli info --artists` for table display.
+///
+/// Unknown values (e.g. the remote count in offline mode) are empty strings.
+#[derive(Tabled)]
+pub struct ArtistCountTableRow {
+    /// Number of artists in the local cache
+    pub cache: String,
+    /// Number of artists followed on Spotify, empty if not checked
+    pub remote: String,
+    /// When the artist cache was last written, empty if unknown
+    #[tabled(rename = "cache updated")]
+    pub cache_updated_at: String,
 }
 
 /// Response structure for Spotify's followed artists API endpoint.
@@ -89,6 +155,16 @@ pub struct ArtistsContainer {
     pub total: Option<u64>,
 }
 
+/// Response structure for Spotify's several artists API endpoint.
+///
+/// Returned by `GET /artists?ids=...`. Unknown IDs are answered with `null`
+/// entries, which is why each artist is optional.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetSeveralArtistsResponse {
+    /// Artist objects in the order of the requested IDs
+    pub artists: Vec<Option<Artist>>,
+}
+
 /// Cursor-based pagination information for API responses.
 ///
 /// Provides cursor information for navigating through paginated API responses.
@@ -102,12 +178,25 @@ pub struct Cursors {
 
 /// Response structure for album-related API endpoints.
 ///
-/// A simple wrapper around a list of albums, used when the API returns
-/// multiple albums without additional pagination or metadata.
+/// A page of albums together with the pagination information needed to
+/// tell whether the page holds all albums matching the request.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AlbumResponse {
     /// List of album objects
     pub items: Vec<Album>,
+    /// URL of the next page, `None` on the last page
+    #[serde(default)]
+    pub next: Option<String>,
+    /// Total number of albums matching the request
+    #[serde(default)]
+    pub total: u32,
+}
+
+impl AlbumResponse {
+    /// Returns `true` if this page contains every album matching the request.
+    pub fn is_complete(&self) -> bool {
+        self.next.is_none()
+    }
 }
 
 /// Represents a Spotify album with release information and artist details.
@@ -127,8 +216,31 @@ pub struct Album {
     pub release_date_precision: String,
     /// Type of album (album, single, compilation, etc.)
     pub album_type: String,
+    /// Release group the album was fetched with (album, single, compilation, appears_on)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub album_group: Option<String>,
     /// List of artists associated with the album
     pub artists: Vec<AlbumArtist>,
+    /// Number of tracks, `None` for releases cached before it was stored
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_tracks: Option<u32>,
+    /// Total duration in milliseconds, only known once the full album was fetched
+    /// (not part of Spotify's album listing)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+    /// Unix timestamp when sporlcli first saw this release (not part of Spotify's data)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_seen_at: Option<u64>,
+}
+
+impl Album {
+    /// Returns `true` if the release date has day precision.
+    ///
+    /// Spotify lists some releases (often reissues or small-label releases)
+    /// only with a month (`2024-10`) or a year (`2024`).
+    pub fn has_exact_date(&self) -> bool {
+        self.release_date_precision == "day"
+    }
 }
 
 /// Represents an artist and their associated releases.
@@ -143,6 +255,210 @@ pub struct ArtistReleases {
     pub releases: Vec<Album>,
 }
 
+/// Result of synchronizing the cached artists with the followed artists on Spotify.
+///
+/// Produced by comparing both sides by artist ID. Newly followed artists are
+/// listed in `added`, unfollowed artists are handed back together with their
+/// cached releases in `removed` so the caller can decide whether to archive
+/// or drop them.
+#[derive(Debug, Clone, Default)]
+pub struct ArtistSyncSummary {
+    /// Artists that are followed on Spotify but were missing from the cache
+    pub added: Vec<Artist>,
+    /// Cached artists (with their releases) that are no longer followed
+    pub removed: Vec<ArtistReleases>,
+    /// Number of artists present on both sides
+    pub unchanged: usize,
+}
+
+impl ArtistSyncSummary {
+    /// Returns `true` if the sync neither added nor removed any artist.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// A completed `releases update` run.
+///
+/// Stored in the update history so that later commands can tell which
+/// releases were discovered since the previous successful update.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateRun {
+    /// Unix timestamp when the run started
+    pub started_at: u64,
+    /// Unix timestamp when the run finished successfully
+    pub finished_at: u64,
+    /// Release types the run fetched (e.g. "album,single")
+    pub release_types: String,
+}
+
+/// An artist whose releases could not be fetched during `releases update`.
+///
+/// Kept in the retry queue until a later run (or `releases update
+/// --retry-failed`) fetches the artist successfully.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FailedArtist {
+    /// Spotify ID of the artist
+    pub artist_id: String,
+    /// Artist name at the time of the failure
+    pub artist_name: String,
+    /// Error message of the last attempt
+    pub error: String,
+    /// Whether the last error is permanent (e.g. 404), so retrying is unlikely to help
+    pub permanent: bool,
+    /// Number of failed runs for this artist
+    pub attempts: u32,
+    /// Unix timestamp of the last failed attempt
+    pub last_failed_at: u64,
+}
+
+/// How an [`ArtistRule`] treats the releases of its artist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArtistRuleMode {
+    /// Hide the artist's releases of the rule's types, or all of them without types
+    Mute,
+    /// Only show the artist's releases of the rule's types
+    Allow,
+}
+
+/// A local rule that hides releases of a followed artist from listings and playlists.
+///
+/// Set with `sporlcli artists mute` and `sporlcli artists allow`. Rules never
+/// affect what `releases update` fetches and caches.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArtistRule {
+    /// Spotify ID of the artist
+    pub artist_id: String,
+    /// Artist name at the time the rule was set
+    pub artist_name: String,
+    /// Whether the types are hidden or the only ones shown
+    pub mode: ArtistRuleMode,
+    /// Release types the rule applies to; empty mutes the artist completely
+    #[serde(default)]
+    pub types: Vec<ReleaseKind>,
+}
+
+impl ArtistRule {
+    /// Returns `true` if the rule lets the release through.
+    ///
+    /// # Arguments
+    ///
+    /// * `album` - A release of the rule's artist
+    /// * `ep_rule` - Thresholds that turn a single into an EP
+    pub fn permits(&self, album: &Album, ep_rule: &EpRule) -> bool {
+        let kinds = ReleaseKinds(self.types.iter().copied().collect());
+        match self.mode {
+            ArtistRuleMode::Mute => !self.types.is_empty() && !kinds.matches(album, ep_rule),
+            ArtistRuleMode::Allow => kinds.matches(album, ep_rule),
+        }
+    }
+
+    /// Describes the rule for listings, e.g. `muted` or `only album, ep`.
+    pub fn describe(&self) -> String {
+        let types = self
+            .types
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        match (self.mode, self.types.is_empty()) {
+            (ArtistRuleMode::Mute, true) => "muted".to_string(),
+            (ArtistRuleMode::Mute, false) => format!("muted: {types}"),
+            (ArtistRuleMode::Allow, _) => format!("only {types}"),
+        }
+    }
+}
+
+/// A release left out of a listing or playlist, together with the reason.
+///
+/// Shown with `--show-hidden`.
+#[derive(Debug, Clone)]
+pub struct HiddenRelease {
+    /// The hidden release
+    pub album: Album,
+    /// Why it is hidden, e.g. `title matches '*(Live)'`
+    pub reason: String,
+}
+
+/// Represents a hidden release row for table display purposes.
+#[derive(Tabled)]
+pub struct HiddenReleaseTableRow {
+    /// Release date formatted as a string
+    pub date: String,
+    /// Release type, e.g. `single` or `album (appears on)`
+    #[tabled(rename = "type")]
+    pub kind: String,
+    /// Album/release name
+    pub name: String,
+    /// All credited artists separated by `; `, followed ones marked with `*`
+    pub artists: String,
+    /// Why the release is hidden
+    pub reason: String,
+}
+
+/// Represents an artist rule row for table display purposes.
+#[derive(Tabled)]
+pub struct ArtistRuleTableRow {
+    /// Artist name
+    pub artist: String,
+    /// Spotify ID of the artist
+    pub id: String,
+    /// Human readable rule, e.g. `muted` or `only album`
+    pub rule: String,
+}
+
+/// Counters of a `releases update` run, printed as summary table at the end.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Tabled)]
+pub struct UpdateSummary {
+    /// Artists whose releases were fetched from Spotify
+    #[tabled(rename = "fetched")]
+    pub fetched: usize,
+    /// Artists skipped because the checkpoint marks them as done
+    #[tabled(rename = "skipped (checkpoint)")]
+    pub skipped: usize,
+    /// Artists whose releases could not be fetched (added to the retry queue)
+    #[tabled(rename = "failed")]
+    pub failed: usize,
+    /// Releases that were not in the cache before
+    #[tabled(rename = "new releases")]
+    pub new_releases: usize,
+    /// Week files written
+    #[tabled(rename = "weeks")]
+    pub weeks: usize,
+}
+
+/// Parameters a release update run was started with.
+///
+/// Stored in the checkpoint so that an interrupted run is only resumed with
+/// the same settings it was started with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunParams {
+    /// Release types the run fetches (e.g. "album,single")
+    pub release_types: String,
+    /// Whether the run was started with `--force`
+    pub force: bool,
+}
+
+/// Checkpoint of a (possibly interrupted) release update run.
+///
+/// Written to the state directory while `releases update` is running and
+/// removed once the run completes. Legacy checkpoints, which only stored
+/// the processed artist IDs, are loaded without `params`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// Identifier of the run that owns this checkpoint
+    pub run_id: String,
+    /// Parameters of the run, `None` for legacy checkpoints
+    pub params: Option<RunParams>,
+    /// Unix timestamp when the run started
+    pub started_at: u64,
+    /// IDs of successfully processed items
+    pub completed: BTreeSet<String>,
+    /// IDs of failed items with the error message of their last attempt
+    pub failed: BTreeMap<String, String>,
+}
+
 /// Represents an artist in the context of an album.
 ///
 /// A simplified artist representation used specifically within album contexts.
@@ -161,14 +477,230 @@ pub struct AlbumArtist {
 /// Defines a specific week by its number and contains all the individual dates
 /// that fall within that week. Used for organizing releases by weekly periods
 /// in the music release tracking system.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct WeekOfTheYear {
     /// Week number within the year (1-based)
     pub week: u32,
+    /// Release year the week number belongs to (the year of the week's first day)
+    ///
+    /// A week spanning New Year belongs to the year it starts in, so this can
+    /// differ from the calendar year of some of its dates.
+    pub year: i32,
     /// All dates that fall within this week
     pub dates: Vec<NaiveDate>,
 }
 
+/// Command-line arguments selecting one or more release weeks.
+///
+/// Shared by `releases`, `playlist` and `info`. A week can be addressed
+/// directly (`--week 2025/32` or `--week 2025-W32`), by a date range
+/// (`--from`/`--to`) or relative to a date (`--release-date` together with
+/// `--previous-weeks`). All selections are resolved by
+/// `utils::resolve_release_weeks`.
+#[derive(Args, Debug, Clone, Default)]
+pub struct WeekSelection {
+    /// Number of previous weeks to include
+    #[clap(long)]
+    pub previous_weeks: Option<u32>,
+
+    /// Select the release week of a specific date (YYYY-MM-DD format)
+    #[clap(long, conflicts_with_all = ["week", "from"])]
+    pub release_date: Option<String>,
+
+    /// Select a release week directly (YYYY/WW or YYYY-Www, e.g. 2025/32 or 2025-W32)
+    #[clap(long, conflicts_with = "from")]
+    pub week: Option<String>,
+
+    /// Select all release weeks from this date on (YYYY-MM-DD format)
+    #[clap(long, conflicts_with = "previous_weeks")]
+    pub from: Option<String>,
+
+    /// End of the --from date range, defaults to today (YYYY-MM-DD format)
+    #[clap(long, requires = "from")]
+    pub to: Option<String>,
+}
+
+/// Command-line arguments filtering releases by their type.
+///
+/// Shared by `releases` and `playlist`. Releases are matched by their stored
+/// `album_type` and `album_group` (see `utils::ReleaseKinds::matches`).
+#[derive(Args, Debug, Clone, Default)]
+pub struct ReleaseTypeFilter {
+    /// Only include releases of these types (album, single, ep, compilation, appears_on or all; can be repeated)
+    #[clap(
+        long = "type",
+        value_parser = utils::parse_release_kinds,
+        action = ArgAction::Append,
+        num_args = 1
+    )]
+    pub types: Vec<ReleaseKinds>,
+
+    /// Leave out releases of these types (can be repeated)
+    #[clap(
+        long = "exclude-type",
+        value_parser = utils::parse_release_kinds,
+        action = ArgAction::Append,
+        num_args = 1
+    )]
+    pub exclude_types: Vec<ReleaseKinds>,
+}
+
+impl ReleaseTypeFilter {
+    /// Returns `true` if any type filter was given.
+    pub fn is_set(&self) -> bool {
+        !self.types.is_empty() || !self.exclude_types.is_empty()
+    }
+
+    /// Returns `true` if the album passes the filter.
+    ///
+    /// Without `--type` every type is included; `--exclude-type` wins over
+    /// `--type`.
+    ///
+    /// # Arguments
+    ///
+    /// * `album` - The release to check
+    /// * `ep_rule` - Thresholds that turn a single into an EP
+    pub fn matches(&self, album: &Album, ep_rule: &EpRule) -> bool {
+        let matches = |kinds: &ReleaseKinds| kinds.matches(album, ep_rule);
+        let included = self.types.is_empty() || self.types.iter().any(matches);
+        included && !self.exclude_types.iter().any(matches)
+    }
+}
+
+/// Command-line arguments filtering releases by the genres of their artists.
+///
+/// Shared by `releases` and `playlist`. A release matches a pattern if any
+/// followed artist credited on it has a matching genre (see
+/// `utils::GenrePattern` for the pattern syntax).
+#[derive(Args, Debug, Clone, Default)]
+pub struct GenreFilter {
+    /// Only include releases of artists with a matching genre (substring or /regex/; can be repeated)
+    #[clap(
+        long = "genre",
+        value_parser = utils::parse_genre_pattern,
+        action = ArgAction::Append,
+        num_args = 1
+    )]
+    pub genres: Vec<GenrePattern>,
+
+    /// Leave out releases of artists with a matching genre (can be repeated)
+    #[clap(
+        long = "exclude-genre",
+        value_parser = utils::parse_genre_pattern,
+        action = ArgAction::Append,
+        num_args = 1
+    )]
+    pub exclude_genres: Vec<GenrePattern>,
+}
+
+impl GenreFilter {
+    /// Returns `true` if any genre filter was given.
+    pub fn is_set(&self) -> bool {
+        !self.genres.is_empty() || !self.exclude_genres.is_empty()
+    }
+
+    /// Returns `true` if the album passes the filter.
+    ///
+    /// Without `--genre` every release is included; `--exclude-genre` wins
+    /// over `--genre`. Artists that are not followed have no known genres.
+    ///
+    /// # Arguments
+    ///
+    /// * `album` - The release to check
+    /// * `genres_by_artist` - Genres of the followed artists by artist ID
+    pub fn matches(&self, album: &Album, genres_by_artist: &HashMap<String, Vec<String>>) -> bool {
+        let genres: Vec<&str> = album
+            .artists
+            .iter()
+            .filter_map(|artist| genres_by_artist.get(&artist.id))
+            .flatten()
+            .map(String::as_str)
+            .collect();
+        let matches = |pattern: &GenrePattern| genres.iter().any(|genre| pattern.matches(genre));
+
+        let included = self.genres.is_empty() || self.genres.iter().any(matches);
+        included && !self.exclude_genres.iter().any(matches)
+    }
+}
+
+/// Command-line arguments filtering releases by their credited artists.
+///
+/// Shared by `releases` and `playlist`. A release matches if any credited
+/// artist, followed or not, has the given ID or a name containing the given
+/// value, ignoring case.
+#[derive(Args, Debug, Clone, Default)]
+pub struct ArtistFilter {
+    /// Only include releases crediting a matching artist (ID or part of the name; can be repeated)
+    #[clap(long = "artist", action = ArgAction::Append, num_args = 1)]
+    pub artists: Vec<String>,
+}
+
+impl ArtistFilter {
+    /// Returns `true` if any artist filter was given.
+    pub fn is_set(&self) -> bool {
+        !self.artists.is_empty()
+    }
+
+    /// Returns `true` if the album passes the filter.
+    ///
+    /// # Arguments
+    ///
+    /// * `album` - The release to check
+    pub fn matches(&self, album: &Album) -> bool {
+        if self.artists.is_empty() {
+            return true;
+        }
+        self.artists.iter().any(|query| {
+            let needle = query.trim().to_lowercase();
+            album
+                .artists
+                .iter()
+                .any(|a| a.id == query.trim() || a.name.to_lowercase().contains(&needle))
+        })
+    }
+}
+
+impl WeekSelection {
+    /// Returns `true` if any week selecting argument was given.
+    pub fn is_set(&self) -> bool {
+        self.previous_weeks.is_some()
+            || self.release_date.is_some()
+            || self.week.is_some()
+            || self.from.is_some()
+    }
+}
+
+/// Output formats of the listing commands (`artists`, `releases`, `info`).
+///
+/// See the `output` module for the machine-readable schemas.
+#[derive(ValueEnum, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human readable table
+    #[default]
+    Table,
+    /// Versioned JSON document
+    Json,
+    /// One JSON record per line
+    Ndjson,
+    /// Comma separated values with a header row
+    Csv,
+    /// Markdown table
+    Markdown,
+}
+
+impl OutputFormat {
+    /// Returns `true` for the formats meant to be consumed by scripts.
+    ///
+    /// Machine-readable output contains nothing but the rendered records:
+    /// no headings and no status messages on stdout.
+    pub fn is_machine_readable(&self) -> bool {
+        matches!(
+            self,
+            OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Csv
+        )
+    }
+}
+
 /// Represents a complete release week with associated albums.
 ///
 /// Combines week information with the actual music releases that occurred
@@ -176,10 +708,8 @@ pub struct WeekOfTheYear {
 /// for a specific time period.
 #[derive(Debug, Clone)]
 pub struct ReleaseWeek {
-    /// The week information (number and dates)
+    /// The week information (number, release year and dates)
     pub week: WeekOfTheYear,
-    /// The year this week belongs to
-    pub year: i32,
     /// All album releases during this week
     pub releases: Vec<Album>,
 }
@@ -193,9 +723,12 @@ pub struct ReleaseWeek {
 pub struct ReleaseTableRow {
     /// Release date formatted as a string
     pub date: String,
+    /// Release type, e.g. `single` or `album (appears on)`
+    #[tabled(rename = "type")]
+    pub kind: String,
     /// Album/release name
     pub name: String,
-    /// Formatted string of artist names
+    /// All credited artists separated by `; `, followed ones marked with `*`
     pub artists: String,
 }
 
@@ -241,10 +774,22 @@ pub struct CreatePlaylistResponse {
 /// Provides a wrapper around the list of detailed album responses.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetSeveralAlbumsResponse {
-    /// List of detailed album information
+    /// List of detailed album information; unknown album IDs are left out
+    #[serde(deserialize_with = "skip_null_albums")]
     pub albums: Vec<GetAlbumResponse>,
 }
 
+/// Deserializes the album list of a several-albums response.
+///
+/// Spotify answers unknown album IDs with `null` entries, which are dropped.
+fn skip_null_albums<'de, D>(deserializer: D) -> Result<Vec<GetAlbumResponse>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let albums: Vec<Option<GetAlbumResponse>> = Deserialize::deserialize(deserializer)?;
+    Ok(albums.into_iter().flatten().collect())
+}
+
 /// Detailed album information including track listings.
 ///
 /// An extended album representation that includes the complete track listing
@@ -258,10 +803,135 @@ pub struct GetAlbumResponse {
     pub name: String,
     /// Release date of the album
     pub release_date: String,
+    /// Precision of the release date (`day`, `month` or `year`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_date_precision: Option<String>,
+    /// Spotify album type (`album`, `single`, `compilation`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub album_type: Option<String>,
+    /// Credited artists of the album
+    #[serde(default)]
+    pub artists: Vec<AlbumArtist>,
+    /// Number of tracks on the album
+    #[serde(default)]
+    pub total_tracks: Option<u32>,
+    /// Record label, if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Copyright statements of the album
+    #[serde(default)]
+    pub copyrights: Vec<Copyright>,
+    /// Known external identifiers such as the UPC
+    #[serde(default)]
+    pub external_ids: ExternalIds,
+    /// Spotify web player URL of the album
+    #[serde(default)]
+    pub external_urls: ExternalUrls,
     /// Complete track listing for the album
     pub tracks: Tracks,
 }
 
+/// Represents one field of a release for the `releases show` table.
+#[derive(Tabled)]
+pub struct AlbumFieldRow {
+    /// Field name, e.g. `label`
+    pub field: String,
+    /// Field value
+    pub value: String,
+}
+
+/// Represents a track row for table display purposes.
+#[derive(Tabled)]
+pub struct TrackTableRow {
+    /// Track number, prefixed with the disc on multi-disc releases (`2-1`)
+    #[tabled(rename = "#")]
+    pub number: String,
+    /// Track name, marked `[E]` if explicit
+    pub name: String,
+    /// All credited artists separated by `; `
+    pub artists: String,
+    /// Track length as `m:ss`
+    pub duration: String,
+}
+
+/// A copyright statement of an album.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Copyright {
+    /// The statement, e.g. `2025 Some Label`
+    pub text: String,
+    /// `C` for the copyright, `P` for the sound recording (performance) copyright
+    #[serde(rename = "type")]
+    pub kind: String,
+}
+
+/// External identifiers of an album.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExternalIds {
+    /// Universal Product Code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upc: Option<String>,
+    /// International Article Number
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ean: Option<String>,
+}
+
+impl GetAlbumResponse {
+    /// Returns `true` if any listed track of the album is explicit.
+    pub fn is_explicit(&self) -> bool {
+        self.tracks.items.iter().any(|track| track.explicit)
+    }
+
+    /// Returns `true` if every track of the album is listed.
+    ///
+    /// Spotify only embeds the first page of tracks, see
+    /// `spotify::releases::get_remaining_album_tracks` for the rest.
+    pub fn has_all_tracks(&self) -> bool {
+        self.tracks.next.is_none()
+            && self
+                .total_tracks
+                .or(self.tracks.total)
+                .is_none_or(|total| total as usize == self.tracks.items.len())
+    }
+
+    /// Returns the total duration of the album in milliseconds.
+    ///
+    /// The duration is `None` unless every track of the album is listed
+    /// with its duration.
+    pub fn duration_ms(&self) -> Option<u64> {
+        if !self.has_all_tracks() {
+            return None;
+        }
+        self.tracks
+            .items
+            .iter()
+            .map(|track| track.duration_ms)
+            .sum()
+    }
+}
+
+/// Edition facts of an album, kept by the edition index.
+///
+/// The small part of the full album details that release listings need to
+/// group editions of the same release (see `utils::group_editions`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EditionInfo {
+    /// Universal Product Code of the album
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upc: Option<String>,
+    /// Whether any listed track of the album is explicit
+    #[serde(default)]
+    pub explicit: bool,
+}
+
+impl From<&GetAlbumResponse> for EditionInfo {
+    fn from(album: &GetAlbumResponse) -> Self {
+        Self {
+            upc: album.external_ids.upc.clone(),
+            explicit: album.is_explicit(),
+        }
+    }
+}
+
 /// Container for track information within an album.
 ///
 /// Wraps a list of tracks, providing a structured way to represent
@@ -269,8 +939,14 @@ pub struct GetAlbumResponse {
 /// information when track-level data is required.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tracks {
-    /// List of tracks in the album
+    /// List of tracks in the album (Spotify embeds the first 50)
     pub items: Vec<Track>,
+    /// Number of tracks of the album, including those not embedded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total: Option<u32>,
+    /// URL of the next page of tracks, `None` once all tracks are listed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<String>,
 }
 
 /// Represents an individual track with essential playback information.
@@ -286,6 +962,21 @@ pub struct Track {
     pub name: String,
     /// Spotify URI for the track (used for playback and playlist operations)
     pub uri: String,
+    /// Track length in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+    /// Credited artists of the track
+    #[serde(default)]
+    pub artists: Vec<AlbumArtist>,
+    /// Position of the track on its disc
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub track_number: Option<u32>,
+    /// Disc the track is on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disc_number: Option<u32>,
+    /// Whether the track has explicit lyrics
+    #[serde(default)]
+    pub explicit: bool,
 }
 
 /// Request payload for adding tracks to a Spotify playlist.
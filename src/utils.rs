@@ -26,6 +26,9 @@
 //! - Command-line argument parsing for release types
 //! - Flexible filtering system for different music release categories
 //!
+//! ## Genre Filtering
+//! - Substring and regex genre patterns for `--genre`/`--exclude-genre`
+//!
 //! # Usage Examples
 //!
 //! ## OAuth Authentication
@@ -50,10 +53,11 @@
 //!
 //! ## Processing Release Data
 //! ```rust,no_run
-//! use sporl::utils::{get_weekly_releases, remove_duplicate_albums};
+//! use sporl::utils::{get_weekly_releases, remove_duplicate_albums, TitleFilter};
 //!
 //! // Get and process weekly releases
-//! let mut albums = get_weekly_releases(42, 2023).await?;
+//! let (mut albums, _hidden) =
+//!     get_weekly_releases(42, 2023, &TitleFilter::default(), &HashSet::new()).await?;
 //! remove_duplicate_albums(&mut albums);
 //! ```
 //!
@@ -70,22 +74,25 @@
 
 use std::{
     cmp::Ordering,
-    collections::{BTreeSet, HashSet},
+    collections::{BTreeSet, HashMap, HashSet},
     fmt,
-    time::Duration,
+    sync::LazyLock,
 };
 
 use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
-use chrono::{Datelike, NaiveDate, Utc, Weekday};
-use indicatif::{ProgressBar, ProgressStyle};
+use chrono::{DateTime, Datelike, NaiveDate, Weekday};
 use rand::{Rng, distr::Alphanumeric};
 use sha2::{Digest, Sha256};
 
 use clap::ValueEnum;
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    management::ReleaseWeekManager,
-    types::{Album, ReleaseTableRow, WeekOfTheYear},
+    clock, config,
+    management::{ArtistReleaseManager, ReleaseWeekManager},
+    progress::Progress,
+    types::{Album, AlbumArtist, GenreFilter, HiddenRelease, WeekOfTheYear, WeekSelection},
 };
 
 /// Generates a random code verifier for OAuth PKCE (Proof Key for Code Exchange).
@@ -136,77 +143,260 @@ pub fn generate_code_challenge(verifier: &str) -> String {
     URL_SAFE_NO_PAD.encode(hash)
 }
 
-/// Finds the Saturday that occurs before or on the given date.
+/// The week definitions sporlcli can organize releases by.
+///
+/// Chosen with the `WEEK_SCHEME` setting (see [`config::week_scheme`]).
+/// Release weeks of all schemes are resolved through the methods below, so
+/// week directories, playlist names and `info` output follow the chosen
+/// scheme consistently.
+///
+/// # Variants
+///
+/// * `SaturdayFriday` - Saturday to Friday with sporlcli's own numbering (`sat-fri`, default)
+/// * `FridayThursday` - Friday to Thursday, following "New Music Friday" (`fri-thu`)
+/// * `Iso` - ISO-8601 weeks from Monday to Sunday (`iso`)
+///
+/// # Numbering
+///
+/// `sat-fri` and `fri-thu` number weeks within the year their first day falls
+/// in: week 1 is the first week starting on or after January 1st, the days
+/// before it belong to the last week of the previous year. `iso` follows the
+/// ISO-8601 rules, where week 1 is the week containing the first Thursday.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, ValueEnum)]
+pub enum WeekScheme {
+    #[default]
+    #[value(name = "sat-fri")]
+    SaturdayFriday,
+    #[value(name = "fri-thu")]
+    FridayThursday,
+    #[value(name = "iso")]
+    Iso,
+}
+
+impl WeekScheme {
+    /// Returns the weekday a week of this scheme starts on.
+    pub fn first_weekday(&self) -> Weekday {
+        match self {
+            WeekScheme::SaturdayFriday => Weekday::Sat,
+            WeekScheme::FridayThursday => Weekday::Fri,
+            WeekScheme::Iso => Weekday::Mon,
+        }
+    }
+
+    /// Returns the weekday a week of this scheme ends on.
+    pub fn last_weekday(&self) -> Weekday {
+        self.first_weekday().pred()
+    }
+
+    /// Finds the first day of the week containing the given date.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - Any date within the week
+    ///
+    /// # Returns
+    ///
+    /// The first day of the week, which is `date` itself if it starts a week.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tuesday = NaiveDate::from_ymd_opt(2023, 10, 17).unwrap();
+    /// let saturday = WeekScheme::SaturdayFriday.week_start(tuesday); // Oct 14, 2023
+    /// let monday = WeekScheme::Iso.week_start(tuesday); // Oct 16, 2023
+    /// ```
+    pub fn week_start(&self, date: NaiveDate) -> NaiveDate {
+        let days_back = (date.weekday().num_days_from_monday() + 7
+            - self.first_weekday().num_days_from_monday())
+            % 7;
+        date - chrono::Duration::days(days_back as i64)
+    }
+
+    /// Determines the release year the week containing `date` belongs to.
+    ///
+    /// For `sat-fri` and `fri-thu` this is the year of the week's first day,
+    /// for `iso` the ISO week-numbering year.
+    pub fn week_year(&self, date: NaiveDate) -> i32 {
+        match self {
+            WeekScheme::Iso => date.iso_week().year(),
+            _ => self.week_start(date).year(),
+        }
+    }
+
+    /// Calculates the number of the week containing `date` within its release year.
+    pub fn week_number(&self, date: NaiveDate) -> u32 {
+        if *self == WeekScheme::Iso {
+            return date.iso_week().week();
+        }
+
+        let current_week_start = self.week_start(date);
+        let jan1 = NaiveDate::from_ymd_opt(current_week_start.year(), 1, 1).unwrap();
+        let first_week_start = self.week_start(jan1);
+
+        let diff_weeks = ((current_week_start - first_week_start).num_days() / 7) as u32;
+
+        // If Jan 1 starts a week *for the anchor year*, shift to 1-based (avoids week 0 in those years)
+        let jan1_starts_week = jan1.weekday() == self.first_weekday();
+        diff_weeks + if jan1_starts_week { 1 } else { 0 }
+    }
+
+    /// Builds the complete week containing the given date.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - Any date within the week to build
+    ///
+    /// # Returns
+    ///
+    /// A `WeekOfTheYear` with the week number, release year and all seven dates.
+    pub fn build_week(&self, date: NaiveDate) -> WeekOfTheYear {
+        let start = self.week_start(date);
+        let dates: Vec<NaiveDate> = (0..7).map(|i| start + chrono::Duration::days(i)).collect();
+
+        WeekOfTheYear {
+            week: self.week_number(start),
+            year: self.week_year(start),
+            dates,
+        }
+    }
+
+    /// Builds a week from its release year and week number.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The release year
+    /// * `week` - The week number within the release year
+    ///
+    /// # Returns
+    ///
+    /// A `Result<WeekOfTheYear, String>` with the week or an error if the
+    /// release year has no such week.
+    pub fn week_from_number(&self, year: i32, week: u32) -> Result<WeekOfTheYear, String> {
+        let missing = || format!("Release year {year} has no week {week}.");
+        if week == 0 {
+            return Err(missing());
+        }
+
+        let start = match self {
+            WeekScheme::Iso => NaiveDate::from_isoywd_opt(year, week, Weekday::Mon),
+            _ => NaiveDate::from_ymd_opt(year, 1, 1).map(|jan1| {
+                // week 1 starts on the first week start of the year unless January 1st is one
+                let offset = if jan1.weekday() == self.first_weekday() {
+                    week as i64 - 1
+                } else {
+                    week as i64
+                };
+                self.week_start(jan1) + chrono::Duration::days(offset * 7)
+            }),
+        }
+        .ok_or_else(missing)?;
+
+        let resolved = self.build_week(start);
+        if resolved.week != week || resolved.year != year {
+            return Err(missing());
+        }
+        Ok(resolved)
+    }
+
+    /// Formats a week for display and playlist names.
+    ///
+    /// The default scheme keeps the established `32/2025` format, the other
+    /// schemes are labelled distinctly so their playlists never collide.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(WeekScheme::SaturdayFriday.label(32, 2025), "32/2025");
+    /// assert_eq!(WeekScheme::FridayThursday.label(32, 2025), "32/2025 (fri-thu)");
+    /// assert_eq!(WeekScheme::Iso.label(32, 2025), "2025-W32");
+    /// ```
+    pub fn label(&self, week: u32, year: i32) -> String {
+        match self {
+            WeekScheme::SaturdayFriday => format!("{week}/{year}"),
+            WeekScheme::FridayThursday => format!("{week}/{year} (fri-thu)"),
+            WeekScheme::Iso => format!("{year}-W{week:02}"),
+        }
+    }
+
+    /// Returns the subdirectory that keeps the week caches of this scheme apart.
+    ///
+    /// The default scheme uses the established `releases/{year}/{week}`
+    /// layout, so existing caches stay valid; other schemes live in
+    /// `releases/{scheme}/{year}/{week}`.
+    pub fn cache_dir(&self) -> Option<&'static str> {
+        match self {
+            WeekScheme::SaturdayFriday => None,
+            WeekScheme::FridayThursday => Some("fri-thu"),
+            WeekScheme::Iso => Some("iso"),
+        }
+    }
+}
+
+impl fmt::Display for WeekScheme {
+    /// Formats a `WeekScheme` using its configuration value (`sat-fri`, `fri-thu`, `iso`).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = self
+            .to_possible_value()
+            .expect("week schemes have no skipped values");
+        f.write_str(value.get_name())
+    }
+}
+
+/// Calculates the release week number for a given date within its year.
 ///
-/// This function is used to determine the start of a week, where weeks are
-/// defined as starting on Saturday. If the given date is already a Saturday,
-/// it returns that date unchanged.
+/// Uses the configured [`WeekScheme`]. With the default Saturday-to-Friday
+/// scheme, weeks are numbered starting from 1, where week 1 is the first
+/// week starting on or after January 1st. This creates a consistent weekly
+/// numbering system for tracking music releases throughout the year.
 ///
 /// # Arguments
 ///
-/// * `date` - The date to find the preceding/current Saturday for
+/// * `date` - The date to calculate the week number for
 ///
 /// # Returns
 ///
-/// A `NaiveDate` representing the Saturday before or on the given date.
+/// A `u32` representing the week number (1-based) within the year.
 ///
 /// # Example
 ///
 /// ```
-/// let tuesday = NaiveDate::from_ymd_opt(2023, 10, 17).unwrap(); // Tuesday
-/// let saturday = get_saturday_before_or_on(tuesday); // Returns Oct 14, 2023 (Saturday)
+/// let date = NaiveDate::from_ymd_opt(2023, 1, 15).unwrap();
+/// let week_num = get_release_week_number(date); // Returns the week number for Jan 15, 2023
 /// ```
-pub(crate) fn get_saturday_before_or_on(date: NaiveDate) -> NaiveDate {
-    let weekday = date.weekday().num_days_from_sunday(); // Sunday=0, Saturday=6
-    let days_to_subtract = (weekday + 1) % 7; // How many days to go back to Saturday
-    date - chrono::Duration::days(days_to_subtract as i64)
+pub fn get_release_week_number(date: NaiveDate) -> u32 {
+    config::week_scheme().week_number(date)
 }
 
-/// Calculates the release week number for a given date within its year.
+/// Determines the release year a date's release week belongs to.
 ///
-/// Weeks are numbered starting from 1, where week 1 begins on the Saturday
-/// before or on January 1st. This creates a consistent weekly numbering system
-/// for tracking music releases throughout the year.
+/// Uses the configured [`WeekScheme`]. With the default scheme a release week
+/// belongs to the year its Saturday falls in. For dates at the start of
+/// January whose week started in December this is the previous year, so e.g.
+/// Friday, January 3rd 2025 belongs to week 52 of 2024.
 ///
 /// # Arguments
 ///
-/// * `date` - The date to calculate the week number for
+/// * `date` - The date to determine the release year for
 ///
 /// # Returns
 ///
-/// A `u32` representing the week number (1-based) within the year.
+/// An `i32` representing the release year that anchors the week number.
 ///
 /// # Example
 ///
 /// ```
-/// let date = NaiveDate::from_ymd_opt(2023, 1, 15).unwrap();
-/// let week_num = get_release_week_number(date); // Returns the week number for Jan 15, 2023
+/// let date = NaiveDate::from_ymd_opt(2025, 1, 3).unwrap(); // Friday
+/// assert_eq!(get_release_week_year(date), 2024);
 /// ```
-pub fn get_release_week_number(date: NaiveDate) -> u32 {
-    let current_week_start = get_saturday_before_or_on(date);
-
-    // Determine which year's scheme applies (previous year if the week-start Saturday is in the prev year)
-    let anchor_year = if current_week_start.year() < date.year() {
-        current_week_start.year()
-    } else {
-        date.year()
-    };
-
-    let jan1 = NaiveDate::from_ymd_opt(anchor_year, 1, 1).unwrap();
-    let first_week_start = get_saturday_before_or_on(jan1);
-
-    let diff_weeks = ((current_week_start - first_week_start).num_days() / 7) as u32;
-
-    // If Jan 1 is Saturday *for the anchor year*, shift to 1-based (avoids week 0 in those years)
-    let jan1_is_sat = jan1.weekday() == Weekday::Sat;
-    diff_weeks + if jan1_is_sat { 1 } else { 0 }
+pub fn get_release_week_year(date: NaiveDate) -> i32 {
+    config::week_scheme().week_year(date)
 }
 
-/// Builds a complete week structure starting from the Saturday before or on the given date.
+/// Builds the complete release week containing the given date.
 ///
-/// Creates a `WeekOfTheYear` struct containing the week number and all seven dates
-/// in that week (Saturday through Friday). This provides a complete representation
-/// of a release week.
+/// Uses the configured [`WeekScheme`] and creates a `WeekOfTheYear` struct
+/// containing the week number, its release year and all seven dates in that
+/// week (Saturday through Friday with the default scheme).
 ///
 /// # Arguments
 ///
@@ -214,7 +404,7 @@ pub fn get_release_week_number(date: NaiveDate) -> u32 {
 ///
 /// # Returns
 ///
-/// A `WeekOfTheYear` struct containing the week number and all dates in that week.
+/// A `WeekOfTheYear` struct containing the week number, its release year and all dates in that week.
 ///
 /// # Example
 ///
@@ -223,24 +413,15 @@ pub fn get_release_week_number(date: NaiveDate) -> u32 {
 /// let week = build_week(date); // Returns week containing Oct 14-20, 2023
 /// ```
 pub fn build_week(date: NaiveDate) -> WeekOfTheYear {
-    let saturday = get_saturday_before_or_on(date);
-    let dates: Vec<NaiveDate> = (0..7)
-        .map(|i| saturday + chrono::Duration::days(i))
-        .collect();
-
-    let week_number = get_release_week_number(saturday);
-
-    WeekOfTheYear {
-        week: week_number,
-        dates,
-    }
+    config::week_scheme().build_week(date)
 }
 
 /// Generates a range of weeks going back from a given date.
 ///
 /// Creates a vector of `WeekOfTheYear` structures representing consecutive weeks
 /// leading up to (and optionally including) the week containing the given date.
-/// If the date falls on a Friday, the current week is included; otherwise it's skipped.
+/// If the date falls on the last day of a week (Friday with the default scheme), the
+/// current week is included; otherwise it's skipped.
 ///
 /// # Arguments
 ///
@@ -258,7 +439,7 @@ pub fn build_week(date: NaiveDate) -> WeekOfTheYear {
 /// let weeks = get_custom_week_range(date, 3); // Gets 3 weeks before current week
 /// ```
 pub fn get_custom_week_range(date: NaiveDate, weeks_before: u32) -> Vec<WeekOfTheYear> {
-    let skip_current = date.weekday() != Weekday::Fri;
+    let skip_current = date.weekday() != config::week_scheme().last_weekday();
 
     let start_offset = if skip_current { 1 } else { 0 };
 
@@ -291,36 +472,278 @@ pub fn remove_duplicate_albums(albums: &mut Vec<Album>) {
     albums.retain(|album| seen_ids.insert(album.id.clone()));
 }
 
-/// Sorts release table rows by date (descending) and then by artist name (ascending).
+/// Maximum number of days between the release dates of two editions of a release.
+pub const EDITION_DATE_WINDOW_DAYS: i64 = 14;
+
+/// Words that mark a bracketed title part or a ` - ` suffix as an edition marker.
+const EDITION_MARKERS: &str =
+    r"deluxe|explicit|clean|edited|remaster(ed)?|expanded|anniversary|bonus|edition";
+
+/// Bracketed title parts naming an edition, e.g. `(Deluxe Edition)` or `[Explicit]`.
+static EDITION_BRACKETS: LazyLock<Regex> = LazyLock::new(|| {
+    RegexBuilder::new(&format!(
+        r"\s*[(\[][^)\]]*\b({EDITION_MARKERS})\b[^)\]]*[)\]]"
+    ))
+    .case_insensitive(true)
+    .build()
+    .expect("edition marker pattern is valid")
+});
+
+/// Title suffixes naming an edition, e.g. ` - Super Deluxe`.
+static EDITION_SUFFIX: LazyLock<Regex> = LazyLock::new(|| {
+    RegexBuilder::new(&format!(r"\s+-\s+[^-]*\b({EDITION_MARKERS})\b[^-]*$"))
+        .case_insensitive(true)
+        .build()
+        .expect("edition marker pattern is valid")
+});
+
+/// Normalizes a release title for edition grouping.
+///
+/// Removes edition markers (bracketed parts or ` - ` suffixes mentioning e.g.
+/// deluxe, explicit, clean, remastered or edition), lowercases the title and
+/// collapses whitespace. Other bracketed parts such as `(Live)` or
+/// `(Remixes)` are kept, as they name a different release.
+///
+/// # Example
 ///
-/// Modifies the input vector in-place to sort entries with the most recent releases first.
-/// When multiple releases have the same date, they are sorted alphabetically by artist name.
-/// This provides a consistent and useful ordering for displaying release information.
+/// ```
+/// assert_eq!(normalize_release_title("Summer (Deluxe Edition)"), "summer");
+/// assert_eq!(normalize_release_title("Summer - 2025 Remaster"), "summer");
+/// assert_eq!(normalize_release_title("Summer (Live)"), "summer (live)");
+/// ```
+pub fn normalize_release_title(name: &str) -> String {
+    let name = EDITION_BRACKETS.replace_all(name, "");
+    let name = EDITION_SUFFIX.replace(&name, "");
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Groups editions of the same release.
+///
+/// Explicit and clean versions, regional duplicates and deluxe editions of a
+/// release are collected into one group. Releases with the same UPC are
+/// always editions of each other. Otherwise their normalized titles (see
+/// [`normalize_release_title`]) and credited artists must be equal and their
+/// release dates at most [`EDITION_DATE_WINDOW_DAYS`] apart from another
+/// edition; a release that joins two groups merges them.
+///
+/// Groups keep the order of their first release; within a group the
+/// preferred edition comes first: the first explicit one if any is known,
+/// else the first one.
 ///
 /// # Arguments
 ///
-/// * `rows` - A mutable reference to a vector of release table rows to sort
+/// * `albums` - Releases, already deduplicated by ID and sorted
+/// * `upcs` - Known UPCs by album ID, e.g. from the edition index
+/// * `explicit` - IDs of the releases known to contain explicit tracks
+///
+/// # Returns
+///
+/// One group per release, each holding at least one edition.
 ///
 /// # Example
 ///
 /// ```
-/// let mut rows = vec![row1, row2, row3];
-/// sort_release_table_rows(&mut rows); // Sorted by date desc, then artist asc
+/// // "Summer" and "Summer (Deluxe)" by the same artist, one week apart
+/// let groups = group_editions(albums, &HashMap::new(), &HashSet::new());
+/// assert_eq!(groups.len(), 1);
+/// assert_eq!(groups[0].len(), 2);
 /// ```
-pub fn sort_release_table_rows(rows: &mut Vec<ReleaseTableRow>) {
-    rows.sort_by(|a, b| {
-        match b.date.cmp(&a.date) {
-            Ordering::Equal => a.artists.cmp(&b.artists), // secondary sort: name ascending
-            other => other,
+pub fn group_editions(
+    albums: Vec<Album>,
+    upcs: &HashMap<String, String>,
+    explicit: &HashSet<String>,
+) -> Vec<Vec<Album>> {
+    // union-find over album indices, the lowest index of a group is its root
+    fn root(parent: &mut [usize], mut i: usize) -> usize {
+        while parent[i] != i {
+            parent[i] = parent[parent[i]];
+            i = parent[i];
         }
-    });
+        i
+    }
+    fn join(parent: &mut [usize], a: usize, b: usize) {
+        let (a, b) = (root(parent, a), root(parent, b));
+        parent[a.max(b)] = a.min(b);
+    }
+
+    let mut parent: Vec<usize> = (0..albums.len()).collect();
+
+    let mut by_upc: HashMap<&str, usize> = HashMap::new();
+    let mut by_key: HashMap<(String, BTreeSet<&str>), Vec<usize>> = HashMap::new();
+    for (i, album) in albums.iter().enumerate() {
+        if let Some(upc) = upcs.get(&album.id) {
+            match by_upc.get(upc.as_str()) {
+                Some(&first) => join(&mut parent, first, i),
+                None => {
+                    by_upc.insert(upc, i);
+                }
+            }
+        }
+
+        let artist_ids = album.artists.iter().map(|a| a.id.as_str()).collect();
+        by_key
+            .entry((normalize_release_title(&album.name), artist_ids))
+            .or_default()
+            .push(i);
+    }
+
+    for bucket in by_key.values() {
+        let mut dated: Vec<(NaiveDate, usize)> = Vec::new();
+        let mut undated: HashMap<&str, usize> = HashMap::new();
+        for &i in bucket {
+            match release_period_start(&albums[i]) {
+                Ok(date) => dated.push((date, i)),
+                Err(_) => match undated.get(albums[i].release_date.as_str()) {
+                    Some(&first) => join(&mut parent, first, i),
+                    None => {
+                        undated.insert(&albums[i].release_date, i);
+                    }
+                },
+            }
+        }
+
+        // neighbours in date order chain the editions within the window
+        dated.sort();
+        for pair in dated.windows(2) {
+            if (pair[1].0 - pair[0].0).num_days() <= EDITION_DATE_WINDOW_DAYS {
+                join(&mut parent, pair[0].1, pair[1].1);
+            }
+        }
+    }
+
+    let mut groups: Vec<Vec<Album>> = Vec::new();
+    let mut group_of_root: HashMap<usize, usize> = HashMap::new();
+    for (i, album) in albums.into_iter().enumerate() {
+        let root = root(&mut parent, i);
+        match group_of_root.get(&root) {
+            Some(&group) => groups[group].push(album),
+            None => {
+                group_of_root.insert(root, groups.len());
+                groups.push(vec![album]);
+            }
+        }
+    }
+
+    for group in &mut groups {
+        if let Some(preferred) = group.iter().position(|album| explicit.contains(&album.id)) {
+            group[..=preferred].rotate_right(1);
+        }
+    }
+    groups
+}
+
+/// Extracts a Spotify album ID from an ID, URI or URL.
+///
+/// # Arguments
+///
+/// * `input` - An album ID, a `spotify:album:` URI or an `open.spotify.com` album URL
+///
+/// # Returns
+///
+/// The album ID, or `None` if the input is none of these (e.g. a search term).
+///
+/// # Example
+///
+/// ```
+/// let url = "https://open.spotify.com/intl-de/album/4LH4d3cOWNNsVw41Gqt2kv?si=abc";
+/// assert_eq!(parse_album_id(url), Some("4LH4d3cOWNNsVw41Gqt2kv".to_string()));
+/// assert_eq!(parse_album_id("ok computer"), None);
+/// ```
+pub fn parse_album_id(input: &str) -> Option<String> {
+    let input = input.trim();
+    let is_id = |id: &str| id.len() == 22 && id.chars().all(|c| c.is_ascii_alphanumeric());
+
+    let candidate = if let Some(id) = input.strip_prefix("spotify:album:") {
+        id
+    } else if input.contains("open.spotify.com/") {
+        let path = input.split(['?', '#']).next().unwrap_or_default();
+        let mut segments = path.split('/').skip_while(|segment| *segment != "album");
+        segments.nth(1)?
+    } else {
+        input
+    };
+
+    is_id(candidate).then(|| candidate.to_string())
+}
+
+/// Formats a track or album length in milliseconds as `m:ss`, or `h:mm:ss`
+/// from one hour on.
+///
+/// Unlike [`crate::progress::format_duration`], which shows elapsed time
+/// with fixed-width hours, this follows how Spotify displays lengths.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(format_track_length(215_000), "3:35");
+/// assert_eq!(format_track_length(3_725_000), "1:02:05");
+/// ```
+pub fn format_track_length(ms: u64) -> String {
+    let seconds = ms / 1000;
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    }
 }
 
-/// Parses a date string or returns the current date if parsing fails or input is None.
+/// Returns the artist a release is listed under.
+///
+/// That is the first credited artist that is followed, or the first credited
+/// artist if none of them is followed.
+///
+/// # Arguments
+///
+/// * `album` - The release
+/// * `followed` - IDs of the followed artists
+///
+/// # Example
+///
+/// ```
+/// // "Guest feat. Followed" is listed under "Followed"
+/// let artist = primary_artist(&album, &followed);
+/// ```
+pub fn primary_artist<'a>(album: &'a Album, followed: &HashSet<String>) -> Option<&'a AlbumArtist> {
+    album
+        .artists
+        .iter()
+        .find(|artist| followed.contains(&artist.id))
+        .or_else(|| album.artists.first())
+}
+
+/// Formats the credited artists of a release for tables.
+///
+/// Joins all artist names with `; ` and marks followed artists with a
+/// trailing `*`, e.g. `Guest; Followed Artist*`.
+///
+/// # Arguments
+///
+/// * `artists` - The credited artists in credit order
+/// * `followed` - IDs of the followed artists
+pub fn format_artists(artists: &[AlbumArtist], followed: &HashSet<String>) -> String {
+    artists
+        .iter()
+        .map(|artist| {
+            if followed.contains(&artist.id) {
+                format!("{}*", artist.name)
+            } else {
+                artist.name.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Parses a date string or returns the current date if the input is None.
 ///
 /// Attempts to parse the input string using the format "%Y-%m-%d" (e.g., "2023-10-17").
-/// If the input is None or parsing fails, returns the current UTC date. This provides
-/// a robust way to handle date inputs with a sensible fallback.
+/// If the input is None, returns today's date from [`clock::today`], i.e. in the
+/// configured timezone or the `--today` override. An input that cannot be
+/// parsed is reported as an error instead of silently using another date.
 ///
 /// # Arguments
 ///
@@ -328,37 +751,324 @@ pub fn sort_release_table_rows(rows: &mut Vec<ReleaseTableRow>) {
 ///
 /// # Returns
 ///
-/// A `NaiveDate` representing either the parsed date or the current date.
+/// A `Result<NaiveDate, String>` containing either the parsed date (or the
+/// current date) or an error message naming the invalid input.
 ///
 /// # Example
 ///
 /// ```
-/// let date1 = get_date_from_string(Some("2023-10-17".to_string())); // Oct 17, 2023
-/// let date2 = get_date_from_string(None); // Current date
-/// let date3 = get_date_from_string(Some("invalid".to_string())); // Current date
+/// let date1 = get_date_from_string(Some("2023-10-17".to_string())); // Ok(Oct 17, 2023)
+/// let date2 = get_date_from_string(None); // Ok(current date)
+/// let date3 = get_date_from_string(Some("invalid".to_string())); // Err(...)
 /// ```
-pub fn get_date_from_string(date: Option<String>) -> NaiveDate {
+pub fn get_date_from_string(date: Option<String>) -> Result<NaiveDate, String> {
     match date {
-        Some(date_str) => NaiveDate::parse_from_str(&date_str, "%Y-%m-%d")
-            .unwrap_or_else(|_| Utc::now().date_naive()),
-        None => Utc::now().date_naive(),
+        Some(date_str) => NaiveDate::parse_from_str(date_str.trim(), "%Y-%m-%d")
+            .map_err(|_| format!("Invalid date '{date_str}'. Expected YYYY-MM-DD.")),
+        None => Ok(clock::today()),
+    }
+}
+
+/// Parses a release week given as `YYYY/WW` or `YYYY-Www`.
+///
+/// Both `2025/32` and the ISO-like `2025-W32` address week 32 of the release
+/// year 2025 in the configured [`WeekScheme`]. The week is resolved through
+/// [`WeekScheme::week_from_number`], so the result is identical to building
+/// the week from any of its dates.
+///
+/// # Arguments
+///
+/// * `input` - The week string, e.g. `2025/32` or `2025-W32`
+///
+/// # Returns
+///
+/// A `Result<WeekOfTheYear, String>` containing the week or an error message
+/// if the input is malformed or the year has no such week.
+///
+/// # Example
+///
+/// ```
+/// let week = parse_week("2025-W1")?;
+/// assert_eq!(week.dates[0], NaiveDate::from_ymd_opt(2025, 1, 4).unwrap());
+/// ```
+pub fn parse_week(input: &str) -> Result<WeekOfTheYear, String> {
+    let invalid = || format!("Invalid week '{input}'. Expected YYYY/WW or YYYY-Www.");
+
+    let trimmed = input.trim();
+    let (year, week) = match trimmed.split_once('/') {
+        Some(parts) => parts,
+        None => trimmed
+            .split_once("-W")
+            .or_else(|| trimmed.split_once("-w"))
+            .ok_or_else(invalid)?,
+    };
+    let year: i32 = year.parse().map_err(|_| invalid())?;
+    let week: u32 = week.parse().map_err(|_| invalid())?;
+
+    config::week_scheme().week_from_number(year, week)
+}
+
+/// Generates all release weeks covering a date range.
+///
+/// Every week that contains at least one date between `from` and `to`
+/// (inclusive) is returned, most recent week first like
+/// [`get_custom_week_range`].
+///
+/// # Arguments
+///
+/// * `from` - First date of the range
+/// * `to` - Last date of the range
+///
+/// # Returns
+///
+/// A `Result<Vec<WeekOfTheYear>, String>` with the covered weeks or an error
+/// if `from` is after `to`.
+///
+/// # Example
+///
+/// ```
+/// let from = NaiveDate::from_ymd_opt(2024, 12, 20).unwrap();
+/// let to = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+/// let weeks = get_week_range_between(from, to)?; // weeks 1/2025, 52/2024, 51/2024
+/// ```
+pub fn get_week_range_between(
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<WeekOfTheYear>, String> {
+    if from > to {
+        return Err(format!("Start date {from} is after end date {to}."));
+    }
+
+    let mut weeks = Vec::new();
+    let mut week_start = config::week_scheme().week_start(from);
+    while week_start <= to {
+        weeks.push(build_week(week_start));
+        week_start += chrono::Duration::days(7);
+    }
+    weeks.reverse();
+
+    Ok(weeks)
+}
+
+/// Resolves the release weeks selected on the command line.
+///
+/// Supports the following selections, all resolved through [`build_week`]:
+/// - `--week` - The given week plus `--previous-weeks` weeks before it
+/// - `--from`/`--to` - All weeks covering the date range (`--to` defaults to today)
+/// - `--release-date` - The week range relative to that date (default: today),
+///   as produced by [`get_custom_week_range`]
+///
+/// # Arguments
+///
+/// * `selection` - The week selection arguments
+///
+/// # Returns
+///
+/// A `Result<Vec<WeekOfTheYear>, String>` with the selected weeks, most recent
+/// first, or an error message describing an invalid week or date.
+///
+/// # Example
+///
+/// ```
+/// let selection = WeekSelection {
+///     week: Some("2025/32".to_string()),
+///     ..Default::default()
+/// };
+/// let weeks = resolve_release_weeks(&selection)?; // week 32 of 2025
+/// ```
+pub fn resolve_release_weeks(selection: &WeekSelection) -> Result<Vec<WeekOfTheYear>, String> {
+    resolve_release_weeks_on(selection, clock::today())
+}
+
+/// Resolves the release weeks selected on the command line relative to a given day.
+///
+/// Same as [`resolve_release_weeks`], but uses `today` instead of the
+/// process-wide clock wherever a selection defaults to the current date.
+///
+/// # Arguments
+///
+/// * `selection` - The week selection arguments
+/// * `today` - The date to treat as today
+///
+/// # Example
+///
+/// ```
+/// let today = NaiveDate::from_ymd_opt(2025, 8, 8).unwrap(); // Friday
+/// let weeks = resolve_release_weeks_on(&WeekSelection::default(), today)?; // week 31/2025
+/// ```
+pub fn resolve_release_weeks_on(
+    selection: &WeekSelection,
+    today: NaiveDate,
+) -> Result<Vec<WeekOfTheYear>, String> {
+    let previous_weeks = selection.previous_weeks.unwrap_or(0);
+    let date_or_today = |date: &Option<String>| match date {
+        Some(_) => get_date_from_string(date.clone()),
+        None => Ok(today),
+    };
+
+    if let Some(week) = &selection.week {
+        let week = parse_week(week)?;
+        let last_day = *week.dates.last().ok_or("Week without dates")?;
+        return Ok(get_custom_week_range(last_day, previous_weeks));
+    }
+
+    if let Some(from) = &selection.from {
+        let from = get_date_from_string(Some(from.clone()))?;
+        let to = date_or_today(&selection.to)?;
+        return get_week_range_between(from, to);
     }
+
+    let date = date_or_today(&selection.release_date)?;
+    Ok(get_custom_week_range(date, previous_weeks))
+}
+
+/// Returns the first day of the period an album's release date describes.
+///
+/// Spotify reports release dates with day (`2024-10-18`), month (`2024-10`)
+/// or year (`2024`) precision. For month and year precision the first day of
+/// the month or year is returned.
+///
+/// # Arguments
+///
+/// * `album` - The album whose release date should be parsed
+///
+/// # Returns
+///
+/// A `Result<NaiveDate, String>` containing the (first) release day or an error message.
+///
+/// # Example
+///
+/// ```
+/// // album.release_date = "2024-10", album.release_date_precision = "month"
+/// let date = release_period_start(&album)?; // 2024-10-01
+/// ```
+pub fn release_period_start(album: &Album) -> Result<NaiveDate, String> {
+    let date = match album.release_date_precision.as_str() {
+        "day" => album.release_date.clone(),
+        "month" => format!("{}-01", album.release_date),
+        "year" => format!("{}-01-01", album.release_date),
+        other => return Err(format!("Unknown release date precision '{other}'")),
+    };
+
+    NaiveDate::parse_from_str(&date, "%Y-%m-%d").map_err(|e| e.to_string())
+}
+
+/// Parses a point in time given on the command line into a Unix timestamp.
+///
+/// Accepts Unix timestamps in seconds, plain dates (interpreted as midnight
+/// UTC) and RFC 3339 date-times.
+///
+/// # Arguments
+///
+/// * `input` - The timestamp string, e.g. `1697760000`, `2023-10-20` or `2023-10-20T12:00:00+02:00`
+///
+/// # Returns
+///
+/// A `Result<u64, String>` containing the Unix timestamp in seconds or an error message.
+///
+/// # Example
+///
+/// ```
+/// let ts = parse_timestamp("2023-10-20").unwrap(); // 1697760000
+/// let ts = parse_timestamp("1697760000").unwrap(); // 1697760000
+/// assert!(parse_timestamp("last friday").is_err());
+/// ```
+pub fn parse_timestamp(input: &str) -> Result<u64, String> {
+    let input = input.trim();
+
+    if let Ok(ts) = input.parse::<u64>() {
+        return Ok(ts);
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+        let ts = date.and_hms_opt(0, 0, 0).unwrap().and_utc().timestamp();
+        return u64::try_from(ts).map_err(|_| format!("Timestamp before 1970: {input}"));
+    }
+
+    match DateTime::parse_from_rfc3339(input) {
+        Ok(dt) => {
+            u64::try_from(dt.timestamp()).map_err(|_| format!("Timestamp before 1970: {input}"))
+        }
+        Err(_) => Err(format!(
+            "Invalid timestamp '{input}'. Expected Unix seconds, YYYY-MM-DD or RFC 3339."
+        )),
+    }
+}
+
+/// Describes how long ago something happened in words.
+///
+/// Used to show the staleness of cached data, rounded down to the largest
+/// whole unit (minutes, hours or days).
+///
+/// # Arguments
+///
+/// * `seconds` - Age in seconds
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(format_age(30), "just now");
+/// assert_eq!(format_age(2 * 3600 + 59), "2 hours ago");
+/// assert_eq!(format_age(86_400), "1 day ago");
+/// ```
+pub fn format_age(seconds: u64) -> String {
+    let (value, unit) = match seconds {
+        0..60 => return "just now".to_string(),
+        60..3_600 => (seconds / 60, "minute"),
+        3_600..86_400 => (seconds / 3_600, "hour"),
+        _ => (seconds / 86_400, "day"),
+    };
+    let plural = if value == 1 { "" } else { "s" };
+    format!("{value} {unit}{plural} ago")
+}
+
+/// Loads the genres of the followed artists when a genre filter needs them.
+///
+/// # Arguments
+///
+/// * `filter` - The genre filter of the command
+///
+/// # Returns
+///
+/// The genres by artist ID (empty without a genre filter), or an error
+/// message if the artist cache cannot be loaded.
+///
+/// # Example
+///
+/// ```
+/// let genres_by_artist = load_genres_for(&filter).await?;
+/// releases.retain(|album| filter.matches(album, &genres_by_artist));
+/// ```
+pub async fn load_genres_for(filter: &GenreFilter) -> Result<HashMap<String, Vec<String>>, String> {
+    if !filter.is_set() {
+        return Ok(HashMap::new());
+    }
+    ArtistReleaseManager::load()
+        .await
+        .map(|manager| manager.genres_by_artist())
+        .map_err(|e| {
+            format!("Genre filters need the artist cache. Run sporlcli artists update. Err: {e}")
+        })
 }
 
 /// Retrieves and processes weekly music releases for a specific week and year.
 ///
 /// Loads release data from cache using a `ReleaseWeekManager`, then processes the results
-/// by removing duplicates and sorting by date and artist. Returns a clean, sorted list
-/// of albums released during the specified week.
+/// by removing duplicates and sorting by date and artist. Releases whose titles are
+/// hidden by the title filter are split off. Returns a clean, sorted list of albums
+/// released during the specified week.
 ///
 /// # Arguments
 ///
 /// * `week` - The week number within the year (1-based)
 /// * `year` - The year to get releases for
+/// * `titles` - Title patterns that hide releases, see [`crate::config::title_filter`]
+/// * `followed` - IDs of the followed artists, used for sorting
 ///
 /// # Returns
 ///
-/// A `Result<Vec<Album>, String>` containing either the processed album list or an error message.
+/// A `Result` containing either the shown releases together with the hidden ones
+/// and their reasons, or an error message.
 ///
 /// # Errors
 ///
@@ -369,9 +1079,14 @@ pub fn get_date_from_string(date: Option<String>) -> NaiveDate {
 /// # Example
 ///
 /// ```
-/// let releases = get_weekly_releases(42, 2023).await?; // Week 42 of 2023
+/// let (releases, hidden) = get_weekly_releases(42, 2023, &titles, &followed).await?; // Week 42 of 2023
 /// ```
-pub async fn get_weekly_releases(week: u32, year: i32) -> Result<Vec<Album>, String> {
+pub async fn get_weekly_releases(
+    week: u32,
+    year: i32,
+    titles: &TitleFilter,
+    followed: &HashSet<String>,
+) -> Result<(Vec<Album>, Vec<HiddenRelease>), String> {
     let mut releases: Vec<Album> = match ReleaseWeekManager::new(week, year, None)
         .load_from_cache()
         .await
@@ -380,49 +1095,52 @@ pub async fn get_weekly_releases(week: u32, year: i32) -> Result<Vec<Album>, Str
             Ok(releases) => releases,
             Err(e) => {
                 return Err(format!(
-                    "Failed to load releases for week {}/{}: {}\nRun sporlcli releases --update.",
+                    "Failed to load releases for week {}/{}: {}\nRun sporlcli releases update.",
                     week, year, e
                 ));
             }
         },
         Err(e) => {
             return Err(format!(
-                "Failed to load releases for week {}/{}: {:?}\nRun sporlcli releases --update.",
+                "Failed to load releases for week {}/{}: {:?}\nRun sporlcli releases update.",
                 week, year, e
             ));
         }
     };
 
     remove_duplicate_albums(&mut releases);
-    sort_albums_by_date_and_artist(&mut releases);
-    Ok(releases)
+    sort_albums_by_date_and_artist(&mut releases, followed);
+    let hidden = split_hidden_releases(&mut releases, |album| titles.hidden_reason(&album.name));
+    Ok((releases, hidden))
 }
 
-/// Sorts albums by release date (descending) and then by first artist name (ascending).
+/// Sorts albums by release date (descending) and then by artist name (ascending).
 ///
 /// Modifies the input vector in-place to sort albums with the most recent releases first.
 /// When multiple albums have the same release date, they are sorted alphabetically by
-/// the name of the first artist. This provides consistent ordering for album displays.
+/// the name of the artist they are listed under (see [`primary_artist`]). This provides
+/// consistent ordering for album displays.
 ///
 /// # Arguments
 ///
 /// * `albums` - A mutable reference to a vector of albums to sort
+/// * `followed` - IDs of the followed artists
 ///
 /// # Example
 ///
 /// ```
 /// let mut albums = vec![album1, album2, album3];
-/// sort_albums_by_date_and_artist(&mut albums); // Sorted by date desc, then artist asc
+/// sort_albums_by_date_and_artist(&mut albums, &followed); // Sorted by date desc, then artist asc
 /// ```
-pub(crate) fn sort_albums_by_date_and_artist(albums: &mut Vec<Album>) {
+pub fn sort_albums_by_date_and_artist(albums: &mut [Album], followed: &HashSet<String>) {
     albums.sort_by(|a, b| {
         let date_cmp = b.release_date.cmp(&a.release_date);
         if date_cmp != Ordering::Equal {
             return date_cmp;
         }
 
-        let a_artist = a.artists.get(0).map(|artist| artist.name.to_lowercase());
-        let b_artist = b.artists.get(0).map(|artist| artist.name.to_lowercase());
+        let a_artist = primary_artist(a, followed).map(|artist| artist.name.to_lowercase());
+        let b_artist = primary_artist(b, followed).map(|artist| artist.name.to_lowercase());
 
         a_artist.cmp(&b_artist)
     });
@@ -440,22 +1158,131 @@ pub(crate) fn sort_albums_by_date_and_artist(albums: &mut Vec<Album>) {
 /// * `Single` - Individual tracks or short releases
 /// * `AppearsOn` - Tracks appearing on compilations or other artists' releases
 /// * `Compilation` - Collection albums, greatest hits, etc.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, ValueEnum, Hash)]
+/// * `Ep` - Spotify singles whose track count (and duration, where known)
+///   fits the configured [`EpRule`]; derived locally, fetched as `single`
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, ValueEnum, Hash, Serialize, Deserialize,
+)]
 #[value(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
 pub enum ReleaseKind {
     Album,
     Single,
     AppearsOn,
     Compilation,
+    Ep,
 }
 
 impl ReleaseKind {
-    pub const ALL: [ReleaseKind; 4] = [
+    pub const ALL: [ReleaseKind; 5] = [
         ReleaseKind::Album,
         ReleaseKind::Single,
         ReleaseKind::AppearsOn,
         ReleaseKind::Compilation,
+        ReleaseKind::Ep,
     ];
+
+    /// Determines the release kind an album was fetched as.
+    ///
+    /// Uses the album's `album_group` when present and falls back to its
+    /// `album_type` for releases cached before the group was stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `album` - The album to classify
+    ///
+    /// # Returns
+    ///
+    /// The matching `ReleaseKind`, or `None` for unknown values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// if ReleaseKind::from_album(&album) == Some(ReleaseKind::Single) {
+    ///     println!("{} is a single", album.name);
+    /// }
+    /// ```
+    pub fn from_album(album: &Album) -> Option<Self> {
+        let group = album.album_group.as_deref().unwrap_or(&album.album_type);
+        ReleaseKind::from_str(group, true).ok()
+    }
+
+    /// Determines what kind of release an album is, including derived EPs.
+    ///
+    /// Unlike [`ReleaseKind::from_album`] this looks at the album's own
+    /// `album_type`, not at the group it was fetched with, and reports
+    /// singles matching the EP rule as `Ep`.
+    ///
+    /// # Arguments
+    ///
+    /// * `album` - The album to classify
+    /// * `ep_rule` - Thresholds that turn a single into an EP
+    ///
+    /// # Returns
+    ///
+    /// The matching `ReleaseKind`, or `None` for unknown album types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// if ReleaseKind::classify(&album, &config::ep_rule()) == Some(ReleaseKind::Ep) {
+    ///     println!("{} is an EP", album.name);
+    /// }
+    /// ```
+    pub fn classify(album: &Album, ep_rule: &EpRule) -> Option<Self> {
+        if ep_rule.is_ep(album) {
+            return Some(ReleaseKind::Ep);
+        }
+        ReleaseKind::from_str(&album.album_type, true).ok()
+    }
+}
+
+/// Thresholds that turn a Spotify `single` into an EP.
+///
+/// Spotify reports EPs with the album type `single`. A single counts as EP
+/// if its number of tracks lies within `min_tracks..=max_tracks` and its
+/// total duration, if known, does not exceed `max_duration_ms`. Singles
+/// without a known track count are never EPs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct EpRule {
+    /// Minimum number of tracks of an EP
+    pub min_tracks: u32,
+    /// Maximum number of tracks of an EP
+    pub max_tracks: u32,
+    /// Maximum total duration of an EP in milliseconds
+    pub max_duration_ms: u64,
+}
+
+impl Default for EpRule {
+    /// Returns Spotify's own distinction: 4 to 6 tracks and at most 30 minutes.
+    fn default() -> Self {
+        Self {
+            min_tracks: 4,
+            max_tracks: 6,
+            max_duration_ms: 30 * 60 * 1000,
+        }
+    }
+}
+
+impl EpRule {
+    /// Returns `true` if the album is a single that fits the EP thresholds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let rule = EpRule::default();
+    /// // a single with 5 tracks and 22 minutes
+    /// assert!(rule.is_ep(&five_track_single));
+    /// ```
+    pub fn is_ep(&self, album: &Album) -> bool {
+        album.album_type == "single"
+            && album
+                .total_tracks
+                .is_some_and(|tracks| (self.min_tracks..=self.max_tracks).contains(&tracks))
+            && album
+                .duration_ms
+                .is_none_or(|duration| duration <= self.max_duration_ms)
+    }
 }
 
 /// A validated, deduplicated set of release kinds parsed from command-line input.
@@ -486,6 +1313,53 @@ impl ReleaseKinds {
     pub fn iter(&self) -> impl Iterator<Item = ReleaseKind> + '_ {
         self.0.iter().copied()
     }
+
+    /// Returns `true` if the album's kind or group is in this set.
+    ///
+    /// An album matches by its kind (see [`ReleaseKind::classify`]) as well
+    /// as by an `appears_on` group, so a single the artist only appears on
+    /// matches both `single` and `appears_on`. Singles detected as EPs
+    /// match `ep`, not `single`.
+    ///
+    /// # Arguments
+    ///
+    /// * `album` - The album to check
+    /// * `ep_rule` - Thresholds that turn a single into an EP
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let eps = parse_release_kinds("ep")?;
+    /// let rule = config::ep_rule();
+    /// let eps: Vec<&Album> = albums.iter().filter(|a| eps.matches(a, &rule)).collect();
+    /// ```
+    pub fn matches(&self, album: &Album, ep_rule: &EpRule) -> bool {
+        let appears_on = album.album_group.as_deref() == Some("appears_on");
+        ReleaseKind::classify(album, ep_rule).is_some_and(|kind| self.0.contains(&kind))
+            || (appears_on && self.0.contains(&ReleaseKind::AppearsOn))
+    }
+
+    /// Returns the kinds to request from Spotify for this selection.
+    ///
+    /// Spotify knows no EPs; they are fetched as singles and told apart
+    /// locally, so `ep` is replaced by `single`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let kinds = parse_release_kinds("album,ep")?;
+    /// assert_eq!(kinds.fetch_groups().to_string(), "album,single");
+    /// ```
+    pub fn fetch_groups(&self) -> ReleaseKinds {
+        ReleaseKinds(
+            self.iter()
+                .map(|kind| match kind {
+                    ReleaseKind::Ep => ReleaseKind::Single,
+                    other => other,
+                })
+                .collect(),
+        )
+    }
 }
 
 impl Default for ReleaseKinds {
@@ -554,6 +1428,279 @@ impl fmt::Display for ReleaseKinds {
     }
 }
 
+/// Formats the type of a release for display.
+///
+/// Shows the release kind and marks releases the artist only appears on.
+///
+/// # Arguments
+///
+/// * `kind` - Release kind (`album`, `single`, `ep`, `compilation`)
+/// * `album_group` - Release group the album was fetched with, if known
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(release_type_label("ep", Some("single")), "ep");
+/// assert_eq!(release_type_label("album", Some("appears_on")), "album (appears on)");
+/// ```
+pub fn release_type_label(kind: &str, album_group: Option<&str>) -> String {
+    match album_group {
+        Some("appears_on") => format!("{kind} (appears on)"),
+        _ => kind.to_string(),
+    }
+}
+
+/// Returns the kind of a release as shown in listings.
+///
+/// Uses [`ReleaseKind::classify`] and falls back to Spotify's `album_type`
+/// for values sporlcli does not know.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(release_kind_name(&five_track_single, &EpRule::default()), "ep");
+/// ```
+pub fn release_kind_name(album: &Album, ep_rule: &EpRule) -> String {
+    ReleaseKind::classify(album, ep_rule)
+        .map(|kind| kind.to_string())
+        .unwrap_or_else(|| album.album_type.clone())
+}
+
+/// A genre pattern given with `--genre` or `--exclude-genre`.
+///
+/// Plain values match genres containing them, ignoring case. Values enclosed
+/// in slashes (`/^dark/`) are case-insensitive regular expressions.
+#[derive(Clone, Debug)]
+pub enum GenrePattern {
+    /// Lowercased substring a genre must contain
+    Substring(String),
+    /// Regular expression a genre must match
+    Regex(Regex),
+}
+
+impl GenrePattern {
+    /// Returns `true` if the genre matches this pattern.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let pattern = parse_genre_pattern("metal")?;
+    /// assert!(pattern.matches("Melodic Death Metal"));
+    /// ```
+    pub fn matches(&self, genre: &str) -> bool {
+        match self {
+            GenrePattern::Substring(needle) => genre.to_lowercase().contains(needle),
+            GenrePattern::Regex(regex) => regex.is_match(genre),
+        }
+    }
+}
+
+/// Parses a genre pattern from command-line input.
+///
+/// # Arguments
+///
+/// * `input` - A substring such as `techno` or a regex in slashes such as `/^(deep|minimal) house$/`
+///
+/// # Returns
+///
+/// A `Result<GenrePattern, String>` containing the pattern or an error message
+/// for empty values and invalid regular expressions.
+///
+/// # Example
+///
+/// ```
+/// let substring = parse_genre_pattern("house")?;
+/// let regex = parse_genre_pattern("/^uk /")?;
+/// assert!(parse_genre_pattern("/(unclosed/").is_err());
+/// ```
+pub fn parse_genre_pattern(input: &str) -> Result<GenrePattern, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err("genre pattern cannot be empty".into());
+    }
+
+    match input
+        .strip_prefix('/')
+        .and_then(|rest| rest.strip_suffix('/'))
+    {
+        Some(expression) => RegexBuilder::new(expression)
+            .case_insensitive(true)
+            .build()
+            .map(GenrePattern::Regex)
+            .map_err(|e| format!("invalid genre regex '{expression}': {e}")),
+        None => Ok(GenrePattern::Substring(input.to_lowercase())),
+    }
+}
+
+/// A release title pattern from `TITLE_INCLUDE` or `TITLE_EXCLUDE`.
+///
+/// Plain values match titles containing them, globs with `*` or `?` must
+/// match the whole title, and values enclosed in slashes (`/sped up$/`) are
+/// regular expressions. All patterns ignore case.
+#[derive(Clone, Debug)]
+pub struct TitlePattern {
+    /// The pattern as configured, used to explain why a title is hidden
+    pub source: String,
+    /// The compiled, case-insensitive pattern
+    regex: Regex,
+}
+
+impl TitlePattern {
+    /// Returns `true` if the title matches this pattern.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let pattern = parse_title_pattern("*(Live)")?;
+    /// assert!(pattern.matches("Sunrise (Live)"));
+    /// ```
+    pub fn matches(&self, title: &str) -> bool {
+        self.regex.is_match(title)
+    }
+}
+
+/// Parses a release title pattern.
+///
+/// # Arguments
+///
+/// * `input` - A substring such as `remaster`, a glob such as `*(Live)` or a
+///   regex in slashes such as `/\b(sped up|slowed)\b/`
+///
+/// # Returns
+///
+/// A `Result<TitlePattern, String>` containing the pattern or an error message
+/// for empty values and invalid regular expressions.
+///
+/// # Example
+///
+/// ```
+/// let glob = parse_title_pattern("*Karaoke Version*")?;
+/// let regex = parse_title_pattern("/instrumental\)?$/")?;
+/// assert!(parse_title_pattern("/(unclosed/").is_err());
+/// ```
+pub fn parse_title_pattern(input: &str) -> Result<TitlePattern, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err("title pattern cannot be empty".into());
+    }
+
+    let expression = match input
+        .strip_prefix('/')
+        .and_then(|rest| rest.strip_suffix('/'))
+    {
+        Some(expression) => expression.to_string(),
+        None if input.contains(['*', '?']) => {
+            let glob: String = input
+                .chars()
+                .map(|c| match c {
+                    '*' => ".*".to_string(),
+                    '?' => ".".to_string(),
+                    c => regex::escape(&c.to_string()),
+                })
+                .collect();
+            format!("^{glob}$")
+        }
+        None => regex::escape(input),
+    };
+
+    RegexBuilder::new(&expression)
+        .case_insensitive(true)
+        .build()
+        .map(|regex| TitlePattern {
+            source: input.to_string(),
+            regex,
+        })
+        .map_err(|e| format!("invalid title pattern '{input}': {e}"))
+}
+
+/// Hides releases and tracks by their titles.
+///
+/// Configured with the `TITLE_INCLUDE` and `TITLE_EXCLUDE` environment
+/// variables, see [`crate::config::title_filter`]. Without include patterns
+/// every title is shown; exclude patterns win over include patterns.
+#[derive(Clone, Debug, Default)]
+pub struct TitleFilter {
+    /// Titles must match one of these patterns, if any are given
+    pub include: Vec<TitlePattern>,
+    /// Titles matching one of these patterns are hidden
+    pub exclude: Vec<TitlePattern>,
+}
+
+impl TitleFilter {
+    /// Returns `true` if any title pattern is configured.
+    pub fn is_set(&self) -> bool {
+        !self.include.is_empty() || !self.exclude.is_empty()
+    }
+
+    /// Explains why a title is hidden.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Name of a release or track
+    ///
+    /// # Returns
+    ///
+    /// `None` if the title is shown, otherwise the reason it is hidden.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let filter = TitleFilter {
+    ///     include: Vec::new(),
+    ///     exclude: vec![parse_title_pattern("remaster")?],
+    /// };
+    /// assert_eq!(
+    ///     filter.hidden_reason("Abbey Road (Remastered 2019)"),
+    ///     Some("title matches 'remaster'".to_string())
+    /// );
+    /// ```
+    pub fn hidden_reason(&self, title: &str) -> Option<String> {
+        if let Some(pattern) = self.exclude.iter().find(|p| p.matches(title)) {
+            return Some(format!("title matches '{}'", pattern.source));
+        }
+        if !self.include.is_empty() && !self.include.iter().any(|p| p.matches(title)) {
+            return Some("title matches no TITLE_INCLUDE pattern".to_string());
+        }
+        None
+    }
+}
+
+/// Moves hidden releases out of a list of releases.
+///
+/// # Arguments
+///
+/// * `albums` - The releases; hidden ones are removed in place
+/// * `reason_of` - Returns why a release is hidden, or `None` to keep it
+///
+/// # Returns
+///
+/// The removed releases together with the reason they are hidden, in their
+/// original order.
+///
+/// # Example
+///
+/// ```
+/// let titles = config::title_filter();
+/// let hidden = split_hidden_releases(&mut albums, |album| titles.hidden_reason(&album.name));
+/// ```
+pub fn split_hidden_releases(
+    albums: &mut Vec<Album>,
+    reason_of: impl Fn(&Album) -> Option<String>,
+) -> Vec<HiddenRelease> {
+    let mut hidden = Vec::new();
+    albums.retain(|album| match reason_of(album) {
+        Some(reason) => {
+            hidden.push(HiddenRelease {
+                album: album.clone(),
+                reason,
+            });
+            false
+        }
+        None => true,
+    });
+    hidden
+}
+
 /// Custom parser for release kinds from command-line input.
 ///
 /// Parses a comma-separated string of release types, with support for special values
@@ -633,24 +1780,23 @@ pub fn parse_release_kinds(input: &str) -> Result<ReleaseKinds, String> {
     Ok(ReleaseKinds(set))
 }
 
-/// Creates a customized spinning progress bar for indicating ongoing operations.
+/// Creates a progress indicator for an ongoing operation.
 ///
-/// This function creates a visual progress indicator that shows a spinning animation
-/// with a custom message to provide user feedback during potentially long-running
-/// operations such as API requests or data processing.
+/// This function creates a progress handle that provides user feedback during
+/// potentially long-running operations such as API requests or data processing.
+/// How the progress is rendered depends on the global `--progress` mode.
 ///
 /// # Arguments
 ///
-/// * `title` - A string slice containing the message to display alongside the spinner.
+/// * `title` - A string slice containing the message to display initially.
 ///             This typically describes the current operation being performed.
 ///
 /// # Returns
 ///
-/// A configured `ProgressBar` instance with:
-/// - A spinning animation using braille-pattern characters
-/// - Blue-colored spinner
-/// - Custom message display
-/// - 100ms tick interval for smooth animation
+/// A `Progress` handle which renders as:
+/// - A blue braille spinner with the message when stderr is a terminal
+/// - Plain status lines at most every 10 seconds when it is not (e.g. cron)
+/// - Nothing but structured events with `--progress json`
 ///
 /// # Example
 ///
@@ -659,25 +1805,6 @@ pub fn parse_release_kinds(input: &str) -> Result<ReleaseKinds, String> {
 /// // Perform some operation
 /// pb.finish_and_clear();
 /// ```
-pub fn create_progress_bar(title: &str) -> ProgressBar {
-    // Create a new spinner-style progress bar (indeterminate progress)
-    let pb = ProgressBar::new_spinner();
-
-    // Set the display message that appears next to the spinner
-    pb.set_message(title.to_string());
-
-    // Enable automatic spinning with 100ms intervals for smooth animation
-    pb.enable_steady_tick(Duration::from_millis(100));
-
-    // Configure the visual appearance of the progress bar
-    pb.set_style(
-        ProgressStyle::with_template("{spinner:.blue} {msg}")
-            .unwrap() // Safe to unwrap as template format is known to be valid
-            // Use braille-pattern characters for a smooth spinning animation
-            // These characters create a visual effect of rotation
-            .tick_chars("⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏"),
-    );
-
-    // Return the configured progress bar ready for use
-    pb
+pub fn create_progress_bar(title: &str) -> Progress {
+    Progress::new(title)
 }
@@ -0,0 +1,67 @@
+use chrono::{NaiveDate, TimeZone, Utc};
+use sporlcli::clock::{Clock, DateTimezone};
+use sporlcli::types::WeekSelection;
+use sporlcli::utils::resolve_release_weeks_on;
+
+#[test]
+fn test_date_timezone_parse() {
+    assert_eq!(DateTimezone::parse("local"), Ok(DateTimezone::Local));
+    assert_eq!(
+        DateTimezone::parse(" Australia/Sydney "),
+        Ok(DateTimezone::Named(chrono_tz::Australia::Sydney))
+    );
+    assert!(DateTimezone::parse("UTC").is_ok());
+
+    let err = DateTimezone::parse("Mars/Olympus").unwrap_err();
+    assert!(err.contains("Mars/Olympus"));
+}
+
+#[test]
+fn test_date_timezone_date_of() {
+    // Friday 08:00 in Sydney is still Thursday in UTC
+    let instant = Utc.with_ymd_and_hms(2025, 8, 7, 22, 0, 0).unwrap();
+
+    let sydney = DateTimezone::parse("Australia/Sydney").unwrap();
+    let utc = DateTimezone::parse("UTC").unwrap();
+    assert_eq!(
+        sydney.date_of(instant),
+        NaiveDate::from_ymd_opt(2025, 8, 8).unwrap()
+    );
+    assert_eq!(
+        utc.date_of(instant),
+        NaiveDate::from_ymd_opt(2025, 8, 7).unwrap()
+    );
+}
+
+#[test]
+fn test_fixed_clock() {
+    let date = NaiveDate::from_ymd_opt(2025, 8, 8).unwrap();
+    assert_eq!(Clock::fixed(date).today(), date);
+}
+
+#[test]
+fn test_resolve_release_weeks_on_fixed_day() {
+    let selection = WeekSelection::default();
+
+    // On a Friday the current release week is complete and included
+    let friday = NaiveDate::from_ymd_opt(2025, 8, 8).unwrap();
+    let weeks = resolve_release_weeks_on(&selection, friday).unwrap();
+    assert_eq!(weeks.len(), 1);
+    assert_eq!(weeks[0].dates.last(), Some(&friday));
+
+    // On the Thursday before, the previous week is the most recent complete one
+    let thursday = NaiveDate::from_ymd_opt(2025, 8, 7).unwrap();
+    let weeks = resolve_release_weeks_on(&selection, thursday).unwrap();
+    assert_eq!(
+        weeks[0].dates.last(),
+        Some(&NaiveDate::from_ymd_opt(2025, 8, 1).unwrap())
+    );
+
+    // --to defaults to the given day
+    let selection = WeekSelection {
+        from: Some("2025-07-26".to_string()),
+        ..Default::default()
+    };
+    let weeks = resolve_release_weeks_on(&selection, friday).unwrap();
+    assert_eq!(weeks.len(), 2);
+}
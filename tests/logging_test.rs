@@ -0,0 +1,41 @@
+use sporlcli::logging::{Kind, Level, format_line};
+
+#[test]
+fn test_level_from_flags() {
+    assert_eq!(Level::from_flags(false, 0), Level::Info);
+    assert_eq!(Level::from_flags(false, 1), Level::Debug);
+    assert_eq!(Level::from_flags(false, 2), Level::Trace);
+    assert_eq!(Level::from_flags(false, 5), Level::Trace);
+    assert_eq!(Level::from_flags(true, 0), Level::Warning);
+}
+
+#[test]
+fn test_level_ordering() {
+    assert!(Level::Error < Level::Warning);
+    assert!(Level::Warning < Level::Info);
+    assert!(Level::Info < Level::Debug);
+    assert!(Level::Debug < Level::Trace);
+}
+
+#[test]
+fn test_kind_level() {
+    assert_eq!(Kind::Info.level(), Level::Info);
+    assert_eq!(Kind::Success.level(), Level::Info);
+    assert_eq!(Kind::Warning.level(), Level::Warning);
+    assert_eq!(Kind::Error.level(), Level::Error);
+    assert_eq!(Kind::Debug.level(), Level::Debug);
+    assert_eq!(Kind::Trace.level(), Level::Trace);
+}
+
+#[test]
+fn test_format_line() {
+    colored::control::set_override(false);
+    assert_eq!(
+        format_line(Kind::Info, format_args!("Found {} releases", 3)),
+        "[o] Found 3 releases"
+    );
+    assert_eq!(
+        format_line(Kind::Warning, format_args!("Careful")),
+        "[!] Careful"
+    );
+}
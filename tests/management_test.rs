@@ -0,0 +1,480 @@
+use sporlcli::management::{
+    ArtistReleaseManager, ArtistRulesManager, EditionIndexManager, RetryQueueManager,
+    STATE_TYPE_RELEASES, StateManager,
+};
+use sporlcli::types::{
+    Album, AlbumArtist, Artist, ArtistReleases, ArtistRule, ArtistRuleMode, ExternalUrls,
+    Followers, GetSeveralAlbumsResponse, RunParams,
+};
+use sporlcli::utils::{EpRule, ReleaseKind, parse_release_kinds};
+use std::collections::{HashMap, HashSet};
+
+// Helper function to create a test artist
+fn create_test_artist(id: &str, name: &str) -> Artist {
+    Artist {
+        id: id.to_string(),
+        name: name.to_string(),
+        genres: Vec::new(),
+        popularity: None,
+        followers: None,
+        images: Vec::new(),
+        external_urls: ExternalUrls::default(),
+        refreshed_at: None,
+    }
+}
+
+// Helper function to create a test album for an artist
+fn create_test_album(id: &str, artist: &Artist) -> Album {
+    Album {
+        id: id.to_string(),
+        name: format!("Album {}", id),
+        release_date: "2023-10-20".to_string(),
+        release_date_precision: "day".to_string(),
+        album_type: "album".to_string(),
+        album_group: None,
+        artists: vec![AlbumArtist {
+            id: artist.id.clone(),
+            name: artist.name.clone(),
+        }],
+        total_tracks: None,
+        duration_ms: None,
+        first_seen_at: None,
+    }
+}
+
+// Helper function to create a manager with one release per artist
+fn create_test_manager(artists: &[Artist]) -> ArtistReleaseManager {
+    let artist_releases = artists
+        .iter()
+        .map(|a| ArtistReleases {
+            artist: a.clone(),
+            releases: vec![create_test_album(&format!("{}_album", a.id), a)],
+        })
+        .collect();
+    ArtistReleaseManager::new(Some(artist_releases))
+}
+
+#[test]
+fn test_sync_artists_detects_follow_and_unfollow_with_same_count() {
+    let a = create_test_artist("a", "Artist A");
+    let b = create_test_artist("b", "Artist B");
+    let c = create_test_artist("c", "Artist C");
+    let mut manager = create_test_manager(&[a.clone(), b.clone()]);
+
+    // Unfollow B, follow C: counts stay equal but the sets differ
+    let summary = manager.sync_artists(vec![c.clone(), a.clone()]);
+
+    assert_eq!(summary.added.len(), 1);
+    assert_eq!(summary.added[0].id, "c");
+    assert_eq!(summary.removed.len(), 1);
+    assert_eq!(summary.removed[0].artist.id, "b");
+    assert_eq!(summary.removed[0].releases.len(), 1);
+    assert_eq!(summary.unchanged, 1);
+
+    let mut ids: Vec<String> = manager
+        .get_all_artists()
+        .unwrap()
+        .into_iter()
+        .map(|a| a.id)
+        .collect();
+    ids.sort();
+    assert_eq!(ids, vec!["a", "c"]);
+}
+
+#[test]
+fn test_sync_artists_keeps_releases_and_refreshes_metadata() {
+    let a = create_test_artist("a", "Artist A");
+    let mut manager = create_test_manager(std::slice::from_ref(&a));
+
+    let mut renamed = create_test_artist("a", "Artist A (renamed)");
+    renamed.genres = vec!["indie".to_string()];
+    let summary = manager.sync_artists(vec![renamed]);
+
+    assert!(summary.is_empty());
+    assert_eq!(summary.unchanged, 1);
+    assert_eq!(manager.get_releases_for_artist("a").unwrap().len(), 1);
+
+    let artists = manager.get_all_artists().unwrap();
+    assert_eq!(artists[0].name, "Artist A (renamed)");
+    assert_eq!(artists[0].genres, vec!["indie"]);
+}
+
+#[test]
+fn test_sync_artists_ignores_duplicate_remote_entries() {
+    let a = create_test_artist("a", "Artist A");
+    let mut manager = ArtistReleaseManager::new(None);
+
+    let summary = manager.sync_artists(vec![a.clone(), a.clone()]);
+
+    assert_eq!(summary.added.len(), 1);
+    assert_eq!(manager.count_artists(), 1);
+}
+
+#[test]
+fn test_clear_releases_after_sync_keeps_removed_artist_releases() {
+    let a = create_test_artist("a", "Artist A");
+    let b = create_test_artist("b", "Artist B");
+    let mut manager = create_test_manager(&[a.clone(), b.clone()]);
+
+    let summary = manager.sync_artists(vec![a.clone()]);
+    let cleared = manager.clear_releases();
+
+    assert_eq!(cleared, 1);
+    assert_eq!(summary.removed.len(), 1);
+    assert_eq!(summary.removed[0].releases.len(), 1);
+    assert!(manager.get_releases_for_artist("a").unwrap().is_empty());
+    assert_eq!(manager.count_artists(), 1);
+}
+
+#[test]
+fn test_update_artist_metadata_keeps_releases_and_ignores_unknown() {
+    let a = create_test_artist("a", "Artist A");
+    let mut manager = create_test_manager(std::slice::from_ref(&a));
+
+    let mut refreshed = create_test_artist("a", "Artist A");
+    refreshed.popularity = Some(42);
+    refreshed.followers = Some(Followers { total: 1000 });
+    let unknown = create_test_artist("x", "Not Followed");
+
+    let updated = manager.update_artist_metadata(vec![refreshed, unknown]);
+
+    assert_eq!(updated, 1);
+    assert_eq!(manager.count_artists(), 1);
+    assert_eq!(manager.get_releases_for_artist("a").unwrap().len(), 1);
+
+    let artist = &manager.get_all_artists().unwrap()[0];
+    assert_eq!(artist.popularity, Some(42));
+    assert_eq!(artist.followers.as_ref().map(|f| f.total), Some(1000));
+}
+
+#[test]
+fn test_artist_deserializes_legacy_cache_entry() {
+    let json = r#"{"id":"a","name":"Artist A","genres":["rock"]}"#;
+    let artist: Artist = serde_json::from_str(json).unwrap();
+
+    assert_eq!(artist.genres, vec!["rock"]);
+    assert!(artist.popularity.is_none());
+    assert!(artist.images.is_empty());
+    assert!(artist.external_urls.spotify.is_none());
+}
+
+#[test]
+fn test_add_releases_keeps_first_seen_timestamp() {
+    let a = create_test_artist("a", "Artist A");
+    let mut manager = ArtistReleaseManager::new(Some(vec![ArtistReleases {
+        artist: a.clone(),
+        releases: Vec::new(),
+    }]));
+
+    manager.add_releases_to_artist("a", vec![create_test_album("old", &a)]);
+    let first_seen = manager.get_releases_for_artist("a").unwrap()[0].first_seen_at;
+    assert!(first_seen.is_some());
+
+    // Replacing the releases keeps the timestamp of already known releases
+    let mut known = create_test_album("old", &a);
+    known.first_seen_at = None;
+    manager.add_releases_to_artist("a", vec![known, create_test_album("new", &a)]);
+
+    let releases = manager.get_releases_for_artist("a").unwrap();
+    let old = releases.iter().find(|r| r.id == "old").unwrap();
+    let new = releases.iter().find(|r| r.id == "new").unwrap();
+    assert_eq!(old.first_seen_at, first_seen);
+    assert!(new.first_seen_at >= first_seen);
+}
+
+#[test]
+fn test_releases_first_seen_since_dedupes_shared_releases() {
+    let a = create_test_artist("a", "Artist A");
+    let b = create_test_artist("b", "Artist B");
+
+    let mut shared_a = create_test_album("shared", &a);
+    shared_a.first_seen_at = Some(200);
+    let mut shared_b = create_test_album("shared", &b);
+    shared_b.first_seen_at = Some(150);
+    let mut old = create_test_album("old", &a);
+    old.first_seen_at = Some(50);
+
+    let manager = ArtistReleaseManager::new(Some(vec![
+        ArtistReleases {
+            artist: a,
+            releases: vec![shared_a, old],
+        },
+        ArtistReleases {
+            artist: b,
+            releases: vec![shared_b],
+        },
+    ]));
+
+    let new_releases = manager.releases_first_seen_since(100);
+    assert_eq!(new_releases.len(), 1);
+    assert_eq!(new_releases[0].id, "shared");
+    assert_eq!(new_releases[0].first_seen_at, Some(150));
+}
+
+#[test]
+fn test_state_manager_checkpoint_matches_run_params() {
+    let albums = RunParams {
+        release_types: "album".to_string(),
+        force: false,
+    };
+    let singles = RunParams {
+        release_types: "single".to_string(),
+        force: false,
+    };
+
+    let mut state = StateManager::new(STATE_TYPE_RELEASES.to_string());
+    assert!(!state.matches(&albums));
+
+    state.start(singles.clone());
+    assert!(state.matches(&singles));
+    assert!(!state.matches(&albums));
+    assert!(!state.get_state().run_id.is_empty());
+    assert!(state.is_empty());
+}
+
+#[test]
+fn test_state_manager_tracks_completed_and_failed_items() {
+    let mut state = StateManager::new(STATE_TYPE_RELEASES.to_string());
+
+    state.fail("a".to_string(), "502 Bad Gateway".to_string());
+    assert!(!state.has("a".to_string()));
+    assert!(!state.is_empty());
+
+    state.add("a".to_string());
+    state.add("a".to_string());
+    assert!(state.has("a".to_string()));
+    assert_eq!(state.get_state().completed.len(), 1);
+    assert!(state.get_state().failed.is_empty());
+}
+
+#[test]
+fn test_add_releases_merges_by_id_across_kinds() {
+    let a = create_test_artist("a", "Artist A");
+    let mut manager = create_test_manager(std::slice::from_ref(&a));
+
+    let mut single = create_test_album("single", &a);
+    single.album_type = "single".to_string();
+    single.album_group = Some("single".to_string());
+    manager.add_releases_to_artist("a", vec![single]);
+
+    let releases = manager.get_releases_for_artist("a").unwrap();
+    assert_eq!(releases.len(), 2);
+    assert!(releases.iter().any(|r| r.id == "a_album"));
+
+    // Re-fetching a known release updates it instead of duplicating it
+    let mut renamed = create_test_album("single", &a);
+    renamed.name = "Renamed".to_string();
+    manager.add_releases_to_artist("a", vec![renamed]);
+
+    let releases = manager.get_releases_for_artist("a").unwrap();
+    let single = releases.iter().find(|r| r.id == "single").unwrap();
+    assert_eq!(releases.len(), 2);
+    assert_eq!(single.name, "Renamed");
+    assert_eq!(single.album_group.as_deref(), Some("single"));
+}
+
+#[test]
+fn test_prune_releases_only_touches_fetched_kinds() {
+    let a = create_test_artist("a", "Artist A");
+    let mut manager = create_test_manager(std::slice::from_ref(&a));
+
+    let mut single = create_test_album("single", &a);
+    single.album_group = Some("single".to_string());
+    manager.add_releases_to_artist("a", vec![single]);
+
+    let singles = parse_release_kinds("single").unwrap();
+    let removed = manager.prune_releases_for_artist("a", &singles, &HashSet::new());
+
+    let releases = manager.get_releases_for_artist("a").unwrap();
+    assert_eq!(removed, 1);
+    assert_eq!(releases.len(), 1);
+    assert_eq!(releases[0].id, "a_album");
+}
+
+#[test]
+fn test_count_unknown_releases() {
+    let artist = create_test_artist("a1", "Artist");
+    let mut manager = create_test_manager(std::slice::from_ref(&artist));
+    manager.add_releases_to_artist("a1", vec![create_test_album("r1", &artist)]);
+
+    let fetched = vec![
+        create_test_album("r1", &artist),
+        create_test_album("r2", &artist),
+    ];
+    assert_eq!(manager.count_unknown_releases("a1", &fetched), 1);
+    assert_eq!(manager.count_unknown_releases("unknown", &fetched), 2);
+}
+
+#[test]
+fn test_retry_queue_records_attempts_and_removes_recovered_artists() {
+    let mut queue = RetryQueueManager::new(Vec::new());
+    assert!(queue.is_empty());
+
+    queue
+        .record("a1", "Artist", "503 Service Unavailable", false)
+        .record("a2", "Gone", "404 Not Found", true)
+        .record("a1", "Artist", "404 Not Found", true);
+
+    assert_eq!(queue.entries().len(), 2);
+    let first = &queue.entries()[0];
+    assert_eq!(first.artist_id, "a1");
+    assert_eq!(first.attempts, 2);
+    assert_eq!(first.error, "404 Not Found");
+    assert!(first.permanent);
+
+    assert!(queue.remove("a1"));
+    assert!(!queue.remove("a1"));
+    assert!(!queue.contains("a1"));
+    assert!(queue.contains("a2"));
+}
+
+#[test]
+fn test_genre_counts_and_genres_by_artist() {
+    let mut a = create_test_artist("a", "Artist A");
+    a.genres = vec!["techno".to_string(), "house".to_string()];
+    let mut b = create_test_artist("b", "Artist B");
+    b.genres = vec!["techno".to_string()];
+    let mut manager = create_test_manager(&[a.clone(), b.clone()]);
+
+    // a release credited to both techno artists counts once for techno
+    let mut shared = create_test_album("shared", &a);
+    shared.artists.push(AlbumArtist {
+        id: "b".to_string(),
+        name: "Artist B".to_string(),
+    });
+    manager.add_releases_to_artist("a", vec![shared.clone()]);
+    manager.add_releases_to_artist("b", vec![shared]);
+
+    assert_eq!(
+        manager.genre_counts(),
+        vec![("techno".to_string(), 2, 3), ("house".to_string(), 1, 2),]
+    );
+    assert_eq!(manager.genres_by_artist()["b"], vec!["techno".to_string()]);
+}
+
+#[test]
+fn test_find_artist_by_id_name_and_unique_substring() {
+    let manager = create_test_manager(&[
+        create_test_artist("a1", "Radiohead"),
+        create_test_artist("a2", "Radio Moscow"),
+        create_test_artist("a3", "Portishead"),
+    ]);
+
+    assert_eq!(manager.find_artist("a2").unwrap().name, "Radio Moscow");
+    assert_eq!(manager.find_artist("RADIOHEAD").unwrap().id, "a1");
+    assert_eq!(manager.find_artist("portis").unwrap().id, "a3");
+    assert!(
+        manager
+            .find_artist("radio")
+            .unwrap_err()
+            .contains("several")
+    );
+    assert!(manager.find_artist("unknown").is_err());
+}
+
+#[test]
+fn test_find_releases_prefers_exact_names_and_dedupes() {
+    let a = create_test_artist("a", "Artist A");
+    let b = create_test_artist("b", "Artist B");
+    let shared = create_test_album("shared", &a);
+    let mut artist_release = ArtistReleaseManager::new(Some(vec![
+        ArtistReleases {
+            artist: a.clone(),
+            releases: vec![create_test_album("a1", &a), shared.clone()],
+        },
+        ArtistReleases {
+            artist: b.clone(),
+            releases: vec![create_test_album("a11", &b), shared],
+        },
+    ]));
+
+    assert_eq!(artist_release.find_releases("album").len(), 3);
+    let exact = artist_release.find_releases("ALBUM A1");
+    assert_eq!(exact.len(), 1);
+    assert_eq!(exact[0].id, "a1");
+    assert_eq!(artist_release.find_releases("shared").len(), 1);
+    assert!(artist_release.find_releases("unknown").is_empty());
+
+    artist_release = ArtistReleaseManager::new(None);
+    assert!(artist_release.find_releases("album").is_empty());
+}
+
+#[test]
+fn test_artist_rules_hide_releases_of_muted_owners_only() {
+    let a = create_test_artist("a", "Artist A");
+    let b = create_test_artist("b", "Artist B");
+    let mut manager = create_test_manager(&[a.clone(), b.clone()]);
+    let mut single = create_test_album("single", &a);
+    single.album_type = "single".to_string();
+    manager.add_releases_to_artist("a", vec![single.clone()]);
+    // a's release that b only appears on
+    let shared = create_test_album("shared", &a);
+    manager.add_releases_to_artist("a", vec![shared.clone()]);
+    manager.add_releases_to_artist("b", vec![shared.clone()]);
+
+    let mute = ArtistRule {
+        artist_id: "a".to_string(),
+        artist_name: "Artist A".to_string(),
+        mode: ArtistRuleMode::Mute,
+        types: Vec::new(),
+    };
+    let mut rules = ArtistRulesManager::new(vec![mute]).with_owners(manager.release_owners());
+    let ep_rule = EpRule::default();
+
+    assert!(!rules.permits(&create_test_album("a_album", &a), &ep_rule));
+    assert_eq!(
+        rules.hidden_reason(&create_test_album("a_album", &a), &ep_rule),
+        Some("artist rule: Artist A muted".to_string())
+    );
+    assert!(rules.permits(&shared, &ep_rule));
+    assert!(rules.permits(&create_test_album("b_album", &b), &ep_rule));
+
+    // setting a rule again replaces it
+    rules.set(ArtistRule {
+        artist_id: "a".to_string(),
+        artist_name: "Artist A".to_string(),
+        mode: ArtistRuleMode::Allow,
+        types: vec![ReleaseKind::Album],
+    });
+    assert_eq!(rules.rules().len(), 1);
+    assert!(rules.permits(&create_test_album("a_album", &a), &ep_rule));
+    assert!(!rules.permits(&single, &ep_rule));
+
+    assert_eq!(rules.remove("a").unwrap().describe(), "only album");
+    assert!(rules.remove("a").is_none());
+    assert!(rules.permits(&single, &ep_rule));
+}
+
+#[test]
+fn test_edition_index_keeps_facts_of_cached_releases() {
+    let response: GetSeveralAlbumsResponse = serde_json::from_str(
+        r#"{"albums": [
+            {"id": "a_album", "name": "Album", "release_date": "2023-10-20",
+             "artists": [], "external_ids": {"upc": "602445790012"},
+             "tracks": {"items": [
+                {"id": "t1", "name": "One", "uri": "spotify:track:t1", "explicit": true}
+             ]}},
+            {"id": "gone", "name": "Gone", "release_date": "2023-10-20",
+             "artists": [], "external_ids": {}, "tracks": {"items": []}}
+        ]}"#,
+    )
+    .unwrap();
+    let a = create_test_artist("a", "Artist A");
+    let manager = create_test_manager(&[a]);
+
+    let mut editions = EditionIndexManager::new(HashMap::new());
+    for album in &response.albums {
+        editions.insert(album);
+    }
+    assert_eq!(editions.len(), 2);
+
+    assert_eq!(editions.retain_albums(&manager.release_ids()), 1);
+    assert_eq!(
+        editions.upcs(),
+        HashMap::from([("a_album".to_string(), "602445790012".to_string())])
+    );
+    assert_eq!(
+        editions.explicit_album_ids(),
+        HashSet::from(["a_album".to_string()])
+    );
+}
@@ -0,0 +1,220 @@
+use chrono::NaiveDate;
+use sporlcli::output::{
+    AlbumRecord, ArtistCountRecord, OUTPUT_VERSION, ReleaseRecord, WeekRecord, heading, render,
+};
+use sporlcli::types::{Album, AlbumArtist, GetSeveralAlbumsResponse, OutputFormat};
+use sporlcli::utils::{EpRule, WeekScheme, build_week};
+use std::collections::HashSet;
+
+fn create_test_album() -> Album {
+    Album {
+        id: "r1".to_string(),
+        name: "Summer \"Live\", Vol. 1".to_string(),
+        release_date: "2025-08-08".to_string(),
+        release_date_precision: "day".to_string(),
+        album_type: "album".to_string(),
+        album_group: Some("appears_on".to_string()),
+        artists: vec![
+            AlbumArtist {
+                id: "a1".to_string(),
+                name: "Alpha".to_string(),
+            },
+            AlbumArtist {
+                id: "a2".to_string(),
+                name: "Beta".to_string(),
+            },
+        ],
+        total_tracks: None,
+        duration_ms: None,
+        first_seen_at: Some(1754600000),
+    }
+}
+
+fn create_test_record() -> ReleaseRecord {
+    let week = build_week(NaiveDate::from_ymd_opt(2025, 8, 8).unwrap());
+    let followed = HashSet::from(["a2".to_string()]);
+    ReleaseRecord::new(
+        &create_test_album(),
+        Some(&week),
+        &followed,
+        &EpRule::default(),
+    )
+}
+
+#[test]
+fn test_render_json_is_versioned() {
+    let rendered = render(&[create_test_record()], OutputFormat::Json).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&rendered).unwrap();
+
+    assert_eq!(json["version"], OUTPUT_VERSION);
+    assert_eq!(json["kind"], "releases");
+
+    let item = &json["items"][0];
+    assert_eq!(item["id"], "r1");
+    assert_eq!(item["album_type"], "album");
+    assert_eq!(item["album_group"], "appears_on");
+    assert_eq!(item["release_date_precision"], "day");
+    assert_eq!(item["week"], 31);
+    assert_eq!(item["year"], 2025);
+    assert_eq!(item["artists"][1]["id"], "a2");
+}
+
+#[test]
+fn test_render_ndjson_one_record_per_line() {
+    let records = vec![create_test_record(), create_test_record()];
+    let rendered = render(&records, OutputFormat::Ndjson).unwrap();
+
+    let lines: Vec<&str> = rendered.lines().collect();
+    assert_eq!(lines.len(), 2);
+    let record: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+    assert_eq!(record["name"], "Summer \"Live\", Vol. 1");
+
+    assert_eq!(
+        render::<ReleaseRecord>(&[], OutputFormat::Ndjson).unwrap(),
+        ""
+    );
+}
+
+#[test]
+fn test_render_csv_quotes_fields() {
+    let rendered = render(&[create_test_record()], OutputFormat::Csv).unwrap();
+    let lines: Vec<&str> = rendered.lines().collect();
+
+    assert!(lines[0].starts_with("id,name,album_type,album_group,release_date"));
+    assert_eq!(
+        lines[1],
+        "r1,\"Summer \"\"Live\"\", Vol. 1\",album,appears_on,2025-08-08,day,31,2025,Alpha; Beta,a1; a2,1754600000,album,,,,a2,"
+    );
+}
+
+#[test]
+fn test_render_markdown_and_table() {
+    let markdown = render(&[create_test_record()], OutputFormat::Markdown).unwrap();
+    assert!(markdown.starts_with("| date"));
+    assert!(markdown.contains("| Alpha; Beta* "));
+
+    let table = render(&[create_test_record()], OutputFormat::Table).unwrap();
+    assert!(table.contains("2025-08-08"));
+
+    assert_eq!(heading("Title", OutputFormat::Markdown), "## Title\n");
+    assert_eq!(heading("Title", OutputFormat::Table), "Title");
+}
+
+#[test]
+fn test_week_record() {
+    let week = WeekScheme::Iso.build_week(NaiveDate::from_ymd_opt(2025, 8, 6).unwrap());
+    let record = WeekRecord::new(&week, WeekScheme::Iso);
+
+    assert_eq!(record.label, "2025-W32");
+    assert_eq!(record.scheme, "iso");
+    assert_eq!(record.start, "2025-08-04");
+    assert_eq!(record.end, "2025-08-10");
+}
+
+#[test]
+fn test_output_format_is_machine_readable() {
+    assert!(OutputFormat::Json.is_machine_readable());
+    assert!(OutputFormat::Ndjson.is_machine_readable());
+    assert!(OutputFormat::Csv.is_machine_readable());
+    assert!(!OutputFormat::Table.is_machine_readable());
+    assert!(!OutputFormat::Markdown.is_machine_readable());
+}
+
+#[test]
+fn test_artist_count_record_offline() {
+    let record = ArtistCountRecord {
+        cache: 42,
+        remote: None,
+        cache_updated_at: Some("2025-08-08T10:00:00Z".to_string()),
+    };
+
+    let csv = render(std::slice::from_ref(&record), OutputFormat::Csv).unwrap();
+    assert_eq!(
+        csv,
+        "cache,remote,cache_updated_at\n42,,2025-08-08T10:00:00Z"
+    );
+
+    let json = render(&[record], OutputFormat::Ndjson).unwrap();
+    assert!(json.contains("\"remote\":null"));
+}
+
+#[test]
+fn test_album_record_from_album_details() {
+    let response: GetSeveralAlbumsResponse = serde_json::from_str(
+        r#"{"albums": [null, {
+            "id": "r1", "name": "Summer", "release_date": "2025-08-08",
+            "album_type": "album", "label": "Label",
+            "artists": [{"id": "a1", "name": "Alpha"}],
+            "external_ids": {"upc": "602445790012"},
+            "copyrights": [{"text": "2025 Label", "type": "P"}],
+            "tracks": {"total": 2, "items": [
+                {"id": "t1", "name": "One", "uri": "spotify:track:t1", "duration_ms": 215000,
+                 "track_number": 1, "disc_number": 1, "explicit": true,
+                 "artists": [{"id": "a1", "name": "Alpha"}]},
+                {"id": "t2", "name": "Two", "uri": "spotify:track:t2", "duration_ms": 185000,
+                 "track_number": 1, "disc_number": 2}
+            ]}
+        }]}"#,
+    )
+    .unwrap();
+    assert_eq!(response.albums.len(), 1);
+
+    let followed = HashSet::from(["a1".to_string()]);
+    let record = AlbumRecord::new(&response.albums[0], &followed);
+    assert_eq!(record.upc.as_deref(), Some("602445790012"));
+    assert_eq!(record.total_tracks, Some(2));
+    assert_eq!(record.duration_ms, Some(400000));
+    assert_eq!(record.followed_artist_ids, vec!["a1"]);
+
+    // a further page of tracks leaves the album incomplete
+    let mut paged = response.albums[0].clone();
+    paged.tracks.next = Some("https://api.spotify.com/v1/albums/r1/tracks?offset=2".to_string());
+    assert!(response.albums[0].has_all_tracks());
+    assert!(!paged.has_all_tracks());
+    assert_eq!(paged.duration_ms(), None);
+
+    let fields: Vec<(String, String)> = record
+        .field_rows()
+        .into_iter()
+        .map(|row| (row.field, row.value))
+        .collect();
+    assert!(fields.contains(&("duration".to_string(), "6:40".to_string())));
+    assert!(fields.contains(&("℗".to_string(), "2025 Label".to_string())));
+    assert!(!fields.iter().any(|(field, _)| field == "url"));
+
+    let tracks = render(&record.tracks, OutputFormat::Markdown).unwrap();
+    assert!(
+        tracks.contains("| 1   | One [E] | Alpha   | 3:35     |"),
+        "{}",
+        tracks
+    );
+    assert!(tracks.contains("| 2-1 | Two     |"), "{}", tracks);
+}
+
+#[test]
+fn test_release_record_from_editions() {
+    let album = create_test_album();
+    let deluxe = Album {
+        id: "r1d".to_string(),
+        name: "Summer \"Live\", Vol. 1 (Deluxe)".to_string(),
+        ..create_test_album()
+    };
+    let week = build_week(NaiveDate::from_ymd_opt(2025, 8, 8).unwrap());
+    let record = ReleaseRecord::from_editions(
+        &[album, deluxe],
+        Some(&week),
+        &HashSet::new(),
+        &EpRule::default(),
+    );
+    assert_eq!(record.id, "r1");
+    assert_eq!(record.edition_ids, vec!["r1d"]);
+
+    let csv = render(std::slice::from_ref(&record), OutputFormat::Csv).unwrap();
+    assert!(csv.lines().nth(1).unwrap().ends_with(",r1d"), "{}", csv);
+    let table = render(&[record], OutputFormat::Markdown).unwrap();
+    assert!(
+        table.contains("| Summer \"Live\", Vol. 1 (2 editions) |"),
+        "{}",
+        table
+    );
+}
@@ -0,0 +1,117 @@
+use std::time::Duration;
+
+use serde_json::Value;
+use sporlcli::{
+    logging::Kind,
+    progress::{
+        ArtistStatus, EVENT_VERSION, Event, ProgressMode, WaitReason, estimate_eta, event_line,
+        format_duration,
+    },
+    types::UpdateSummary,
+};
+
+#[test]
+fn test_progress_mode_resolve() {
+    assert_eq!(ProgressMode::Auto.resolve(true), ProgressMode::Bar);
+    assert_eq!(ProgressMode::Auto.resolve(false), ProgressMode::Plain);
+    assert_eq!(ProgressMode::Bar.resolve(false), ProgressMode::Bar);
+    assert_eq!(ProgressMode::Plain.resolve(true), ProgressMode::Plain);
+    assert_eq!(ProgressMode::Json.resolve(true), ProgressMode::Json);
+}
+
+#[test]
+fn test_event_line_artist_finished() {
+    let line = event_line(&Event::ArtistFinished {
+        artist_id: "a1".to_string(),
+        artist_name: "Alpha".to_string(),
+        position: 3,
+        total: 10,
+        status: ArtistStatus::Failed,
+        error: Some("timeout".to_string()),
+    });
+    assert!(!line.contains('\n'));
+
+    let json: Value = serde_json::from_str(&line).unwrap();
+    assert_eq!(json["version"], EVENT_VERSION);
+    assert!(json["ts"].as_str().unwrap().ends_with('Z'));
+    assert_eq!(json["event"], "artist_finished");
+    assert_eq!(json["artist_id"], "a1");
+    assert_eq!(json["position"], 3);
+    assert_eq!(json["total"], 10);
+    assert_eq!(json["status"], "failed");
+    assert_eq!(json["error"], "timeout");
+}
+
+#[test]
+fn test_event_line_wait_and_message() {
+    let json: Value = serde_json::from_str(&event_line(&Event::RateLimitWait {
+        seconds: 30,
+        reason: WaitReason::RetryAfter,
+    }))
+    .unwrap();
+    assert_eq!(json["event"], "rate_limit_wait");
+    assert_eq!(json["seconds"], 30);
+    assert_eq!(json["reason"], "retry_after");
+
+    let json: Value = serde_json::from_str(&event_line(&Event::Message {
+        kind: Kind::Success,
+        message: "Release cache updated.".to_string(),
+    }))
+    .unwrap();
+    assert_eq!(json["event"], "message");
+    assert_eq!(json["kind"], "success");
+    assert_eq!(json["message"], "Release cache updated.");
+}
+
+#[test]
+fn test_estimate_eta() {
+    let secs = Duration::from_secs;
+
+    assert_eq!(estimate_eta(0, 10, secs(5), secs(0), secs(0)), None);
+    assert_eq!(
+        estimate_eta(10, 10, secs(5), secs(0), secs(30)),
+        Some(secs(0))
+    );
+    // 2s per item, no waits
+    assert_eq!(
+        estimate_eta(5, 10, secs(10), secs(0), secs(0)),
+        Some(secs(10))
+    );
+    // throttle waits are excluded from the time per item and planned instead
+    assert_eq!(
+        estimate_eta(10, 20, secs(40), secs(20), secs(30)),
+        Some(secs(50))
+    );
+}
+
+#[test]
+fn test_format_duration() {
+    assert_eq!(format_duration(Duration::from_secs(0)), "00:00:00");
+    assert_eq!(format_duration(Duration::from_secs(75)), "00:01:15");
+    assert_eq!(format_duration(Duration::from_secs(3725)), "01:02:05");
+}
+
+#[test]
+fn test_event_line_update_finished() {
+    let summary = UpdateSummary {
+        fetched: 7,
+        skipped: 2,
+        failed: 1,
+        new_releases: 4,
+        weeks: 12,
+    };
+    let json: Value = serde_json::from_str(&event_line(&Event::UpdateFinished {
+        summary,
+        elapsed_seconds: 95,
+        interrupted: true,
+    }))
+    .unwrap();
+    assert_eq!(json["event"], "update_finished");
+    assert_eq!(json["fetched"], 7);
+    assert_eq!(json["skipped"], 2);
+    assert_eq!(json["failed"], 1);
+    assert_eq!(json["new_releases"], 4);
+    assert_eq!(json["weeks"], 12);
+    assert_eq!(json["elapsed_seconds"], 95);
+    assert_eq!(json["interrupted"], true);
+}
@@ -0,0 +1,31 @@
+use std::time::{Duration, Instant};
+
+use sporlcli::{
+    progress::{self, WaitReason},
+    shutdown,
+};
+
+#[tokio::test]
+async fn test_request_cuts_throttle_wait_short() {
+    assert!(!shutdown::requested());
+
+    let waiter = tokio::spawn(async {
+        let started = Instant::now();
+        progress::wait(Duration::from_secs(60), WaitReason::Throttle).await;
+        started.elapsed()
+    });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    shutdown::request();
+
+    let waited = tokio::time::timeout(Duration::from_secs(5), waiter)
+        .await
+        .expect("throttle wait did not end on shutdown request")
+        .unwrap();
+    assert!(waited < Duration::from_secs(5));
+    assert!(shutdown::requested());
+
+    // once requested, waiting for the interruption completes immediately
+    tokio::time::timeout(Duration::from_secs(1), shutdown::interrupted())
+        .await
+        .expect("interrupted() did not complete after the request");
+}
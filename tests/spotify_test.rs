@@ -0,0 +1,24 @@
+use reqwest::StatusCode;
+use sporlcli::spotify::ErrorClass;
+
+#[test]
+fn test_error_class_from_status() {
+    for status in [
+        StatusCode::BAD_REQUEST,
+        StatusCode::FORBIDDEN,
+        StatusCode::NOT_FOUND,
+    ] {
+        assert_eq!(ErrorClass::from_status(status), ErrorClass::Permanent);
+    }
+
+    for status in [
+        StatusCode::UNAUTHORIZED,
+        StatusCode::REQUEST_TIMEOUT,
+        StatusCode::TOO_MANY_REQUESTS,
+        StatusCode::INTERNAL_SERVER_ERROR,
+        StatusCode::BAD_GATEWAY,
+        StatusCode::SERVICE_UNAVAILABLE,
+    ] {
+        assert_eq!(ErrorClass::from_status(status), ErrorClass::Transient);
+    }
+}
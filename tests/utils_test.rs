@@ -1,7 +1,9 @@
-use chrono::{Datelike, Duration, NaiveDate, Utc, Weekday};
-use sporlcli::types::{Album, AlbumArtist, ReleaseTableRow};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use sporlcli::types::{
+    Album, AlbumArtist, ArtistFilter, GenreFilter, ReleaseTypeFilter, WeekSelection,
+};
 use sporlcli::utils::*;
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 // Helper function to create a test album
 fn create_test_album(id: &str, name: &str, release_date: &str, artist_name: &str) -> Album {
@@ -11,19 +13,14 @@ fn create_test_album(id: &str, name: &str, release_date: &str, artist_name: &str
         release_date: release_date.to_string(),
         release_date_precision: "day".to_string(),
         album_type: "album".to_string(),
+        album_group: None,
         artists: vec![AlbumArtist {
             id: format!("{}_artist_id", id),
             name: artist_name.to_string(),
         }],
-    }
-}
-
-// Helper function to create a test release table row
-fn create_test_release_row(date: &str, name: &str, artists: &str) -> ReleaseTableRow {
-    ReleaseTableRow {
-        date: date.to_string(),
-        name: name.to_string(),
-        artists: artists.to_string(),
+        total_tracks: None,
+        duration_ms: None,
+        first_seen_at: None,
     }
 }
 
@@ -109,6 +106,51 @@ fn test_build_week() {
     assert!(week.week >= 1);
 }
 
+#[test]
+fn test_build_week_spanning_new_year_belongs_to_previous_year() {
+    // Saturday, December 28th 2024 - Friday, January 3rd 2025
+    for day in [
+        NaiveDate::from_ymd_opt(2024, 12, 28).unwrap(),
+        NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+        NaiveDate::from_ymd_opt(2025, 1, 3).unwrap(),
+    ] {
+        let week = build_week(day);
+        assert_eq!(week.week, 52);
+        assert_eq!(week.year, 2024);
+        assert_eq!(get_release_week_year(day), 2024);
+    }
+
+    // Saturday, January 4th 2025 starts week 1 of 2025
+    let week = build_week(NaiveDate::from_ymd_opt(2025, 1, 4).unwrap());
+    assert_eq!(week.week, 1);
+    assert_eq!(week.year, 2025);
+}
+
+#[test]
+fn test_build_week_when_new_year_is_saturday() {
+    // January 1st 2022 is a Saturday and starts week 1 of 2022
+    let week = build_week(NaiveDate::from_ymd_opt(2022, 1, 1).unwrap());
+    assert_eq!(week.week, 1);
+    assert_eq!(week.year, 2022);
+
+    // Friday, December 31st 2021 belongs to the last week of 2021
+    let week = build_week(NaiveDate::from_ymd_opt(2021, 12, 31).unwrap());
+    assert_eq!(week.year, 2021);
+    assert_eq!(week.week, 52);
+}
+
+#[test]
+fn test_get_custom_week_range_spanning_new_year() {
+    // Friday, January 10th 2025 includes the current week
+    let friday = NaiveDate::from_ymd_opt(2025, 1, 10).unwrap();
+    let weeks: Vec<(u32, i32)> = get_custom_week_range(friday, 3)
+        .iter()
+        .map(|w| (w.week, w.year))
+        .collect();
+
+    assert_eq!(weeks, vec![(1, 2025), (52, 2024), (51, 2024), (50, 2024)]);
+}
+
 #[test]
 fn test_get_custom_week_range() {
     let test_date = NaiveDate::from_ymd_opt(2023, 10, 17).unwrap(); // Tuesday
@@ -150,23 +192,30 @@ fn test_remove_duplicate_albums() {
 }
 
 #[test]
-fn test_sort_release_table_rows() {
-    let mut rows = vec![
-        create_test_release_row("2023-10-01", "Album A", "Artist Z"),
-        create_test_release_row("2023-10-03", "Album C", "Artist A"),
-        create_test_release_row("2023-10-01", "Album B", "Artist A"), // Same date, different artist
-        create_test_release_row("2023-10-02", "Album D", "Artist B"),
-    ];
-
-    sort_release_table_rows(&mut rows);
-
-    // Should be sorted by date descending, then by artist ascending
-    assert_eq!(rows[0].date, "2023-10-03"); // Most recent
-    assert_eq!(rows[1].date, "2023-10-02");
-    assert_eq!(rows[2].date, "2023-10-01");
-    assert_eq!(rows[2].artists, "Artist A"); // Earlier alphabetically
-    assert_eq!(rows[3].date, "2023-10-01");
-    assert_eq!(rows[3].artists, "Artist Z"); // Later alphabetically
+fn test_followed_artists_in_sorting_formatting_and_filter() {
+    let mut featuring = create_test_album("1", "Featuring", "2025-08-08", "Guest");
+    featuring.artists.push(AlbumArtist {
+        id: "followed".to_string(),
+        name: "Zed".to_string(),
+    });
+    let other = create_test_album("2", "Other", "2025-08-08", "Mid");
+    let followed = HashSet::from(["followed".to_string()]);
+
+    assert_eq!(primary_artist(&featuring, &followed).unwrap().name, "Zed");
+    assert_eq!(format_artists(&featuring.artists, &followed), "Guest; Zed*");
+
+    let mut albums = vec![featuring.clone(), other.clone()];
+    sort_albums_by_date_and_artist(&mut albums, &followed);
+    assert_eq!(albums[0].id, "2");
+    sort_albums_by_date_and_artist(&mut albums, &HashSet::new());
+    assert_eq!(albums[0].id, "1");
+
+    let filter = ArtistFilter {
+        artists: vec!["zed".to_string()],
+    };
+    assert!(filter.matches(&featuring));
+    assert!(!filter.matches(&other));
+    assert!(ArtistFilter::default().matches(&other));
 }
 
 #[test]
@@ -174,17 +223,184 @@ fn test_get_date_from_string() {
     // Test valid date string
     let valid_date = get_date_from_string(Some("2023-10-17".to_string()));
     let expected = NaiveDate::from_ymd_opt(2023, 10, 17).unwrap();
-    assert_eq!(valid_date, expected);
+    assert_eq!(valid_date, Ok(expected));
 
     // Test None input (should return current date)
     let current_date = get_date_from_string(None);
-    let today = Utc::now().date_naive();
-    assert_eq!(current_date, today);
+    assert_eq!(current_date, Ok(sporlcli::clock::today()));
 
-    // Test invalid date string (should return current date)
+    // Test invalid date string (should report the bad date)
     let invalid_date = get_date_from_string(Some("invalid-date".to_string()));
-    let today = Utc::now().date_naive();
-    assert_eq!(invalid_date, today);
+    assert!(invalid_date.unwrap_err().contains("invalid-date"));
+}
+
+#[test]
+fn test_parse_week() {
+    let week = parse_week("2025/32").unwrap();
+    assert_eq!((week.week, week.year), (32, 2025));
+    assert_eq!(week, build_week(week.dates[3]));
+
+    let iso = parse_week("2025-W32").unwrap();
+    assert_eq!(iso.dates, week.dates);
+
+    // Week 1 of 2025 starts on Saturday, January 4th
+    let first = parse_week("2025-W01").unwrap();
+    assert_eq!(first.dates[0], NaiveDate::from_ymd_opt(2025, 1, 4).unwrap());
+
+    // Week 52 of 2024 spans New Year
+    let last = parse_week("2024/52").unwrap();
+    assert_eq!(last.dates[6], NaiveDate::from_ymd_opt(2025, 1, 3).unwrap());
+
+    assert!(parse_week("2025/0").is_err());
+    assert!(parse_week("2024/53").is_err());
+    assert!(parse_week("2025-32").is_err());
+    assert!(parse_week("week 32").is_err());
+}
+
+#[test]
+fn test_get_week_range_between() {
+    let from = NaiveDate::from_ymd_opt(2024, 12, 20).unwrap(); // Friday
+    let to = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap(); // Monday
+    let weeks: Vec<(u32, i32)> = get_week_range_between(from, to)
+        .unwrap()
+        .iter()
+        .map(|w| (w.week, w.year))
+        .collect();
+
+    assert_eq!(weeks, vec![(1, 2025), (52, 2024), (51, 2024), (50, 2024)]);
+    assert!(get_week_range_between(to, from).is_err());
+}
+
+#[test]
+fn test_week_scheme_friday_thursday() {
+    let scheme = WeekScheme::FridayThursday;
+    let date = NaiveDate::from_ymd_opt(2025, 8, 6).unwrap(); // Wednesday
+
+    let week = scheme.build_week(date);
+    assert_eq!(week.week, 31);
+    assert_eq!(week.year, 2025);
+    assert_eq!(week.dates[0], NaiveDate::from_ymd_opt(2025, 8, 1).unwrap());
+    assert_eq!(week.dates[0].weekday(), Weekday::Fri);
+    assert_eq!(week.dates[6].weekday(), Weekday::Thu);
+
+    // January 1st 2027 is a Friday and starts week 1
+    let new_year = scheme.build_week(NaiveDate::from_ymd_opt(2027, 1, 1).unwrap());
+    assert_eq!((new_year.week, new_year.year), (1, 2027));
+
+    // January 2nd 2025 belongs to the last week of 2024
+    let spanning = scheme.build_week(NaiveDate::from_ymd_opt(2025, 1, 2).unwrap());
+    assert_eq!((spanning.week, spanning.year), (52, 2024));
+
+    assert_eq!(scheme.week_from_number(2025, 31).unwrap(), week);
+    assert!(scheme.week_from_number(2025, 0).is_err());
+}
+
+#[test]
+fn test_week_scheme_iso() {
+    let scheme = WeekScheme::Iso;
+    let week = scheme.build_week(NaiveDate::from_ymd_opt(2025, 8, 6).unwrap());
+    assert_eq!((week.week, week.year), (32, 2025));
+    assert_eq!(week.dates[0], NaiveDate::from_ymd_opt(2025, 8, 4).unwrap());
+    assert_eq!(week.dates[6].weekday(), Weekday::Sun);
+
+    // December 30th 2024 is in the first ISO week of 2025
+    let first = scheme.build_week(NaiveDate::from_ymd_opt(2024, 12, 30).unwrap());
+    assert_eq!((first.week, first.year), (1, 2025));
+
+    assert_eq!(scheme.week_from_number(2025, 32).unwrap(), week);
+    assert!(scheme.week_from_number(2026, 53).is_ok());
+    assert!(scheme.week_from_number(2025, 53).is_err());
+}
+
+#[test]
+fn test_week_scheme_matches_default_week_functions() {
+    let scheme = WeekScheme::SaturdayFriday;
+    let date = NaiveDate::from_ymd_opt(2025, 1, 3).unwrap();
+    assert_eq!(scheme, WeekScheme::default());
+    assert_eq!(scheme.build_week(date), build_week(date));
+    assert_eq!(scheme.week_number(date), get_release_week_number(date));
+    assert_eq!(scheme.week_year(date), get_release_week_year(date));
+}
+
+#[test]
+fn test_week_scheme_labels_and_cache_dirs() {
+    assert_eq!(WeekScheme::SaturdayFriday.label(32, 2025), "32/2025");
+    assert_eq!(
+        WeekScheme::FridayThursday.label(32, 2025),
+        "32/2025 (fri-thu)"
+    );
+    assert_eq!(WeekScheme::Iso.label(1, 2025), "2025-W01");
+
+    assert_eq!(WeekScheme::SaturdayFriday.cache_dir(), None);
+    assert_eq!(WeekScheme::FridayThursday.cache_dir(), Some("fri-thu"));
+    assert_eq!(WeekScheme::Iso.cache_dir(), Some("iso"));
+
+    assert_eq!(WeekScheme::FridayThursday.to_string(), "fri-thu");
+}
+
+#[test]
+fn test_resolve_release_weeks() {
+    let selection = WeekSelection {
+        week: Some("2025/32".to_string()),
+        previous_weeks: Some(1),
+        ..Default::default()
+    };
+    let weeks: Vec<u32> = resolve_release_weeks(&selection)
+        .unwrap()
+        .iter()
+        .map(|w| w.week)
+        .collect();
+    assert_eq!(weeks, vec![32, 31]);
+
+    let selection = WeekSelection {
+        from: Some("2025-08-02".to_string()),
+        to: Some("2025-08-08".to_string()),
+        ..Default::default()
+    };
+    let weeks = resolve_release_weeks(&selection).unwrap();
+    assert_eq!(weeks.len(), 1);
+    assert_eq!(weeks[0].week, 31);
+
+    let selection = WeekSelection {
+        release_date: Some("2025-13-01".to_string()),
+        ..Default::default()
+    };
+    assert!(resolve_release_weeks(&selection).is_err());
+}
+
+#[test]
+fn test_release_period_start() {
+    let mut album = create_test_album("1", "Album", "2024-10-18", "Artist");
+    assert_eq!(
+        release_period_start(&album),
+        Ok(NaiveDate::from_ymd_opt(2024, 10, 18).unwrap())
+    );
+
+    album.release_date = "2024-10".to_string();
+    album.release_date_precision = "month".to_string();
+    assert_eq!(
+        release_period_start(&album),
+        Ok(NaiveDate::from_ymd_opt(2024, 10, 1).unwrap())
+    );
+
+    album.release_date = "2024".to_string();
+    album.release_date_precision = "year".to_string();
+    assert_eq!(
+        release_period_start(&album),
+        Ok(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+    );
+
+    album.release_date_precision = "decade".to_string();
+    assert!(release_period_start(&album).is_err());
+}
+
+#[test]
+fn test_parse_timestamp() {
+    assert_eq!(parse_timestamp("1697760000"), Ok(1697760000));
+    assert_eq!(parse_timestamp("2023-10-20"), Ok(1697760000));
+    assert_eq!(parse_timestamp("2023-10-20T02:00:00+02:00"), Ok(1697760000));
+    assert!(parse_timestamp("last friday").is_err());
+    assert!(parse_timestamp("1969-12-31").is_err());
 }
 
 #[test]
@@ -238,9 +454,10 @@ fn test_parse_release_kinds_valid_inputs() {
     // Test "all" keyword
     let result = parse_release_kinds("all").unwrap();
     let kinds: Vec<ReleaseKind> = result.iter().collect();
-    assert_eq!(kinds.len(), 4);
+    assert_eq!(kinds.len(), 5);
     assert!(kinds.contains(&ReleaseKind::Album));
     assert!(kinds.contains(&ReleaseKind::Single));
+    assert!(kinds.contains(&ReleaseKind::Ep));
     assert!(kinds.contains(&ReleaseKind::AppearsOn));
     assert!(kinds.contains(&ReleaseKind::Compilation));
 
@@ -306,9 +523,316 @@ fn test_release_kinds_iter() {
 #[test]
 fn test_release_kind_all_constant() {
     // Ensure ALL constant contains all variants
-    assert_eq!(ReleaseKind::ALL.len(), 4);
+    assert_eq!(ReleaseKind::ALL.len(), 5);
     assert!(ReleaseKind::ALL.contains(&ReleaseKind::Album));
     assert!(ReleaseKind::ALL.contains(&ReleaseKind::Single));
     assert!(ReleaseKind::ALL.contains(&ReleaseKind::AppearsOn));
     assert!(ReleaseKind::ALL.contains(&ReleaseKind::Compilation));
+    assert!(ReleaseKind::ALL.contains(&ReleaseKind::Ep));
+}
+
+#[test]
+fn test_format_age() {
+    assert_eq!(format_age(0), "just now");
+    assert_eq!(format_age(59), "just now");
+    assert_eq!(format_age(60), "1 minute ago");
+    assert_eq!(format_age(2 * 3600 + 59), "2 hours ago");
+    assert_eq!(format_age(86_400), "1 day ago");
+    assert_eq!(format_age(10 * 86_400), "10 days ago");
+}
+
+#[test]
+fn test_release_type_filter() {
+    let mut own_single = create_test_album("1", "Single", "2025-08-08", "Artist");
+    own_single.album_type = "single".to_string();
+    own_single.album_group = Some("single".to_string());
+    let mut feature = create_test_album("2", "Feature", "2025-08-08", "Other");
+    feature.album_type = "single".to_string();
+    feature.album_group = Some("appears_on".to_string());
+    let mut compilation = create_test_album("3", "Hits", "2025-08-08", "Artist");
+    compilation.album_type = "compilation".to_string();
+    let album = create_test_album("4", "Album", "2025-08-08", "Artist");
+
+    let rule = EpRule::default();
+    let all = ReleaseTypeFilter::default();
+    assert!(!all.is_set());
+    assert!(all.matches(&compilation, &rule));
+
+    let singles = ReleaseTypeFilter {
+        types: vec![parse_release_kinds("single").unwrap()],
+        exclude_types: vec![],
+    };
+    assert!(singles.matches(&own_single, &rule));
+    assert!(singles.matches(&feature, &rule));
+    assert!(!singles.matches(&album, &rule));
+
+    let own_singles = ReleaseTypeFilter {
+        types: vec![parse_release_kinds("single").unwrap()],
+        exclude_types: vec![parse_release_kinds("appears_on").unwrap()],
+    };
+    assert!(own_singles.matches(&own_single, &rule));
+    assert!(!own_singles.matches(&feature, &rule));
+
+    let no_compilations = ReleaseTypeFilter {
+        types: vec![],
+        exclude_types: vec![parse_release_kinds("compilation").unwrap()],
+    };
+    assert!(no_compilations.matches(&album, &rule));
+    assert!(!no_compilations.matches(&compilation, &rule));
+}
+
+#[test]
+fn test_release_type_label() {
+    assert_eq!(release_type_label("album", None), "album");
+    assert_eq!(release_type_label("single", Some("single")), "single");
+    assert_eq!(
+        release_type_label("album", Some("appears_on")),
+        "album (appears on)"
+    );
+}
+
+#[test]
+fn test_ep_rule() {
+    let rule = EpRule::default();
+    let mut release = create_test_album("1", "Five Tracks", "2025-08-08", "Artist");
+    release.album_type = "single".to_string();
+    release.album_group = Some("single".to_string());
+
+    // unknown track count: never an EP
+    assert!(!rule.is_ep(&release));
+
+    release.total_tracks = Some(5);
+    assert!(rule.is_ep(&release));
+    assert_eq!(
+        ReleaseKind::classify(&release, &rule),
+        Some(ReleaseKind::Ep)
+    );
+    assert_eq!(release_kind_name(&release, &rule), "ep");
+
+    release.duration_ms = Some(45 * 60 * 1000);
+    assert!(!rule.is_ep(&release));
+    assert_eq!(release_kind_name(&release, &rule), "single");
+
+    release.duration_ms = Some(20 * 60 * 1000);
+    release.total_tracks = Some(2);
+    assert!(!rule.is_ep(&release));
+
+    let strict = EpRule {
+        min_tracks: 2,
+        max_tracks: 3,
+        max_duration_ms: 25 * 60 * 1000,
+    };
+    assert!(strict.is_ep(&release));
+
+    // albums are never EPs
+    release.album_type = "album".to_string();
+    assert!(!strict.is_ep(&release));
+}
+
+#[test]
+fn test_release_kinds_ep_filter_and_fetch_groups() {
+    let rule = EpRule::default();
+    let mut ep = create_test_album("1", "EP", "2025-08-08", "Artist");
+    ep.album_type = "single".to_string();
+    ep.total_tracks = Some(4);
+    let mut single = ep.clone();
+    single.total_tracks = Some(1);
+
+    let eps = parse_release_kinds("ep").unwrap();
+    assert!(eps.matches(&ep, &rule));
+    assert!(!eps.matches(&single, &rule));
+
+    let singles = parse_release_kinds("single").unwrap();
+    assert!(!singles.matches(&ep, &rule));
+    assert!(singles.matches(&single, &rule));
+
+    let kinds = parse_release_kinds("album,ep").unwrap();
+    assert_eq!(kinds.fetch_groups().to_string(), "album,single");
+    assert_eq!(
+        parse_release_kinds("all")
+            .unwrap()
+            .fetch_groups()
+            .to_string(),
+        "album,single,appears_on,compilation"
+    );
+}
+
+#[test]
+fn test_parse_genre_pattern() {
+    let substring = parse_genre_pattern("Techno").unwrap();
+    assert!(substring.matches("minimal techno"));
+    assert!(!substring.matches("house"));
+
+    let regex = parse_genre_pattern("/^(deep|tech) house$/").unwrap();
+    assert!(regex.matches("Deep House"));
+    assert!(!regex.matches("deep house revival"));
+
+    assert!(parse_genre_pattern("  ").is_err());
+    assert!(parse_genre_pattern("/(unclosed/").is_err());
+}
+
+#[test]
+fn test_genre_filter() {
+    let album = create_test_album("1", "Collab", "2025-08-08", "Artist");
+    let genres_by_artist = HashMap::from([(
+        "1_artist_id".to_string(),
+        vec!["minimal techno".to_string(), "dub techno".to_string()],
+    )]);
+
+    assert!(GenreFilter::default().matches(&album, &genres_by_artist));
+
+    let techno = GenreFilter {
+        genres: vec![parse_genre_pattern("techno").unwrap()],
+        exclude_genres: vec![],
+    };
+    assert!(techno.matches(&album, &genres_by_artist));
+    // artists without known genres never match --genre
+    assert!(!techno.matches(&album, &HashMap::new()));
+
+    let no_dub = GenreFilter {
+        genres: vec![parse_genre_pattern("techno").unwrap()],
+        exclude_genres: vec![parse_genre_pattern("/^dub/").unwrap()],
+    };
+    assert!(!no_dub.matches(&album, &genres_by_artist));
+}
+
+#[test]
+fn test_parse_title_pattern() {
+    let substring = parse_title_pattern("Remaster").unwrap();
+    assert!(substring.matches("Abbey Road (Remastered 2019)"));
+
+    let glob = parse_title_pattern("*(Live)").unwrap();
+    assert!(glob.matches("Sunrise (live)"));
+    assert!(!glob.matches("Sunrise (Live) [Deluxe]"));
+    assert!(parse_title_pattern("Song ?").unwrap().matches("song 2"));
+
+    let regex = parse_title_pattern(r"/\b(sped up|slowed)\b/").unwrap();
+    assert!(regex.matches("Summer - Sped Up"));
+    assert!(!regex.matches("Speed Upgrade"));
+
+    assert!(parse_title_pattern(" ").is_err());
+    assert!(parse_title_pattern("/(unclosed/").is_err());
+}
+
+#[test]
+fn test_title_filter_and_split_hidden_releases() {
+    let filter = TitleFilter {
+        include: vec![parse_title_pattern("summer").unwrap()],
+        exclude: vec![parse_title_pattern("*(Live)").unwrap()],
+    };
+    assert!(filter.is_set());
+    assert_eq!(filter.hidden_reason("Summer"), None);
+    assert_eq!(
+        filter.hidden_reason("Summer (Live)"),
+        Some("title matches '*(Live)'".to_string())
+    );
+    assert_eq!(
+        filter.hidden_reason("Winter"),
+        Some("title matches no TITLE_INCLUDE pattern".to_string())
+    );
+    assert_eq!(TitleFilter::default().hidden_reason("Winter"), None);
+
+    let mut albums = vec![
+        create_test_album("1", "Summer", "2025-08-08", "Artist"),
+        create_test_album("2", "Winter", "2025-08-08", "Artist"),
+        create_test_album("3", "Summer (Live)", "2025-08-08", "Artist"),
+    ];
+    let hidden = split_hidden_releases(&mut albums, |album| filter.hidden_reason(&album.name));
+    assert_eq!(albums.len(), 1);
+    assert_eq!(albums[0].id, "1");
+    let hidden_ids: Vec<&str> = hidden.iter().map(|h| h.album.id.as_str()).collect();
+    assert_eq!(hidden_ids, vec!["2", "3"]);
+}
+
+#[test]
+fn test_parse_album_id() {
+    let id = "4LH4d3cOWNNsVw41Gqt2kv";
+    assert_eq!(parse_album_id(id), Some(id.to_string()));
+    assert_eq!(
+        parse_album_id("spotify:album:4LH4d3cOWNNsVw41Gqt2kv"),
+        Some(id.to_string())
+    );
+    assert_eq!(
+        parse_album_id("https://open.spotify.com/intl-de/album/4LH4d3cOWNNsVw41Gqt2kv?si=abc"),
+        Some(id.to_string())
+    );
+    assert_eq!(parse_album_id("ok computer"), None);
+    assert_eq!(parse_album_id("spotify:track:4LH4d3cOWNNsVw41Gqt2kv"), None);
+}
+
+#[test]
+fn test_format_track_length() {
+    assert_eq!(format_track_length(0), "0:00");
+    assert_eq!(format_track_length(215_000), "3:35");
+    assert_eq!(format_track_length(3_725_000), "1:02:05");
+}
+
+#[test]
+fn test_normalize_release_title() {
+    assert_eq!(normalize_release_title("Summer (Deluxe Edition)"), "summer");
+    assert_eq!(normalize_release_title("Summer [Explicit]"), "summer");
+    assert_eq!(normalize_release_title("Summer - 2025 Remaster"), "summer");
+    assert_eq!(normalize_release_title("Summer  - Clean"), "summer");
+    assert_eq!(normalize_release_title("Summer (Live)"), "summer (live)");
+    assert_eq!(
+        normalize_release_title("Summer - Remixes"),
+        "summer - remixes"
+    );
+}
+
+#[test]
+fn test_group_editions() {
+    let edition = |id: &str, name: &str, date: &str| Album {
+        artists: vec![AlbumArtist {
+            id: "a1".to_string(),
+            name: "Alpha".to_string(),
+        }],
+        ..create_test_album(id, name, date, "Alpha")
+    };
+    let albums = vec![
+        edition("std", "Summer", "2025-08-08"),
+        edition("other", "Winter", "2025-08-08"),
+        edition("deluxe", "Summer (Deluxe)", "2025-08-01"),
+        edition("later", "Summer", "2025-09-30"),
+        edition("regional", "Été", "2025-08-08"),
+        create_test_album("guest", "Summer", "2025-08-08", "Guest"),
+    ];
+
+    let groups = group_editions(albums.clone(), &HashMap::new(), &HashSet::new());
+    let ids: Vec<Vec<&str>> = groups
+        .iter()
+        .map(|group| group.iter().map(|album| album.id.as_str()).collect())
+        .collect();
+    assert_eq!(
+        ids,
+        vec![
+            vec!["std", "deluxe"],
+            vec!["other"],
+            vec!["later"],
+            vec!["regional"],
+            vec!["guest"]
+        ]
+    );
+
+    // same UPC groups regardless of the title; explicit editions are preferred
+    let upcs = HashMap::from([
+        ("std".to_string(), "602445790012".to_string()),
+        ("regional".to_string(), "602445790012".to_string()),
+    ]);
+    let explicit = HashSet::from(["deluxe".to_string()]);
+    let groups = group_editions(albums, &upcs, &explicit);
+    let first: Vec<&str> = groups[0].iter().map(|album| album.id.as_str()).collect();
+    assert_eq!(first, vec!["deluxe", "std", "regional"]);
+    assert_eq!(groups.len(), 4);
+
+    // an edition between two others merges them into one group
+    let albums = vec![
+        edition("early", "Summer", "2025-08-01"),
+        edition("late", "Summer", "2025-08-20"),
+        edition("bridge", "Summer (Deluxe)", "2025-08-10"),
+    ];
+    let groups = group_editions(albums, &HashMap::new(), &HashSet::new());
+    assert_eq!(groups.len(), 1);
+    let ids: Vec<&str> = groups[0].iter().map(|album| album.id.as_str()).collect();
+    assert_eq!(ids, vec!["early", "late", "bridge"]);
 }